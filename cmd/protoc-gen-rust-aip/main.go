@@ -24,6 +24,38 @@ func main() {
 			true,
 			"set to false to exclude resource definitions from code generation",
 		)
+		messageAccessors = flags.Bool(
+			"message_accessors",
+			false,
+			"set to true to generate resource name and resource reference accessors on prost messages",
+		)
+		prostModule = flags.String(
+			"prost_module",
+			"",
+			"set to the Rust path of the module including the prost modules, e.g. crate::pb, if it is not where include_aip.rs is included",
+		)
+		serde = flags.String(
+			"serde",
+			"",
+			"set to string or structured to generate serde implementations for resource names",
+		)
+		validation = flags.String(
+			"validation",
+			string(genaip.ValidationAIP122),
+			"set to rfc1034 to restrict variable segments to RFC-1034 labels",
+		)
+	)
+	serviceNames := genaip.ServiceNames{}
+	flags.Var(
+		serviceNames,
+		"service_name",
+		"set to <proto package>=<service> to override the service in full resource names, may be repeated",
+	)
+	revisions := genaip.ResourceTypes{}
+	flags.Var(
+		revisions,
+		"revisions",
+		"set to a resource type to generate its revision-qualified name type, may be repeated",
 	)
 	protogen.Options{
 		ParamFunc: flags.Set,
@@ -33,6 +65,12 @@ func main() {
 		
 		return genaip.Run(plugin, genaip.Config{
 			IncludeResourceDefinitions: *includeResourceDefinitions,
+			MessageAccessors:           *messageAccessors,
+			ProstModule:                *prostModule,
+			Serde:                      genaip.SerdeMode(*serde),
+			ServiceNames:               serviceNames,
+			Revisions:                  revisions,
+			Validation:                 genaip.ValidationProfile(*validation),
 		})
 	})
 }
@@ -0,0 +1,161 @@
+use std::str::Split;
+
+use crate::{ResourceNameError, Scanner, WILDCARD};
+
+/// Returns the part of `name` from the start of its segment `first` to the end of its
+/// segment `last`, e.g. the value of a variable spanning the segments between them. Used by
+/// the generated parsers, with segments split from `name`.
+///
+/// # Panics
+///
+/// Panics if `first` or `last` is not a slice of `name`.
+#[doc(hidden)]
+pub fn span<'a>(name: &'a str, first: &'a str, last: &'a str) -> &'a str {
+    &name[offset(name, first)..offset(name, last) + last.len()]
+}
+
+/// Returns the part of `name` from the start of its segment `first` to the end of the name,
+/// e.g. the value of a variable ending with `**`. Used by the generated parsers.
+///
+/// # Panics
+///
+/// Panics if `first` is not a slice of `name`.
+#[doc(hidden)]
+pub fn span_from<'a>(name: &'a str, first: &'a str) -> &'a str {
+    &name[offset(name, first)..]
+}
+
+/// Returns the byte offset of a slice of `name` within it.
+fn offset(name: &str, part: &str) -> usize {
+    let range = name.as_bytes().as_ptr_range();
+    let start = part.as_ptr();
+    assert!(
+        range.contains(&start) || start == range.end,
+        "segment is not a slice of the name"
+    );
+    start as usize - range.start as usize
+}
+
+/// Checks that the value of a variable spanning several segments matches its template, e.g.
+/// `projects/*/books/*`, and returns the segments of the value for validation.
+pub fn capture_segments<'a>(
+    field: &'static str,
+    value: &'a str,
+    template: &str,
+) -> Result<Split<'a, char>, ResourceNameError> {
+    let mut scanner = Scanner::new(value, template.split('/').count());
+    if scanner
+        .capture(template)
+        .and_then(|_| scanner.finish())
+        .is_err()
+    {
+        let rule = format!("match '{template}'");
+        return Err(ResourceNameError::invalid_format(field, &rule, value));
+    }
+    Ok(value.split('/'))
+}
+
+/// Returns the value of a variable spanning several segments with the [`WILDCARD`] in
+/// each of its wildcards, e.g. `projects/-/books/-` for `projects/*/books/*`.
+pub fn capture_wildcard(template: &str) -> String {
+    template
+        .split('/')
+        .map(|segment| match segment {
+            "*" | "**" => WILDCARD,
+            literal => literal,
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Returns true if the value of a variable spanning several segments matches `concrete`,
+/// where a [`WILDCARD`] segment matches any segment. In place of the `**` that ends
+/// `template`, a lone [`WILDCARD`] matches any suffix, as set by [`capture_wildcard`].
+pub fn capture_matches(template: &str, value: &str, concrete: &str) -> bool {
+    let count = template.split('/').count();
+    let mut values = value.splitn(count, '/');
+    let mut concretes = concrete.splitn(count, '/');
+    template.split('/').all(|segment| {
+        let (Some(value), Some(concrete)) = (values.next(), concretes.next()) else {
+            return false;
+        };
+        match segment {
+            "**" => value == concrete || (value == WILDCARD && !concrete.is_empty()),
+            _ => !concrete.contains('/') && (value == WILDCARD || value == concrete),
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn span_of_segments() {
+        let name = "projects/p/files/a/b";
+        let parts: Vec<&str> = name.split('/').collect();
+        assert_eq!(span(name, parts[0], parts[1]), "projects/p");
+        assert_eq!(span_from(name, parts[3]), "a/b");
+        let trailing = "files/";
+        let last = trailing.split('/').next_back().unwrap();
+        assert_eq!(span_from(trailing, last), "");
+    }
+
+    #[test]
+    fn capture_segments_errors() {
+        for (value, template) in [
+            ("users/u", "users/*/shelves/*"),
+            ("users/u/books/b", "users/*/shelves/*"),
+            ("users/u/shelves/s/x", "users/*/shelves/*"),
+            ("projects/p/users/u", "users/*"),
+        ] {
+            let err = capture_segments("parent", value, template).unwrap_err();
+            assert_eq!(
+                err,
+                ResourceNameError::invalid_format("parent", &format!("match '{template}'"), value),
+            );
+        }
+        let segments: Vec<&str> =
+            capture_segments("parent", "users/u/shelves/s", "users/*/shelves/*")
+                .unwrap()
+                .collect();
+        assert_eq!(segments, ["users", "u", "shelves", "s"]);
+        // Empty segments match, and are rejected by the validation of each segment
+        assert_eq!(capture_segments("path", "a//b", "**").unwrap().count(), 3);
+    }
+
+    #[test]
+    fn capture_wildcards() {
+        assert_eq!(capture_wildcard("users/*/shelves/*"), "users/-/shelves/-");
+        assert_eq!(capture_wildcard("**"), "-");
+        let template = "users/*/shelves/*";
+        assert!(capture_matches(
+            template,
+            "users/-/shelves/s",
+            "users/u/shelves/s"
+        ));
+        assert!(!capture_matches(template, "users/-", "users/u/shelves/s"));
+        assert!(!capture_matches(
+            template,
+            "users/u/shelves/s",
+            "users/-/shelves/s"
+        ));
+        assert!(!capture_matches("users/*", "users/-", "users/u/shelves/s"));
+
+        // The wildcard of a `**` capture matches any suffix, which must not be empty
+        let wildcard = capture_wildcard("**");
+        assert!(capture_matches("**", &wildcard, "a/b"));
+        assert!(capture_matches("**", &wildcard, "a"));
+        assert!(!capture_matches("**", &wildcard, ""));
+        assert!(!capture_matches("**", "a/b", "a/c"));
+        assert!(capture_matches("files/**", "files/-", "files/a/b"));
+        assert!(!capture_matches("files/**", "files/-", "other/a/b"));
+    }
+
+    #[test]
+    #[should_panic(expected = "not a slice of the name")]
+    fn span_of_other_string() {
+        let other = String::from("projects/p");
+        span("projects/p", &other, &other);
+    }
+}
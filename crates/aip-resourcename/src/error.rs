@@ -0,0 +1,256 @@
+use std::fmt;
+
+/// The kind of failure reported by a [`ResourceNameError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResourceNameErrorKind {
+    /// A variable segment is empty.
+    Empty,
+    /// A variable segment contains an illegal character.
+    IllegalChar,
+    /// The name has the wrong number of segments.
+    WrongSegmentCount,
+    /// A literal segment does not match the pattern.
+    UnexpectedLiteral,
+    /// The name does not match any pattern of the resource.
+    NoMatchingPattern,
+    /// A path template is malformed.
+    InvalidPattern,
+    /// A full resource name belongs to another service, or is not a full resource name.
+    WrongService,
+    /// A resource URI is malformed.
+    InvalidUri,
+    /// A value is longer than allowed.
+    TooLong,
+    /// A variable segment does not follow the format required by its validation rules.
+    InvalidFormat,
+    /// A variable segment holds a wildcard where a concrete value is required.
+    Wildcard,
+    /// A resource type is not known.
+    UnknownType,
+}
+
+/// Error returned when parsing or validating a resource name fails.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ResourceNameError {
+    /// The kind of failure.
+    pub kind: ResourceNameErrorKind,
+    /// The pattern variable the failure relates to, if any.
+    pub field: Option<&'static str>,
+    /// The index of the segment the failure relates to, if any.
+    pub position: Option<usize>,
+    /// What the pattern expected, if known.
+    pub expected: Option<String>,
+    /// What was found instead, if known.
+    pub got: Option<String>,
+}
+
+impl ResourceNameError {
+    fn new(kind: ResourceNameErrorKind) -> Self {
+        Self {
+            kind,
+            field: None,
+            position: None,
+            expected: None,
+            got: None,
+        }
+    }
+
+    /// Creates an error for an empty variable segment.
+    pub fn empty(field: &'static str) -> Self {
+        Self {
+            field: Some(field),
+            ..Self::new(ResourceNameErrorKind::Empty)
+        }
+    }
+
+    /// Creates an error for an empty resource name.
+    pub fn empty_name() -> Self {
+        Self::new(ResourceNameErrorKind::Empty)
+    }
+
+    /// Creates an error for an empty segment of a name whose pattern is not known.
+    pub fn empty_segment(position: usize) -> Self {
+        Self {
+            position: Some(position),
+            ..Self::new(ResourceNameErrorKind::Empty)
+        }
+    }
+
+    /// Creates an error for a variable segment containing an illegal character.
+    pub fn illegal_char(field: &'static str, c: char) -> Self {
+        Self {
+            field: Some(field),
+            got: Some(c.to_string()),
+            ..Self::new(ResourceNameErrorKind::IllegalChar)
+        }
+    }
+
+    /// Creates an error for a variable segment containing a character outside of `allowed`,
+    /// which describes the characters its validation rules accept.
+    pub fn illegal_char_in(field: &'static str, c: char, allowed: &str) -> Self {
+        Self {
+            expected: Some(allowed.to_string()),
+            ..Self::illegal_char(field, c)
+        }
+    }
+
+    /// Creates an error for a name with the wrong number of segments.
+    pub fn wrong_segment_count(expected: usize, got: usize) -> Self {
+        Self {
+            expected: Some(expected.to_string()),
+            got: Some(got.to_string()),
+            ..Self::new(ResourceNameErrorKind::WrongSegmentCount)
+        }
+    }
+
+    /// Creates an error for a literal segment that does not match the pattern.
+    pub fn unexpected_literal(position: usize, expected: &str, got: &str) -> Self {
+        Self {
+            position: Some(position),
+            expected: Some(expected.to_string()),
+            got: Some(got.to_string()),
+            ..Self::new(ResourceNameErrorKind::UnexpectedLiteral)
+        }
+    }
+
+    /// Creates an error for a name that matches none of the resource's patterns.
+    ///
+    /// `closest` is the pattern that matched the longest prefix of the name, `position` is the
+    /// first segment where the name diverged from it, and `got` is that segment, or `None` if
+    /// the name ended early.
+    pub fn no_matching_pattern(closest: &str, position: usize, got: Option<&str>) -> Self {
+        Self {
+            position: Some(position),
+            expected: Some(closest.to_string()),
+            got: got.map(str::to_string),
+            ..Self::new(ResourceNameErrorKind::NoMatchingPattern)
+        }
+    }
+
+    /// Creates an error for a malformed segment of a path template.
+    pub fn invalid_pattern(pattern: &str, position: usize, segment: &str) -> Self {
+        Self {
+            position: Some(position),
+            expected: Some(pattern.to_string()),
+            got: Some(segment.to_string()),
+            ..Self::new(ResourceNameErrorKind::InvalidPattern)
+        }
+    }
+
+    /// Creates an error for a full resource name of another service than `expected`. `got`
+    /// is the service of the name, or `None` if it is not a full resource name.
+    pub fn wrong_service(expected: &str, got: Option<&str>) -> Self {
+        Self {
+            expected: Some(expected.to_string()),
+            got: got.map(str::to_string),
+            ..Self::new(ResourceNameErrorKind::WrongService)
+        }
+    }
+
+    /// Creates an error for a malformed resource URI.
+    pub fn invalid_uri(uri: &str) -> Self {
+        Self {
+            got: Some(uri.to_string()),
+            ..Self::new(ResourceNameErrorKind::InvalidUri)
+        }
+    }
+
+    /// Creates an error for a value longer than `max` characters.
+    pub fn too_long(field: &'static str, max: usize, got: usize) -> Self {
+        Self {
+            field: Some(field),
+            expected: Some(max.to_string()),
+            got: Some(got.to_string()),
+            ..Self::new(ResourceNameErrorKind::TooLong)
+        }
+    }
+
+    /// Creates an error for a variable segment breaking a validation rule, where `rule`
+    /// completes "must", e.g. "start with a letter".
+    pub fn invalid_format(field: &'static str, rule: &str, value: &str) -> Self {
+        Self {
+            field: Some(field),
+            expected: Some(rule.to_string()),
+            got: Some(value.to_string()),
+            ..Self::new(ResourceNameErrorKind::InvalidFormat)
+        }
+    }
+
+    /// Creates an error for a wildcard where a concrete value is required.
+    pub fn wildcard(field: &'static str) -> Self {
+        Self {
+            field: Some(field),
+            ..Self::new(ResourceNameErrorKind::Wildcard)
+        }
+    }
+
+    /// Creates an error for a resource type that is not known.
+    pub fn unknown_type(resource_type: &str) -> Self {
+        Self {
+            got: Some(resource_type.to_string()),
+            ..Self::new(ResourceNameErrorKind::UnknownType)
+        }
+    }
+}
+
+impl fmt::Display for ResourceNameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let field = self.field.unwrap_or_default();
+        let position = self.position.unwrap_or_default();
+        let expected = self.expected.as_deref().unwrap_or_default();
+        let got = self.got.as_deref().unwrap_or_default();
+        match self.kind {
+            ResourceNameErrorKind::Empty if self.field.is_some() => write!(f, "{field}: empty"),
+            ResourceNameErrorKind::Empty if self.position.is_some() => {
+                write!(f, "empty segment at position {position}")
+            }
+            ResourceNameErrorKind::Empty => f.write_str("resource name cannot be empty"),
+            ResourceNameErrorKind::IllegalChar if self.expected.is_some() => write!(
+                f,
+                "{field}: contains illegal character '{got}', expected {expected}"
+            ),
+            ResourceNameErrorKind::IllegalChar => {
+                write!(f, "{field}: contains illegal character '{got}'")
+            }
+            ResourceNameErrorKind::WrongSegmentCount => {
+                write!(f, "expected {expected} parts, got {got}")
+            }
+            ResourceNameErrorKind::UnexpectedLiteral => {
+                write!(f, "expected '{expected}' at position {position}, got '{got}'")
+            }
+            ResourceNameErrorKind::NoMatchingPattern if self.got.is_none() => write!(
+                f,
+                "no matching pattern, closest is '{expected}' which expects more than {position} parts"
+            ),
+            ResourceNameErrorKind::NoMatchingPattern => write!(
+                f,
+                "no matching pattern, closest is '{expected}' which diverges at position {position}, got '{got}'"
+            ),
+            ResourceNameErrorKind::InvalidPattern => write!(
+                f,
+                "invalid pattern '{expected}', malformed segment '{got}' at position {position}"
+            ),
+            ResourceNameErrorKind::WrongService if self.got.is_none() => {
+                write!(f, "expected a full resource name of service '{expected}'")
+            }
+            ResourceNameErrorKind::WrongService => {
+                write!(f, "expected service '{expected}', got '{got}'")
+            }
+            ResourceNameErrorKind::InvalidUri => write!(
+                f,
+                "invalid resource URI '{got}', expected https://<service>/<version>/<name>"
+            ),
+            ResourceNameErrorKind::TooLong => {
+                write!(f, "{field}: expected at most {expected} characters, got {got}")
+            }
+            ResourceNameErrorKind::InvalidFormat => {
+                write!(f, "{field}: must {expected}, got '{got}'")
+            }
+            ResourceNameErrorKind::Wildcard => write!(f, "{field}: wildcard not allowed"),
+            ResourceNameErrorKind::UnknownType => write!(f, "unknown resource type '{got}'"),
+        }
+    }
+}
+
+impl std::error::Error for ResourceNameError {}
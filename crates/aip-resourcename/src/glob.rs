@@ -0,0 +1,221 @@
+use std::fmt;
+
+use crate::{PathTemplate, ResourceName, ResourceNameError, Segment};
+
+/// A segment of a [`NameGlob`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum GlobSegment {
+    /// A segment that matches itself.
+    Literal(String),
+    /// `*`, which matches any single segment.
+    Any,
+    /// `**`, which matches any suffix, including an empty one.
+    AnySuffix,
+}
+
+/// A glob over the names of a resource type, such as `projects/acme/books/*` or
+/// `projects/**`, e.g. for IAM-like policies.
+///
+/// `*` matches any single segment and `**`, which must be the last segment, matches any
+/// suffix. A glob is compiled against the patterns of the resource type, so that globs that
+/// can never match one of them are rejected, and names only match if they also match one of
+/// the patterns.
+///
+/// ```
+/// use aip_resourcename::NameGlob;
+///
+/// let patterns = &["projects/{project}/books/{book}"];
+/// let glob = NameGlob::compile("projects/acme/**", patterns)?;
+/// assert!(glob.matches("projects/acme/books/rust-book"));
+/// assert!(!glob.matches("projects/acme/shelves/top"));
+///
+/// assert!(NameGlob::compile("projects/*/shelves/*", patterns).is_err());
+/// # Ok::<(), aip_resourcename::ResourceNameError>(())
+/// ```
+///
+/// Compile a glob once to match many names, e.g. with [`NameGlob::for_type`] and
+/// [`NameGlob::matches_name`], rather than calling [`ResourceName::matches_glob`] for each.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NameGlob {
+    glob: String,
+    segments: Vec<GlobSegment>,
+    templates: Vec<PathTemplate>,
+}
+
+impl NameGlob {
+    /// Compiles a glob against resource name patterns. Fails if the glob is malformed, or
+    /// if it cannot match any of the patterns, reporting the pattern that came closest.
+    pub fn compile(glob: &str, patterns: &[&str]) -> Result<Self, ResourceNameError> {
+        let parts: Vec<&str> = glob.split('/').collect();
+        let mut segments = Vec::with_capacity(parts.len());
+        for (i, part) in parts.iter().enumerate() {
+            let segment = match *part {
+                "" => return Err(ResourceNameError::invalid_pattern(glob, i, part)),
+                "**" if i + 1 == parts.len() => GlobSegment::AnySuffix,
+                "*" => GlobSegment::Any,
+                _ if part.contains('*') => {
+                    return Err(ResourceNameError::invalid_pattern(glob, i, part))
+                }
+                _ => GlobSegment::Literal(part.to_string()),
+            };
+            segments.push(segment);
+        }
+
+        let mut templates = Vec::new();
+        let mut closest: Option<(&str, usize)> = None;
+        for pattern in patterns {
+            let template = PathTemplate::parse(pattern)?;
+            match divergence(&segments, &template) {
+                None => templates.push(template),
+                Some(position) if closest.is_none_or(|(_, p)| position > p) => {
+                    closest = Some((pattern, position));
+                }
+                Some(_) => {}
+            }
+        }
+        if templates.is_empty() {
+            let (pattern, position) = closest.unwrap_or_default();
+            return Err(ResourceNameError::no_matching_pattern(
+                pattern,
+                position,
+                parts.get(position).copied(),
+            ));
+        }
+        Ok(Self {
+            glob: glob.to_string(),
+            segments,
+            templates,
+        })
+    }
+
+    /// Compiles a glob against the patterns of a resource name type.
+    pub fn for_type<T: ResourceName>(glob: &str) -> Result<Self, ResourceNameError> {
+        Self::compile(glob, T::PATTERNS)
+    }
+
+    /// Returns the glob the matcher was compiled from.
+    pub fn as_str(&self) -> &str {
+        &self.glob
+    }
+
+    /// Tests whether a typed resource name matches the glob and one of the patterns.
+    pub fn matches_name(&self, name: &impl ResourceName) -> bool {
+        self.matches(&name.to_string())
+    }
+
+    /// Tests whether a resource name matches the glob and one of the patterns.
+    pub fn matches(&self, name: &str) -> bool {
+        if name.split('/').any(str::is_empty) || !self.templates.iter().any(|t| t.matches(name)) {
+            return false;
+        }
+        let mut parts = name.split('/');
+        for segment in &self.segments {
+            match (segment, parts.next()) {
+                (GlobSegment::AnySuffix, _) => return true,
+                (GlobSegment::Any, Some(_)) => {}
+                (GlobSegment::Literal(literal), Some(part)) if literal == part => {}
+                _ => return false,
+            }
+        }
+        parts.next().is_none()
+    }
+}
+
+impl fmt::Display for NameGlob {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.glob)
+    }
+}
+
+/// Returns the position of the first glob segment that prevents the glob from matching
+/// names of the pattern, or `None` if it can match some.
+fn divergence(glob: &[GlobSegment], pattern: &PathTemplate) -> Option<usize> {
+    let pattern: Vec<&Segment> = pattern.expanded_segments().collect();
+    for (i, segment) in glob.iter().enumerate() {
+        match (segment, pattern.get(i)) {
+            (GlobSegment::AnySuffix, _) | (_, Some(Segment::MultiWildcard)) => return None,
+            (_, None) => return Some(i),
+            (GlobSegment::Any, Some(_)) => {}
+            (GlobSegment::Literal(literal), Some(Segment::Literal(expected))) => {
+                if literal != expected {
+                    return Some(i);
+                }
+            }
+            (GlobSegment::Literal(_), Some(_)) => {}
+        }
+    }
+    (glob.len() < pattern.len()).then_some(glob.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ResourceNameErrorKind as Kind;
+
+    const PATTERNS: &[&str] = &[
+        "projects/{project}/books/{book}",
+        "projects/{project}/shelves/{shelf}/books/{book}",
+    ];
+
+    #[test]
+    fn compile_errors() {
+        for (glob, position, segment) in [
+            ("", 0, ""),
+            ("projects//**", 1, ""),
+            ("projects/p/", 2, ""),
+            ("projects/a*", 1, "a*"),
+            ("projects/**/books/*", 1, "**"),
+            ("projects/***", 1, "***"),
+        ] {
+            assert_eq!(
+                NameGlob::compile(glob, PATTERNS),
+                Err(ResourceNameError::invalid_pattern(glob, position, segment)),
+                "{glob:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn compile_no_matching_pattern() {
+        assert_eq!(
+            NameGlob::compile("projects/*/authors/*", PATTERNS),
+            Err(ResourceNameError::no_matching_pattern(
+                PATTERNS[0],
+                2,
+                Some("authors")
+            ))
+        );
+        assert_eq!(
+            NameGlob::compile("projects/*", PATTERNS),
+            Err(ResourceNameError::no_matching_pattern(PATTERNS[0], 2, None))
+        );
+        assert_eq!(
+            NameGlob::compile("projects/*/books/*/x", PATTERNS)
+                .unwrap_err()
+                .kind,
+            Kind::NoMatchingPattern
+        );
+        assert_eq!(
+            NameGlob::compile("**", &["projects/{project"])
+                .unwrap_err()
+                .kind,
+            Kind::InvalidPattern
+        );
+    }
+
+    #[test]
+    fn matches_only_valid_names() {
+        let glob = NameGlob::compile("projects/p/**", PATTERNS).unwrap();
+        assert!(glob.matches("projects/p/books/b"));
+        assert!(glob.matches("projects/p/shelves/s/books/b"));
+        assert!(!glob.matches("projects/p"));
+        assert!(!glob.matches("projects/p/authors/a"));
+        assert!(!glob.matches("projects/p//b"));
+        assert!(!glob.matches("projects/p/books/b/"));
+        assert!(!glob.matches("projects/q/books/b"));
+
+        let glob = NameGlob::compile("projects/*/books/*", PATTERNS).unwrap();
+        assert!(glob.matches("projects/p/books/b"));
+        assert!(!glob.matches("projects/p/shelves/s/books/b"));
+    }
+}
@@ -0,0 +1,533 @@
+//! Runtime support for AIP resource names.
+//!
+//! This crate is the Rust counterpart of the Go `pkg/resourcename` package. It holds
+//! everything the code generated by `protoc-gen-rust-aip` shares: the [`ResourceNameError`]
+//! type, the [`ResourceName`] traits and the [`Scanner`] used by the generated parsers.
+//!
+//! It can also be used directly, through [`PathTemplate`] and the free functions
+//! [`sprint`], [`sscan`], [`matches`](fn@matches), [`is_ancestor`] and [`validate`], for
+//! names whose pattern is only known at runtime:
+//!
+//! ```
+//! use aip_resourcename::PathTemplate;
+//!
+//! let template: PathTemplate = "projects/{project}/books/{book}".parse()?;
+//! let name = template.sprint(&["my-project", "rust-book"]);
+//! assert_eq!(name, "projects/my-project/books/rust-book");
+//! assert_eq!(template.sscan(&name)?, ["my-project", "rust-book"]);
+//! # Ok::<(), aip_resourcename::ResourceNameError>(())
+//! ```
+//!
+//! The `serde` feature enables the `serde` module, used by the code generated with the
+//! `serde` plugin option. The `regex` feature enables regular expressions in [`rules`].
+
+mod capture;
+mod error;
+mod glob;
+mod resource_name;
+pub mod rules;
+mod scanner;
+#[cfg(feature = "serde")]
+pub mod serde;
+mod template;
+mod untyped;
+
+pub use capture::{capture_matches, capture_segments, capture_wildcard, span, span_from};
+pub use error::{ResourceNameError, ResourceNameErrorKind};
+pub use glob::NameGlob;
+pub use resource_name::{DynResourceName, ResourceName};
+pub use scanner::Scanner;
+pub use template::{PathTemplate, Segment};
+pub use untyped::{Pairs, UntypedResourceName, UntypedResourceNameRef};
+
+/// The resource name wildcard, which stands for any value of a variable segment.
+pub const WILDCARD: &str = "-";
+
+/// The maximum length of a variable segment under the AIP-122 rules.
+pub const MAX_SEGMENT_LEN: usize = 1024;
+
+/// The maximum length of a variable segment under the RFC-1034 rules.
+pub const MAX_RFC1034_SEGMENT_LEN: usize = 63;
+
+/// Validates a single variable segment of a resource name according to AIP-122: it must be
+/// non-empty printable ASCII, without whitespace or `/`, and at most 1024 characters.
+///
+/// ```
+/// use aip_resourcename::validate_segment;
+///
+/// assert!(validate_segment("book", "rust-book").is_ok());
+/// assert!(validate_segment("book", "rust book").is_err());
+/// ```
+pub fn validate_segment(field: &'static str, value: &str) -> Result<(), ResourceNameError> {
+    if value.is_empty() {
+        return Err(ResourceNameError::empty(field));
+    }
+    if value.contains('/') {
+        return Err(ResourceNameError::illegal_char(field, '/'));
+    }
+    if let Some(c) = value.chars().find(|c| !c.is_ascii_graphic()) {
+        return Err(ResourceNameError::illegal_char_in(
+            field,
+            c,
+            "printable ASCII without whitespace",
+        ));
+    }
+    if value.len() > MAX_SEGMENT_LEN {
+        return Err(ResourceNameError::too_long(
+            field,
+            MAX_SEGMENT_LEN,
+            value.len(),
+        ));
+    }
+    Ok(())
+}
+
+/// Validates a single variable segment of a resource name according to RFC-1034: it must
+/// hold lowercase letters, digits and hyphens, start with a letter, not end with a hyphen,
+/// and be at most 63 characters. The [`WILDCARD`] is accepted as well.
+///
+/// ```
+/// use aip_resourcename::validate_rfc1034_segment;
+///
+/// assert!(validate_rfc1034_segment("project", "my-project").is_ok());
+/// assert!(validate_rfc1034_segment("project", "1-project").is_err());
+/// ```
+pub fn validate_rfc1034_segment(field: &'static str, value: &str) -> Result<(), ResourceNameError> {
+    if value.is_empty() {
+        return Err(ResourceNameError::empty(field));
+    }
+    if value == WILDCARD {
+        return Ok(());
+    }
+    if let Some(c) = value
+        .chars()
+        .find(|c| !(c.is_ascii_lowercase() || c.is_ascii_digit() || *c == '-'))
+    {
+        return Err(ResourceNameError::illegal_char_in(
+            field,
+            c,
+            "lowercase letters, digits and hyphens",
+        ));
+    }
+    if !value.starts_with(|c: char| c.is_ascii_lowercase()) {
+        return Err(ResourceNameError::invalid_format(
+            field,
+            "start with a letter",
+            value,
+        ));
+    }
+    if value.ends_with('-') {
+        return Err(ResourceNameError::invalid_format(
+            field,
+            "not end with a hyphen",
+            value,
+        ));
+    }
+    if value.len() > MAX_RFC1034_SEGMENT_LEN {
+        return Err(ResourceNameError::too_long(
+            field,
+            MAX_RFC1034_SEGMENT_LEN,
+            value.len(),
+        ));
+    }
+    Ok(())
+}
+
+/// Rejects the [`WILDCARD`] as the value of a variable segment, where a concrete value is
+/// required, e.g. in the name of a resource to create.
+pub fn reject_wildcard(field: &'static str, value: &str) -> Result<(), ResourceNameError> {
+    if value == WILDCARD {
+        return Err(ResourceNameError::wildcard(field));
+    }
+    Ok(())
+}
+
+/// Tests whether `concrete` is matched by `name` segment by segment, where a [`WILDCARD`]
+/// segment of `name` matches any segment. This compares names of different patterns, e.g.
+/// of the variants of a multi-pattern resource.
+///
+/// ```
+/// use aip_resourcename::wildcard_matches;
+///
+/// assert!(wildcard_matches("projects/-/shelves/-", "projects/p/shelves/s"));
+/// assert!(!wildcard_matches("projects/-/shelves/-", "users/u/shelves/s"));
+/// ```
+pub fn wildcard_matches(name: &str, concrete: &str) -> bool {
+    name.split('/').count() == concrete.split('/').count()
+        && name
+            .split('/')
+            .zip(concrete.split('/'))
+            .all(|(segment, other)| segment == WILDCARD || segment == other)
+}
+
+/// Splits the value of a segment holding several variables, such as `{key_a}~{key_b}`, at the
+/// separators between them. Each variable ends at the first following separator.
+///
+/// ```
+/// let [key_a, key_b] = aip_resourcename::split_segment("{key_a}~{key_b}", "a~b", &['~'])?;
+/// assert_eq!((key_a, key_b), ("a", "b"));
+/// # Ok::<(), aip_resourcename::ResourceNameError>(())
+/// ```
+pub fn split_segment<'a, const N: usize>(
+    segment: &'static str,
+    value: &'a str,
+    separators: &[char],
+) -> Result<[&'a str; N], ResourceNameError> {
+    debug_assert_eq!(separators.len() + 1, N);
+    let mut values = [""; N];
+    let mut rest = value;
+    for (slot, separator) in values.iter_mut().zip(separators) {
+        let Some((head, tail)) = rest.split_once(*separator) else {
+            let rule = format!("contain '{separator}'");
+            return Err(ResourceNameError::invalid_format(segment, &rule, value));
+        };
+        *slot = head;
+        rest = tail;
+    }
+    values[N - 1] = rest;
+    Ok(values)
+}
+
+/// Rejects a separator in the value of a variable that the separator follows in its segment,
+/// e.g. `~` in `key_a` of `{key_a}~{key_b}`, since it would end the variable early when parsed.
+pub fn reject_separator(
+    field: &'static str,
+    value: &str,
+    separator: char,
+) -> Result<(), ResourceNameError> {
+    if value.contains(separator) {
+        return Err(ResourceNameError::illegal_char(field, separator));
+    }
+    Ok(())
+}
+
+/// Formats resource name variables according to a pattern and returns the resulting string.
+///
+/// Variables missing from `variables` are left empty. An invalid pattern yields an empty
+/// string.
+pub fn sprint(pattern: &str, variables: &[&str]) -> String {
+    match PathTemplate::parse(pattern) {
+        Ok(template) => template.sprint(variables),
+        Err(_) => String::new(),
+    }
+}
+
+/// Scans a resource name and returns the values of its variable segments, as determined
+/// by the provided pattern.
+pub fn sscan<'a>(name: &'a str, pattern: &str) -> Result<Vec<&'a str>, ResourceNameError> {
+    PathTemplate::parse(pattern)?.sscan(name)
+}
+
+/// Tests whether a resource name matches a pattern.
+pub fn matches(pattern: &str, name: &str) -> bool {
+    PathTemplate::parse(pattern).is_ok_and(|template| template.matches(name))
+}
+
+/// Tests whether `name` is nested under `ancestor`, i.e. starts with all of its segments
+/// and has more segments after them. A name is not its own ancestor.
+///
+/// ```
+/// assert!(aip_resourcename::is_ancestor("projects/p", "projects/p/books/b"));
+/// assert!(!aip_resourcename::is_ancestor("projects/p", "projects/pp/books/b"));
+/// ```
+pub fn is_ancestor(ancestor: &str, name: &str) -> bool {
+    name.strip_prefix(ancestor).is_some_and(is_nested_path)
+}
+
+/// Reports whether the rest of a name, after an ancestor, holds at least one more segment.
+fn is_nested_path(rest: &str) -> bool {
+    rest.len() > 1 && rest.starts_with('/')
+}
+
+/// Splits a full resource name, e.g. `//library.googleapis.com/projects/p/books/b`, into the
+/// name of its service and the resource name relative to the service.
+pub fn split_full_name(full_name: &str) -> Option<(&str, &str)> {
+    full_name.strip_prefix("//")?.split_once('/')
+}
+
+/// Returns the resource name relative to `service` of a full resource name, failing if the
+/// name is not a full resource name of that service.
+///
+/// ```
+/// let name = aip_resourcename::strip_service("//library.googleapis.com/shelves/s", "library.googleapis.com");
+/// assert_eq!(name, Ok("shelves/s"));
+/// ```
+pub fn strip_service<'a>(full_name: &'a str, service: &str) -> Result<&'a str, ResourceNameError> {
+    match split_full_name(full_name) {
+        Some((got, name)) if got == service => Ok(name),
+        got => Err(ResourceNameError::wrong_service(
+            service,
+            got.map(|(got, _)| got),
+        )),
+    }
+}
+
+/// Splits a resource URI of `service`, e.g. `https://library.googleapis.com/v1/shelves/s`, into
+/// its API version and the resource name relative to the service.
+///
+/// ```
+/// let uri = "https://library.googleapis.com/v1/shelves/s";
+/// let parts = aip_resourcename::split_uri(uri, "library.googleapis.com");
+/// assert_eq!(parts, Ok(("v1", "shelves/s")));
+/// ```
+pub fn split_uri<'a>(uri: &'a str, service: &str) -> Result<(&'a str, &'a str), ResourceNameError> {
+    let (host, rest) = uri
+        .strip_prefix("https://")
+        .or_else(|| uri.strip_prefix("http://"))
+        .and_then(|rest| rest.split_once('/'))
+        .ok_or_else(|| ResourceNameError::invalid_uri(uri))?;
+    if host != service {
+        return Err(ResourceNameError::wrong_service(service, Some(host)));
+    }
+    match rest.split_once('/') {
+        Some((version, name)) if !version.is_empty() => Ok((version, name)),
+        _ => Err(ResourceNameError::invalid_uri(uri)),
+    }
+}
+
+/// The separator between a resource name and its revision ID, see AIP-162.
+pub const REVISION_SEPARATOR: char = '@';
+
+/// The maximum length of a revision ID.
+pub const MAX_REVISION_ID_LEN: usize = 64;
+
+/// Splits a revision-qualified name, e.g. `projects/p/books/b@3f2a`, into the resource name
+/// and the revision ID, if any. Only the last segment of the name can hold a revision.
+///
+/// ```
+/// use aip_resourcename::split_revision;
+///
+/// assert_eq!(split_revision("books/b@3f2a"), ("books/b", Some("3f2a")));
+/// assert_eq!(split_revision("books/b"), ("books/b", None));
+/// ```
+pub fn split_revision(name: &str) -> (&str, Option<&str>) {
+    match name.rsplit_once(REVISION_SEPARATOR) {
+        Some((name, revision)) if !revision.contains('/') => (name, Some(revision)),
+        _ => (name, None),
+    }
+}
+
+/// Rejects the [`REVISION_SEPARATOR`] in the last segment of the ID of a resource with
+/// revisions, where it would be read back as the start of a revision ID. The error points to
+/// `revision_type`, which parses names with a revision.
+///
+/// ```
+/// use aip_resourcename::reject_revision_separator;
+///
+/// assert!(reject_revision_separator("book", "b", "BookRevisionName").is_ok());
+/// assert!(reject_revision_separator("book", "b@3f2a", "BookRevisionName").is_err());
+/// ```
+pub fn reject_revision_separator(
+    field: &'static str,
+    id: &str,
+    revision_type: &str,
+) -> Result<(), ResourceNameError> {
+    if split_revision(id).1.is_some() {
+        return Err(ResourceNameError::illegal_char_in(
+            field,
+            REVISION_SEPARATOR,
+            &format!("a {revision_type} for a name with a revision"),
+        ));
+    }
+    Ok(())
+}
+
+/// Validates a revision ID, which holds up to 64 lowercase letters, digits and hyphens.
+pub fn validate_revision_id(revision: &str) -> Result<(), ResourceNameError> {
+    if revision.is_empty() {
+        return Err(ResourceNameError::empty("revision"));
+    }
+    if let Some(c) = revision
+        .chars()
+        .find(|c| !(c.is_ascii_lowercase() || c.is_ascii_digit() || *c == '-'))
+    {
+        return Err(ResourceNameError::illegal_char_in(
+            "revision",
+            c,
+            "lowercase letters, digits and hyphens",
+        ));
+    }
+    if revision.len() > MAX_REVISION_ID_LEN {
+        return Err(ResourceNameError::too_long(
+            "revision",
+            MAX_REVISION_ID_LEN,
+            revision.len(),
+        ));
+    }
+    Ok(())
+}
+
+/// Validates the format of a resource name whose pattern is not known.
+pub fn validate(name: &str) -> Result<(), ResourceNameError> {
+    if name.is_empty() {
+        return Err(ResourceNameError::empty_name());
+    }
+    match name.split('/').position(str::is_empty) {
+        Some(position) => Err(ResourceNameError::empty_segment(position)),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ResourceNameErrorKind as Kind;
+
+    #[test]
+    fn validate_empty_segments() {
+        assert_eq!(validate("").unwrap_err(), ResourceNameError::empty_name());
+        for (name, position) in [
+            ("/projects/p", 0),
+            ("projects/p/", 2),
+            ("projects//books/b", 1),
+            ("/", 0),
+        ] {
+            assert_eq!(
+                validate(name).unwrap_err(),
+                ResourceNameError::empty_segment(position),
+                "{name}"
+            );
+        }
+        assert!(validate("projects/p").is_ok());
+    }
+
+    #[test]
+    fn ancestors() {
+        assert!(!is_ancestor("projects/p", "projects/p"));
+        assert!(!is_ancestor("projects/p", "projects/p/"));
+        assert!(is_ancestor("projects/p", "projects/p/books"));
+    }
+
+    #[test]
+    fn full_names() {
+        assert_eq!(split_full_name("library.googleapis.com/shelves/s"), None);
+        assert_eq!(
+            strip_service("//other.googleapis.com/shelves/s", "library.googleapis.com")
+                .unwrap_err()
+                .got
+                .as_deref(),
+            Some("other.googleapis.com")
+        );
+    }
+
+    #[test]
+    fn uris() {
+        for uri in [
+            "library.googleapis.com/v1/shelves/s",
+            "ftp://library.googleapis.com/v1/shelves/s",
+            "https://library.googleapis.com",
+            "https://library.googleapis.com/v1",
+            "https://library.googleapis.com//shelves/s",
+        ] {
+            assert_eq!(
+                split_uri(uri, "library.googleapis.com").unwrap_err().kind,
+                Kind::InvalidUri,
+                "{uri}"
+            );
+        }
+        assert_eq!(
+            split_uri(
+                "https://other.googleapis.com/v1/shelves/s",
+                "library.googleapis.com"
+            )
+            .unwrap_err()
+            .kind,
+            Kind::WrongService
+        );
+    }
+
+    #[test]
+    fn revisions() {
+        assert_eq!(split_revision("books/b@x/y"), ("books/b@x/y", None));
+        assert_eq!(split_revision("books/b@"), ("books/b", Some("")));
+        for (revision, kind) in [
+            ("", Kind::Empty),
+            ("ABC", Kind::IllegalChar),
+            ("a_b", Kind::IllegalChar),
+        ] {
+            assert_eq!(validate_revision_id(revision).unwrap_err().kind, kind);
+        }
+        assert!(validate_revision_id(&"a".repeat(MAX_REVISION_ID_LEN)).is_ok());
+        assert_eq!(
+            validate_revision_id(&"a".repeat(MAX_REVISION_ID_LEN + 1))
+                .unwrap_err()
+                .kind,
+            Kind::TooLong
+        );
+        let err = reject_revision_separator("path", "a/b@c", "FileRevisionName").unwrap_err();
+        assert_eq!((err.kind, err.field), (Kind::IllegalChar, Some("path")));
+        assert!(reject_revision_separator("path", "a@b/c", "FileRevisionName").is_ok());
+    }
+
+    #[test]
+    fn validate_segment_aip122() {
+        for (value, kind) in [
+            ("", Kind::Empty),
+            ("a/b", Kind::IllegalChar),
+            ("a b", Kind::IllegalChar),
+            ("a\tb", Kind::IllegalChar),
+            ("café", Kind::IllegalChar),
+        ] {
+            assert_eq!(
+                validate_segment("book", value).unwrap_err().kind,
+                kind,
+                "{value:?}"
+            );
+        }
+        assert!(validate_segment("book", &"a".repeat(MAX_SEGMENT_LEN)).is_ok());
+        assert_eq!(
+            validate_segment("book", &"a".repeat(MAX_SEGMENT_LEN + 1)).unwrap_err(),
+            ResourceNameError::too_long("book", MAX_SEGMENT_LEN, MAX_SEGMENT_LEN + 1)
+        );
+        assert!(validate_segment("book", "Rust_Book~1.0").is_ok());
+    }
+
+    #[test]
+    fn validate_segment_rfc1034() {
+        for (value, kind) in [
+            ("", Kind::Empty),
+            ("My-project", Kind::IllegalChar),
+            ("my_project", Kind::IllegalChar),
+            ("my.project", Kind::IllegalChar),
+            ("1-project", Kind::InvalidFormat),
+            ("-project", Kind::InvalidFormat),
+            ("project-", Kind::InvalidFormat),
+        ] {
+            let err = validate_rfc1034_segment("project", value).unwrap_err();
+            assert_eq!((err.kind, err.field), (kind, Some("project")), "{value:?}");
+        }
+        let max = "a".repeat(MAX_RFC1034_SEGMENT_LEN);
+        assert!(validate_rfc1034_segment("project", &max).is_ok());
+        assert_eq!(
+            validate_rfc1034_segment("project", &format!("{max}a"))
+                .unwrap_err()
+                .kind,
+            Kind::TooLong
+        );
+        assert!(validate_rfc1034_segment("project", WILDCARD).is_ok());
+        assert!(validate_rfc1034_segment("project", "a").is_ok());
+    }
+
+    #[test]
+    fn wildcards() {
+        assert!(wildcard_matches("projects/-/books/b", "projects/p/books/b"));
+        assert!(!wildcard_matches("projects/-", "projects/p/books/b"));
+        assert!(!wildcard_matches(
+            "projects/p/books/-",
+            "projects/q/books/b"
+        ));
+    }
+
+    #[test]
+    fn split_segment_missing_separator() {
+        let err = split_segment::<3>("{a}~{b}.{c}", "x~y", &['~', '.']).unwrap_err();
+        assert_eq!(err.kind, Kind::InvalidFormat);
+        assert_eq!(err.expected.as_deref(), Some("contain '.'"));
+        assert_eq!(
+            split_segment::<2>("{a}~{b}", "x~y~z", &['~']),
+            Ok(["x", "y~z"])
+        );
+        assert!(reject_separator("a", "x~y", '~').is_err());
+        assert!(reject_separator("b", "x~y", '.').is_ok());
+    }
+}
@@ -0,0 +1,110 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{is_nested_path, NameGlob, ResourceNameError};
+
+/// Common interface implemented by every generated resource name.
+pub trait ResourceName: fmt::Display + FromStr<Err = ResourceNameError> {
+    /// The resource type, e.g. `library.googleapis.com/Book`.
+    const TYPE: &'static str;
+    /// The resource name patterns this type can hold, in declaration order.
+    const PATTERNS: &'static [&'static str];
+    /// The singular name of the resource, as declared in its resource descriptor.
+    const SINGULAR: &'static str;
+    /// The plural name of the resource, as declared in its resource descriptor.
+    const PLURAL: &'static str;
+
+    /// Validates the resource name.
+    fn validate(&self) -> Result<(), ResourceNameError>;
+
+    /// Returns true if any field contains a wildcard.
+    fn contains_wildcard(&self) -> bool;
+
+    /// Returns true if `other` is the name of a resource nested under this one.
+    /// The name is compared as it is displayed, without allocating.
+    fn is_ancestor_of(&self, other: &str) -> bool {
+        let mut rest = StripPrefix(other);
+        fmt::write(&mut rest, format_args!("{self}")).is_ok() && is_nested_path(rest.0)
+    }
+
+    /// Returns true if the name matches a glob such as `projects/acme/books/*`, see
+    /// [`NameGlob`]. Globs that cannot match names of this type match nothing.
+    ///
+    /// The glob is compiled on each call, so compile it once with [`NameGlob::for_type`] to
+    /// match many names.
+    fn matches_glob(&self, glob: &str) -> bool {
+        NameGlob::for_type::<Self>(glob).is_ok_and(|glob| glob.matches_name(self))
+    }
+}
+
+/// Strips what is written to it from the front of a string, failing on the first mismatch.
+struct StripPrefix<'a>(&'a str);
+
+impl fmt::Write for StripPrefix<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 = self.0.strip_prefix(s).ok_or(fmt::Error)?;
+        Ok(())
+    }
+}
+
+/// Object-safe counterpart of [`ResourceName`], for storing resource names of
+/// different types together, e.g. in a `Vec<Box<dyn DynResourceName>>`.
+pub trait DynResourceName: fmt::Debug + fmt::Display {
+    /// Returns the resource type.
+    fn resource_type(&self) -> &'static str;
+
+    /// Returns the resource name patterns of the underlying type.
+    fn patterns(&self) -> &'static [&'static str];
+
+    /// Returns the singular name of the resource.
+    fn singular(&self) -> &'static str;
+
+    /// Returns the plural name of the resource.
+    fn plural(&self) -> &'static str;
+
+    /// Validates the resource name.
+    fn validate(&self) -> Result<(), ResourceNameError>;
+
+    /// Returns true if any field contains a wildcard.
+    fn contains_wildcard(&self) -> bool;
+
+    /// Returns true if `other` is the name of a resource nested under this one.
+    fn is_ancestor_of(&self, other: &str) -> bool;
+
+    /// Returns true if the name matches a glob such as `projects/acme/books/*`.
+    fn matches_glob(&self, glob: &str) -> bool;
+}
+
+impl<T: ResourceName + fmt::Debug> DynResourceName for T {
+    fn resource_type(&self) -> &'static str {
+        T::TYPE
+    }
+
+    fn patterns(&self) -> &'static [&'static str] {
+        T::PATTERNS
+    }
+
+    fn singular(&self) -> &'static str {
+        T::SINGULAR
+    }
+
+    fn plural(&self) -> &'static str {
+        T::PLURAL
+    }
+
+    fn validate(&self) -> Result<(), ResourceNameError> {
+        ResourceName::validate(self)
+    }
+
+    fn contains_wildcard(&self) -> bool {
+        ResourceName::contains_wildcard(self)
+    }
+
+    fn is_ancestor_of(&self, other: &str) -> bool {
+        ResourceName::is_ancestor_of(self, other)
+    }
+
+    fn matches_glob(&self, glob: &str) -> bool {
+        ResourceName::matches_glob(self, glob)
+    }
+}
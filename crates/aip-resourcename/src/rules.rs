@@ -0,0 +1,246 @@
+//! Per-variable validation rules, declared with the `rust_aip.variable_rules` proto option.
+//!
+//! The generated `validate` methods call these after the validation profile of the plugin,
+//! for the variables that have rules. Every rule accepts the [`WILDCARD`].
+//!
+//! ```
+//! use aip_resourcename::rules;
+//!
+//! assert!(rules::validate_uuid("book", "0f8fad5b-d9cb-469f-a165-70867728950e").is_ok());
+//! assert!(rules::validate_charset("user", "jane.doe@example.com", "a-z0-9.@").is_ok());
+//! assert!(rules::validate_max_len("book", "rust-book", 4).is_err());
+//! ```
+//!
+//! [`WILDCARD`]: crate::WILDCARD
+
+use crate::{ResourceNameError, WILDCARD};
+
+/// Validates that a variable holds a lowercase hyphenated UUID.
+pub fn validate_uuid(field: &'static str, value: &str) -> Result<(), ResourceNameError> {
+    if value == WILDCARD || is_uuid(value) {
+        Ok(())
+    } else {
+        Err(ResourceNameError::invalid_format(
+            field,
+            "be a lowercase UUID",
+            value,
+        ))
+    }
+}
+
+fn is_uuid(value: &str) -> bool {
+    value.len() == 36
+        && value.char_indices().all(|(i, c)| match i {
+            8 | 13 | 18 | 23 => c == '-',
+            _ => c.is_ascii_digit() || matches!(c, 'a'..='f'),
+        })
+}
+
+/// Validates that a variable holds at most `max` characters.
+pub fn validate_max_len(
+    field: &'static str,
+    value: &str,
+    max: usize,
+) -> Result<(), ResourceNameError> {
+    let len = value.chars().count();
+    if len > max {
+        return Err(ResourceNameError::too_long(field, max, len));
+    }
+    Ok(())
+}
+
+/// Validates that a variable only holds characters of `charset`, which lists single
+/// characters and ranges such as `a-z`. A `-` at the start or end of the charset stands for
+/// itself.
+pub fn validate_charset(
+    field: &'static str,
+    value: &str,
+    charset: &str,
+) -> Result<(), ResourceNameError> {
+    if value == WILDCARD {
+        return Ok(());
+    }
+    match value.chars().find(|c| !charset_contains(charset, *c)) {
+        Some(c) => Err(ResourceNameError::illegal_char_in(
+            field,
+            c,
+            &format!("characters in [{charset}]"),
+        )),
+        None => Ok(()),
+    }
+}
+
+fn charset_contains(charset: &str, c: char) -> bool {
+    let chars: Vec<char> = charset.chars().collect();
+    let mut i = 0;
+    while i < chars.len() {
+        if i + 2 < chars.len() && chars[i + 1] == '-' {
+            if (chars[i]..=chars[i + 2]).contains(&c) {
+                return true;
+            }
+            i += 3;
+        } else {
+            if chars[i] == c {
+                return true;
+            }
+            i += 1;
+        }
+    }
+    false
+}
+
+/// A regular expression a variable must match as a whole, compiled on first use. Requires
+/// the `regex` feature.
+///
+/// The pattern follows the syntax of the `regex` crate. The plugin checks the regexes of
+/// variable rules with Go's `regexp` and rejects the syntax the two read differently, so
+/// that a rule that generates compiles here.
+///
+/// ```
+/// use aip_resourcename::rules::RegexRule;
+///
+/// static BOOK: RegexRule = RegexRule::new("[a-z]+-[0-9]+");
+/// assert!(BOOK.validate("book", "rust-2024").is_ok());
+/// assert!(BOOK.validate("book", "rust-2024-x").is_err());
+/// ```
+#[cfg(feature = "regex")]
+#[derive(Debug)]
+pub struct RegexRule {
+    pattern: &'static str,
+    regex: std::sync::OnceLock<regex::Regex>,
+}
+
+#[cfg(feature = "regex")]
+impl RegexRule {
+    /// Creates a rule for a pattern, which must be a valid regular expression.
+    pub const fn new(pattern: &'static str) -> Self {
+        Self {
+            pattern,
+            regex: std::sync::OnceLock::new(),
+        }
+    }
+
+    /// Validates that a variable matches the pattern as a whole.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pattern is not a valid regular expression.
+    pub fn validate(&self, field: &'static str, value: &str) -> Result<(), ResourceNameError> {
+        let regex = self.regex.get_or_init(|| {
+            regex::Regex::new(&format!("^(?:{})$", self.pattern))
+                .expect("invalid variable rule regex")
+        });
+        if value == WILDCARD || regex.is_match(value) {
+            Ok(())
+        } else {
+            Err(ResourceNameError::invalid_format(
+                field,
+                &format!("match '{}'", self.pattern),
+                value,
+            ))
+        }
+    }
+}
+
+/// Declares a static [`RegexRule`], as the generated validations do. Without the `regex`
+/// feature, it fails to compile with an error that names the feature.
+///
+/// ```
+/// aip_resourcename::regex_rule!(BOOK, "[a-z]+-[0-9]+");
+/// assert!(BOOK.validate("book", "rust-2024").is_ok());
+/// ```
+#[cfg(feature = "regex")]
+#[macro_export]
+macro_rules! regex_rule {
+    ($name:ident, $pattern:literal) => {
+        static $name: $crate::rules::RegexRule = $crate::rules::RegexRule::new($pattern);
+    };
+}
+
+/// Declares a static `RegexRule`, which requires the `regex` feature.
+#[cfg(not(feature = "regex"))]
+#[macro_export]
+macro_rules! regex_rule {
+    ($name:ident, $pattern:literal) => {
+        ::core::compile_error!(::core::concat!(
+            "the variable rule regex \"",
+            $pattern,
+            "\" requires the regex feature of aip-resourcename"
+        ));
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ResourceNameErrorKind as Kind;
+
+    #[test]
+    fn uuid() {
+        for value in [
+            "0F8FAD5B-D9CB-469F-A165-70867728950E",
+            "0f8fad5bd9cb469fa16570867728950e",
+            "0f8fad5b-d9cb-469f-a165-70867728950",
+            "0f8fad5b-d9cb-469f-a165_70867728950e",
+            "",
+        ] {
+            assert_eq!(
+                validate_uuid("book", value).unwrap_err().kind,
+                Kind::InvalidFormat,
+                "{value:?}"
+            );
+        }
+        assert!(validate_uuid("book", WILDCARD).is_ok());
+    }
+
+    #[test]
+    fn max_len_counts_chars() {
+        assert!(validate_max_len("book", "ééé", 3).is_ok());
+        assert_eq!(
+            validate_max_len("book", "éééé", 3),
+            Err(ResourceNameError::too_long("book", 3, 4))
+        );
+    }
+
+    #[test]
+    fn charset() {
+        assert!(validate_charset("user", "abc", "a-z").is_ok());
+        assert_eq!(
+            validate_charset("user", "aBc", "a-z"),
+            Err(ResourceNameError::illegal_char_in(
+                "user",
+                'B',
+                "characters in [a-z]"
+            ))
+        );
+        assert!(validate_charset("user", "a-b", "-a-z").is_ok());
+        assert!(validate_charset("user", "a-b", "a-z-").is_ok());
+        assert!(validate_charset("user", "a-b", "a-z").is_err());
+        assert!(validate_charset("user", "", "a-z").is_ok());
+        assert!(validate_charset("user", WILDCARD, "a-z").is_ok());
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn regex_matches_whole_value() {
+        static RULE: RegexRule = RegexRule::new("a|b");
+        assert!(RULE.validate("key", "a").is_ok());
+        assert!(RULE.validate("key", WILDCARD).is_ok());
+        assert_eq!(
+            RULE.validate("key", "ab"),
+            Err(ResourceNameError::invalid_format(
+                "key",
+                "match 'a|b'",
+                "ab"
+            ))
+        );
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    #[should_panic(expected = "invalid variable rule regex")]
+    fn regex_invalid() {
+        static RULE: RegexRule = RegexRule::new("a{,2}");
+        let _ = RULE.validate("key", "a");
+    }
+}
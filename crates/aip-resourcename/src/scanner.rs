@@ -0,0 +1,217 @@
+use std::str::Split;
+
+use crate::ResourceNameError;
+
+/// Walks the segments of a resource name in a single pass, checking them against a
+/// pattern one segment at a time.
+///
+/// The generated parsers drive a scanner with one call per pattern segment, so parsing
+/// never collects an intermediate `Vec` and fails on the first segment that does not match.
+///
+/// ```
+/// use aip_resourcename::Scanner;
+///
+/// let mut scanner = Scanner::new("projects/p/books/b", 4);
+/// scanner.literal("projects")?;
+/// let project = scanner.variable()?;
+/// scanner.literal("books")?;
+/// let book = scanner.variable()?;
+/// scanner.finish()?;
+/// assert_eq!((project, book), ("p", "b"));
+/// # Ok::<(), aip_resourcename::ResourceNameError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct Scanner<'a> {
+    name: &'a str,
+    parts: Split<'a, char>,
+    position: usize,
+    expected: usize,
+}
+
+impl<'a> Scanner<'a> {
+    /// Creates a scanner over `name` for a pattern with `expected` segments.
+    #[inline]
+    pub fn new(name: &'a str, expected: usize) -> Self {
+        Self {
+            name,
+            parts: name.split('/'),
+            position: 0,
+            expected,
+        }
+    }
+
+    /// Consumes the next segment, which must be equal to `literal`.
+    #[inline]
+    pub fn literal(&mut self, literal: &str) -> Result<(), ResourceNameError> {
+        self.expect(literal).map(|_| ())
+    }
+
+    /// Consumes the next segment and returns it as the value of a variable.
+    #[inline]
+    pub fn variable(&mut self) -> Result<&'a str, ResourceNameError> {
+        self.next()
+    }
+
+    /// Consumes the segments matched by the template of a variable spanning several
+    /// segments, e.g. `projects/*/books/*` or `**`, and returns them as its value.
+    ///
+    /// ```
+    /// use aip_resourcename::Scanner;
+    ///
+    /// let mut scanner = Scanner::new("projects/p/files/a/b.txt", 4);
+    /// let parent = scanner.capture("projects/*")?;
+    /// scanner.literal("files")?;
+    /// let path = scanner.capture("**")?;
+    /// scanner.finish()?;
+    /// assert_eq!((parent, path), ("projects/p", "a/b.txt"));
+    /// # Ok::<(), aip_resourcename::ResourceNameError>(())
+    /// ```
+    pub fn capture(&mut self, template: &str) -> Result<&'a str, ResourceNameError> {
+        let mut first = None;
+        let mut last = "";
+        for segment in template.split('/') {
+            last = match segment {
+                "*" => self.next()?,
+                "**" => {
+                    let part = self.next()?;
+                    first.get_or_insert(part);
+                    self.rest().unwrap_or(part)
+                }
+                literal => self.expect(literal)?,
+            };
+            first.get_or_insert(last);
+        }
+        Ok(crate::span(self.name, first.unwrap_or(last), last))
+    }
+
+    /// Checks that the whole name has been consumed.
+    #[inline]
+    pub fn finish(mut self) -> Result<(), ResourceNameError> {
+        if self.parts.next().is_some() {
+            return Err(ResourceNameError::wrong_segment_count(
+                self.expected,
+                self.position + 1 + self.parts.count(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Consumes the remaining segments and returns the last one.
+    fn rest(&mut self) -> Option<&'a str> {
+        self.parts.by_ref().inspect(|_| self.position += 1).last()
+    }
+
+    #[inline]
+    fn expect(&mut self, literal: &str) -> Result<&'a str, ResourceNameError> {
+        match self.next()? {
+            part if part == literal => Ok(part),
+            part => Err(ResourceNameError::unexpected_literal(
+                self.position - 1,
+                literal,
+                part,
+            )),
+        }
+    }
+
+    #[inline]
+    fn next(&mut self) -> Result<&'a str, ResourceNameError> {
+        let Some(part) = self.parts.next() else {
+            return Err(ResourceNameError::wrong_segment_count(
+                self.expected,
+                self.position,
+            ));
+        };
+        self.position += 1;
+        Ok(part)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ResourceNameErrorKind as Kind;
+
+    #[test]
+    fn too_few_segments() {
+        let mut scanner = Scanner::new("projects/p", 4);
+        scanner.literal("projects").unwrap();
+        scanner.variable().unwrap();
+        assert_eq!(
+            scanner.literal("books"),
+            Err(ResourceNameError::wrong_segment_count(4, 2))
+        );
+    }
+
+    #[test]
+    fn too_many_segments() {
+        let mut scanner = Scanner::new("projects/p/books/b/x/y", 4);
+        scanner.literal("projects").unwrap();
+        scanner.variable().unwrap();
+        scanner.literal("books").unwrap();
+        scanner.variable().unwrap();
+        assert_eq!(
+            scanner.finish(),
+            Err(ResourceNameError::wrong_segment_count(4, 6))
+        );
+    }
+
+    #[test]
+    fn unexpected_literal() {
+        let mut scanner = Scanner::new("projects/p/shelves/s", 4);
+        scanner.literal("projects").unwrap();
+        scanner.variable().unwrap();
+        assert_eq!(
+            scanner.literal("books"),
+            Err(ResourceNameError::unexpected_literal(2, "books", "shelves"))
+        );
+    }
+
+    #[test]
+    fn empty_segments_are_values() {
+        // Empty values are rejected by the validation of the generated names
+        let mut scanner = Scanner::new("projects//books/", 4);
+        scanner.literal("projects").unwrap();
+        assert_eq!(scanner.variable(), Ok(""));
+        scanner.literal("books").unwrap();
+        assert_eq!(scanner.variable(), Ok(""));
+        assert!(scanner.finish().is_ok());
+        assert_eq!(
+            Scanner::new("/projects", 2).literal("projects"),
+            Err(ResourceNameError::unexpected_literal(0, "projects", ""))
+        );
+    }
+
+    #[test]
+    fn capture_errors() {
+        let mut scanner = Scanner::new("files", 2);
+        scanner.literal("files").unwrap();
+        assert_eq!(
+            scanner.capture("**").unwrap_err().kind,
+            Kind::WrongSegmentCount
+        );
+
+        let mut scanner = Scanner::new("users/u/books/b", 4);
+        assert_eq!(
+            scanner.capture("users/*/shelves/*"),
+            Err(ResourceNameError::unexpected_literal(2, "shelves", "books"))
+        );
+
+        let mut scanner = Scanner::new("users/u", 4);
+        assert_eq!(
+            scanner.capture("users/*/shelves/*"),
+            Err(ResourceNameError::wrong_segment_count(4, 2))
+        );
+    }
+
+    #[test]
+    fn capture_rest() {
+        let mut scanner = Scanner::new("files/a", 2);
+        scanner.literal("files").unwrap();
+        assert_eq!(scanner.capture("**"), Ok("a"));
+        assert!(scanner.finish().is_ok());
+
+        let mut scanner = Scanner::new("a/b/c", 1);
+        assert_eq!(scanner.capture("**"), Ok("a/b/c"));
+        assert!(scanner.finish().is_ok());
+    }
+}
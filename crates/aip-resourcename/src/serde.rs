@@ -0,0 +1,315 @@
+//! Serde support for resource names, enabled by the `serde` feature.
+//!
+//! The generated `Serialize` and `Deserialize` implementations delegate to these functions.
+//! Names are either serialized as their canonical string, or structured as a map from the
+//! variables of their pattern to their values:
+//!
+//! ```text
+//! "projects/my-project/books/rust-book"
+//! {"project": "my-project", "book": "rust-book"}
+//! ```
+//!
+//! In the structured form, each variable is deserialized into its own field and the name is
+//! then validated, so a value cannot spill into the segments of another variable. A
+//! multi-pattern name deserializes to the pattern with exactly its variables. Names that
+//! cannot be told apart by their variables, such as those of patterns unknown to a
+//! `FUTURE_MULTI_PATTERN` resource, are serialized as their string in the structured form too.
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use ::serde::de::{self, MapAccess, Visitor};
+use ::serde::ser::SerializeMap;
+pub use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{ResourceName, ResourceNameError};
+
+/// Serializes a resource name as its canonical string.
+pub fn serialize_str<T, S>(name: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: fmt::Display + ?Sized,
+    S: Serializer,
+{
+    serializer.collect_str(name)
+}
+
+/// Deserializes a resource name from its canonical string.
+pub fn deserialize_str<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: FromStr<Err = ResourceNameError>,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_str(StrVisitor(PhantomData))
+}
+
+struct StrVisitor<T>(PhantomData<T>);
+
+impl<T: FromStr<Err = ResourceNameError>> Visitor<'_> for StrVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a resource name")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<T, E> {
+        v.parse().map_err(E::custom)
+    }
+}
+
+/// Serializes a resource name as a map from the variables of its pattern to their values.
+pub fn serialize_variables<S, const N: usize>(
+    serializer: S,
+    variables: [(&str, &str); N],
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let mut map = serializer.serialize_map(Some(N))?;
+    for (variable, value) in variables {
+        map.serialize_entry(variable, value)?;
+    }
+    map.end()
+}
+
+/// Deserializes the values of the variables of a pattern from a map, which must hold exactly
+/// these variables. The values are returned in the order of `variables`.
+pub fn deserialize_variables<'de, D, const N: usize>(
+    deserializer: D,
+    variables: [&str; N],
+) -> Result<[String; N], D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let mut map = Variables::deserialize(deserializer)?;
+    map.take(variables)
+        .ok_or_else(|| map.no_match(&[&variables[..]]))
+}
+
+/// Validates a resource name built from deserialized variables.
+pub fn validated<T, E>(name: T) -> Result<T, E>
+where
+    T: ResourceName,
+    E: de::Error,
+{
+    name.validate().map_err(E::custom)?;
+    Ok(name)
+}
+
+/// The variables of a resource name, deserialized from a map before the pattern they belong
+/// to is known.
+#[derive(Debug, Default)]
+pub struct Variables(Vec<(String, String)>);
+
+impl Variables {
+    /// Takes the values of `variables` if they are exactly the deserialized variables, in the
+    /// order of `variables`.
+    pub fn take<const N: usize>(&mut self, variables: [&str; N]) -> Option<[String; N]> {
+        if self.0.len() != N || !variables.iter().all(|v| self.0.iter().any(|(k, _)| k == v)) {
+            return None;
+        }
+        Some(variables.map(|variable| {
+            let (_, value) = self.0.iter_mut().find(|(key, _)| key == variable).unwrap();
+            std::mem::take(value)
+        }))
+    }
+
+    /// Returns the error for variables that are exactly the variables of none of the patterns,
+    /// given by their variables.
+    pub fn no_match<E: de::Error>(&self, patterns: &[&[&str]]) -> E {
+        let keys: Vec<&str> = self.0.iter().map(|(key, _)| key.as_str()).collect();
+        E::custom(format!(
+            "expected the variables of one of {patterns:?}, got {keys:?}"
+        ))
+    }
+}
+
+impl<'de> Deserialize<'de> for Variables {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(VariablesVisitor)
+    }
+}
+
+struct VariablesVisitor;
+
+impl<'de> Visitor<'de> for VariablesVisitor {
+    type Value = Variables;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a map of resource name variables")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut variables = Vec::with_capacity(map.size_hint().unwrap_or(0));
+        while let Some((key, value)) = map.next_entry::<String, String>()? {
+            if variables.iter().any(|(k, _)| *k == key) {
+                return Err(de::Error::custom(format!("duplicate variable {key}")));
+            }
+            variables.push((key, value));
+        }
+        Ok(Variables(variables))
+    }
+}
+
+/// A resource name deserialized either from a map of variables, or from its canonical string,
+/// as the names that cannot be told apart by their variables are serialized. Deserializing it
+/// requires a self-describing format, such as JSON.
+#[derive(Debug)]
+pub enum VariablesOrName<T> {
+    /// The variables, for the caller to match to a pattern.
+    Variables(Variables),
+    /// The name parsed from its string.
+    Name(T),
+}
+
+impl<'de, T: FromStr<Err = ResourceNameError>> Deserialize<'de> for VariablesOrName<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(VariablesOrNameVisitor(PhantomData))
+    }
+}
+
+struct VariablesOrNameVisitor<T>(PhantomData<T>);
+
+impl<'de, T: FromStr<Err = ResourceNameError>> Visitor<'de> for VariablesOrNameVisitor<T> {
+    type Value = VariablesOrName<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a map of resource name variables or a resource name")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        VariablesVisitor
+            .visit_map(map)
+            .map(VariablesOrName::Variables)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        v.parse().map(VariablesOrName::Name).map_err(E::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{reject_separator, validate_segment, PathTemplate};
+
+    /// A complex resource name as generated with `serde=structured`.
+    #[derive(Debug, PartialEq)]
+    struct Key {
+        a: String,
+        b: String,
+    }
+
+    impl fmt::Display for Key {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "keys/{}~{}", self.a, self.b)
+        }
+    }
+
+    impl FromStr for Key {
+        type Err = ResourceNameError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let values = PathTemplate::parse(Self::PATTERNS[0])?.sscan(s)?;
+            let key = Key {
+                a: values[0].to_string(),
+                b: values[1].to_string(),
+            };
+            key.validate()?;
+            Ok(key)
+        }
+    }
+
+    impl ResourceName for Key {
+        const TYPE: &'static str = "example.googleapis.com/Key";
+        const PATTERNS: &'static [&'static str] = &["keys/{a}~{b}"];
+        const SINGULAR: &'static str = "key";
+        const PLURAL: &'static str = "keys";
+
+        fn validate(&self) -> Result<(), ResourceNameError> {
+            validate_segment("a", &self.a)?;
+            validate_segment("b", &self.b)?;
+            reject_separator("a", &self.a, '~')
+        }
+
+        fn contains_wildcard(&self) -> bool {
+            false
+        }
+    }
+
+    impl Serialize for Key {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serialize_variables(serializer, [("a", &self.a), ("b", &self.b)])
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Key {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let [a, b] = deserialize_variables(deserializer, ["a", "b"])?;
+            validated(Self { a, b })
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let key = Key {
+            a: "x".to_string(),
+            b: "y~z".to_string(),
+        };
+        let json = serde_json::to_string(&key).unwrap();
+        assert_eq!(json, r#"{"a":"x","b":"y~z"}"#);
+        assert_eq!(serde_json::from_str::<Key>(&json).unwrap(), key);
+        let json = r#"{"b":"y","a":"x"}"#;
+        assert_eq!(
+            serde_json::from_str::<Key>(json).unwrap().to_string(),
+            "keys/x~y"
+        );
+    }
+
+    #[test]
+    fn values_stay_in_their_variable() {
+        // Joined and parsed again, this would read as a = "x" and b = "y~z"
+        let err = serde_json::from_str::<Key>(r#"{"a":"x~y","b":"z"}"#).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("a: contains illegal character '~'"),
+            "{err}"
+        );
+        let err = serde_json::from_str::<Key>(r#"{"a":"x/y","b":"z"}"#).unwrap_err();
+        assert!(err.to_string().contains("illegal character '/'"), "{err}");
+    }
+
+    #[test]
+    fn unknown_variables() {
+        for json in [
+            r#"{"a":"x"}"#,
+            r#"{"a":"x","c":"y"}"#,
+            r#"{"a":"x","b":"y","c":"z"}"#,
+        ] {
+            let err = serde_json::from_str::<Key>(json).unwrap_err();
+            assert!(err.to_string().contains("expected the variables"), "{err}");
+        }
+        let err = serde_json::from_str::<Key>(r#"{"a":"x","a":"y"}"#).unwrap_err();
+        assert!(err.to_string().contains("duplicate variable a"), "{err}");
+    }
+
+    #[test]
+    fn take_exact_variables() {
+        let mut variables: Variables = serde_json::from_str(r#"{"b":"y","a":"x"}"#).unwrap();
+        assert_eq!(variables.take(["a"]), None);
+        assert_eq!(variables.take(["a", "c"]), None);
+        assert_eq!(
+            variables.take(["a", "b"]),
+            Some(["x".to_string(), "y".to_string()])
+        );
+    }
+
+    #[test]
+    fn variables_or_name() {
+        let name: VariablesOrName<Key> = serde_json::from_str(r#""keys/x~y""#).unwrap();
+        assert!(matches!(name, VariablesOrName::Name(key) if key.a == "x"));
+        let name: VariablesOrName<Key> = serde_json::from_str(r#"{"a":"x"}"#).unwrap();
+        assert!(matches!(name, VariablesOrName::Variables(_)));
+        assert!(serde_json::from_str::<VariablesOrName<Key>>(r#""keys/x""#).is_err());
+    }
+}
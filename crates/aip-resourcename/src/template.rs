@@ -0,0 +1,410 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{ResourceNameError, Scanner, WILDCARD};
+
+/// The literals that can separate the variables of a complex segment.
+const SEPARATORS: [char; 4] = ['~', '.', '-', '_'];
+
+/// A segment of a resource name pattern.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Segment {
+    /// A literal segment, e.g. `projects`.
+    Literal(String),
+    /// A variable segment, e.g. `{project}`, holding the variable name.
+    Variable(String),
+    /// A segment holding several variables, e.g. `{key_a}~{key_b}`. Its parts are the
+    /// variables and the single-character literals separating them: `~`, `.`, `-` or `_`.
+    Complex { raw: String, parts: Vec<Segment> },
+    /// A variable whose value spans the segments matched by a template of literals and
+    /// wildcards, e.g. `{name=projects/*/books/*}` or `{path=**}`.
+    Capture {
+        name: String,
+        template: PathTemplate,
+    },
+    /// `*`, an anonymous variable matching any single segment.
+    Wildcard,
+    /// `**`, an anonymous variable matching the rest of the name. It must come last.
+    MultiWildcard,
+}
+
+impl Segment {
+    /// Returns true if this segment is a variable (e.g., `{project}`).
+    pub fn is_variable(&self) -> bool {
+        matches!(self, Segment::Variable(_))
+    }
+
+    /// Returns the literal value of the segment, the name of the variable, or the raw
+    /// complex segment.
+    pub fn literal(&self) -> &str {
+        match self {
+            Segment::Literal(literal) | Segment::Variable(literal) => literal,
+            Segment::Complex { raw, .. } => raw,
+            Segment::Capture { name, .. } => name,
+            Segment::Wildcard => "*",
+            Segment::MultiWildcard => "**",
+        }
+    }
+
+    /// Returns the names of the variables of the segment, in order.
+    pub fn variables(&self) -> impl Iterator<Item = &str> {
+        let parts = match self {
+            Segment::Complex { parts, .. } => parts.as_slice(),
+            segment => std::slice::from_ref(segment),
+        };
+        parts
+            .iter()
+            .filter(|part| matches!(part, Segment::Variable(_) | Segment::Capture { .. }))
+            .map(Segment::literal)
+    }
+
+    /// Returns true if this segment matches the rest of the name: `**`, or a capture ending
+    /// with it.
+    fn is_multi_wildcard(&self) -> bool {
+        match self {
+            Segment::MultiWildcard => true,
+            Segment::Capture { template, .. } => template
+                .segments
+                .last()
+                .is_some_and(Segment::is_multi_wildcard),
+            _ => false,
+        }
+    }
+}
+
+/// A parsed resource name pattern, such as `projects/{project}/books/{book}`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PathTemplate {
+    pattern: String,
+    segments: Vec<Segment>,
+}
+
+impl PathTemplate {
+    /// Parses a resource name pattern into segments, following the path template syntax of
+    /// `google.api.http`: besides literals and `{variable}`, a segment can be `*`, `**`, or a
+    /// variable spanning several segments, such as `{name=projects/*/books/*}`.
+    ///
+    /// ```
+    /// use aip_resourcename::PathTemplate;
+    ///
+    /// let template = PathTemplate::parse("{parent=projects/*}/files/{path=**}")?;
+    /// assert_eq!(template.sscan("projects/p/files/a/b.txt")?, ["projects/p", "a/b.txt"]);
+    /// assert_eq!(template.sprint(&["projects/p", "c"]), "projects/p/files/c");
+    /// # Ok::<(), aip_resourcename::ResourceNameError>(())
+    /// ```
+    pub fn parse(pattern: &str) -> Result<Self, ResourceNameError> {
+        let mut segments = Vec::new();
+        let mut parts = split_pattern(pattern).enumerate().peekable();
+        while let Some((i, part)) = parts.next() {
+            let segment = if part == "*" {
+                Segment::Wildcard
+            } else if part == "**" {
+                Segment::MultiWildcard
+            } else if let Some(segment) = parse_variable(part) {
+                segment
+            } else if is_valid_literal(part) {
+                Segment::Literal(part.to_string())
+            } else if let Some(parts) = parse_complex(part) {
+                Segment::Complex {
+                    raw: part.to_string(),
+                    parts,
+                }
+            } else {
+                return Err(ResourceNameError::invalid_pattern(pattern, i, part));
+            };
+            if segment.is_multi_wildcard() && parts.peek().is_some() {
+                return Err(ResourceNameError::invalid_pattern(pattern, i, part));
+            }
+            segments.push(segment);
+        }
+        Ok(Self {
+            pattern: pattern.to_string(),
+            segments,
+        })
+    }
+
+    /// Returns the pattern the template was parsed from.
+    pub fn as_str(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Returns the segments of the pattern.
+    pub fn segments(&self) -> &[Segment] {
+        &self.segments
+    }
+
+    /// Returns the names of the variables of the pattern, in order.
+    pub fn variables(&self) -> impl Iterator<Item = &str> {
+        self.segments.iter().flat_map(Segment::variables)
+    }
+
+    /// Returns the segments of the pattern with the templates of captures expanded, so that
+    /// each matches a single segment of a name, apart from a trailing `**`.
+    pub(crate) fn expanded_segments(&self) -> impl Iterator<Item = &Segment> {
+        self.segments.iter().flat_map(|segment| match segment {
+            Segment::Capture { template, .. } => template.segments.iter(),
+            segment => std::slice::from_ref(segment).iter(),
+        })
+    }
+
+    /// Formats variables according to the pattern. Variables missing from `variables`
+    /// are left empty, and anonymous wildcards are formatted as the [`WILDCARD`].
+    pub fn sprint(&self, variables: &[&str]) -> String {
+        let mut variables = variables.iter();
+        let mut result = String::with_capacity(self.pattern.len());
+        for (i, segment) in self.segments.iter().enumerate() {
+            if i > 0 {
+                result.push('/');
+            }
+            let parts = match segment {
+                Segment::Complex { parts, .. } => parts.as_slice(),
+                segment => std::slice::from_ref(segment),
+            };
+            for part in parts {
+                match part {
+                    Segment::Variable(_) | Segment::Capture { .. } => {
+                        result.push_str(variables.next().unwrap_or(&""))
+                    }
+                    Segment::Wildcard | Segment::MultiWildcard => result.push_str(WILDCARD),
+                    _ => result.push_str(part.literal()),
+                }
+            }
+        }
+        result
+    }
+
+    /// Scans a resource name and returns the values of the variables, in order. Anonymous
+    /// wildcards are matched but not returned.
+    pub fn sscan<'a>(&self, name: &'a str) -> Result<Vec<&'a str>, ResourceNameError> {
+        let mut scanner = Scanner::new(name, self.expanded_segments().count());
+        let mut variables = Vec::new();
+        let mut position = 0;
+        for segment in &self.segments {
+            match segment {
+                Segment::Literal(literal) => scanner.literal(literal)?,
+                Segment::Variable(_) => variables.push(scanner.variable()?),
+                Segment::Complex { raw, parts } => {
+                    let value = scanner.variable()?;
+                    let values = split_complex(parts, value).ok_or_else(|| {
+                        ResourceNameError::unexpected_literal(position, raw, value)
+                    })?;
+                    variables.extend(values);
+                }
+                Segment::Capture { template, .. } => {
+                    let value = scanner.capture(template.as_str())?;
+                    position += value.split('/').count() - 1;
+                    variables.push(value);
+                }
+                Segment::Wildcard => {
+                    scanner.variable()?;
+                }
+                Segment::MultiWildcard => {
+                    scanner.capture("**")?;
+                }
+            }
+            position += 1;
+        }
+        scanner.finish()?;
+        Ok(variables)
+    }
+
+    /// Tests whether a resource name matches the pattern.
+    pub fn matches(&self, name: &str) -> bool {
+        self.sscan(name).is_ok()
+    }
+}
+
+impl FromStr for PathTemplate {
+    type Err = ResourceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl fmt::Display for PathTemplate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.pattern)
+    }
+}
+
+/// Splits a pattern at the slashes outside of variables, which may hold templates such as
+/// `{name=projects/*}`.
+fn split_pattern(pattern: &str) -> impl Iterator<Item = &str> {
+    let mut depth = 0;
+    pattern.split(move |c| {
+        match c {
+            '{' => depth += 1,
+            '}' => depth -= 1,
+            _ => {}
+        }
+        c == '/' && depth == 0
+    })
+}
+
+/// Parses a variable segment, `{name}` or `{name=template}`, where the template is made of
+/// literals and wildcards. `{name=*}` is the same as `{name}`.
+fn parse_variable(segment: &str) -> Option<Segment> {
+    let variable = segment.strip_prefix('{')?.strip_suffix('}')?;
+    let (name, template) = variable.split_once('=').unwrap_or((variable, "*"));
+    if !is_valid_name(name) {
+        return None;
+    }
+    if template == "*" {
+        return Some(Segment::Variable(name.to_string()));
+    }
+    let template = PathTemplate::parse(template).ok()?;
+    let anonymous = template.segments.iter().all(|segment| {
+        matches!(
+            segment,
+            Segment::Literal(_) | Segment::Wildcard | Segment::MultiWildcard
+        )
+    });
+    anonymous.then(|| Segment::Capture {
+        name: name.to_string(),
+        template,
+    })
+}
+
+/// Parses the parts of a complex segment, e.g. `{key_a}~{key_b}`, or returns `None` if the
+/// segment is not made of variables separated by single separators.
+fn parse_complex(segment: &str) -> Option<Vec<Segment>> {
+    let mut parts = Vec::new();
+    let mut rest = segment;
+    loop {
+        let (variable, tail) = rest.strip_prefix('{')?.split_once('}')?;
+        if !is_valid_name(variable) {
+            return None;
+        }
+        parts.push(Segment::Variable(variable.to_string()));
+        let mut chars = tail.chars();
+        match chars.next() {
+            None => break,
+            Some(separator) if SEPARATORS.contains(&separator) => {
+                parts.push(Segment::Literal(separator.to_string()));
+                rest = chars.as_str();
+            }
+            Some(_) => return None,
+        }
+    }
+    (parts.len() > 1).then_some(parts)
+}
+
+/// Splits the value of a complex segment into the values of its variables, each ending at
+/// the first following separator, or returns `None` if a separator is missing.
+fn split_complex<'a>(parts: &[Segment], value: &'a str) -> Option<Vec<&'a str>> {
+    let mut values = Vec::with_capacity(parts.len() / 2 + 1);
+    let mut rest = value;
+    for separator in parts.iter().filter(|part| !part.is_variable()) {
+        let (head, tail) = rest.split_once(separator.literal())?;
+        values.push(head);
+        rest = tail;
+    }
+    values.push(rest);
+    Some(values)
+}
+
+/// Reports whether a variable name is a field path, such as `book` or `book.name`.
+fn is_valid_name(s: &str) -> bool {
+    !s.is_empty()
+        && s.split('.').all(|ident| {
+            !ident.is_empty() && ident.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        })
+}
+
+/// Reports whether a pattern segment is non-empty and free of braces.
+fn is_valid_literal(s: &str) -> bool {
+    !s.is_empty() && !s.contains(['{', '}'])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ResourceNameErrorKind as Kind;
+
+    #[test]
+    fn parse_errors() {
+        for (pattern, position, segment) in [
+            ("", 0, ""),
+            ("/projects/{project}", 0, ""),
+            ("projects/{project}/", 2, ""),
+            ("projects//{project}", 1, ""),
+            ("projects/{project-id}", 1, "{project-id}"),
+            ("projects/{}", 1, "{}"),
+            ("projects/{a={b}}", 1, "{a={b}}"),
+            ("projects/{a}+{b}", 1, "{a}+{b}"),
+            ("projects/{a}~~{b}", 1, "{a}~~{b}"),
+            ("files/**/x", 1, "**"),
+            ("{path=**}/x", 0, "{path=**}"),
+            ("{path=a/**/b}", 0, "{path=a/**/b}"),
+            ("{name={a}/b}", 0, "{name={a}/b}"),
+        ] {
+            assert_eq!(
+                PathTemplate::parse(pattern),
+                Err(ResourceNameError::invalid_pattern(
+                    pattern, position, segment
+                )),
+                "{pattern:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn parse_segments() {
+        let template = PathTemplate::parse("{parent=projects/*}/keys/{a}~{b}/*/**").unwrap();
+        assert_eq!(
+            template.variables().collect::<Vec<_>>(),
+            ["parent", "a", "b"]
+        );
+        assert!(matches!(template.segments()[0], Segment::Capture { .. }));
+        assert!(matches!(template.segments()[2], Segment::Complex { .. }));
+        assert_eq!(template.segments()[3], Segment::Wildcard);
+        assert_eq!(template.segments()[4], Segment::MultiWildcard);
+        assert_eq!(
+            PathTemplate::parse("{book=*}").unwrap().segments(),
+            [Segment::Variable("book".to_string())]
+        );
+    }
+
+    #[test]
+    fn sscan_errors() {
+        let template = PathTemplate::parse("projects/{project}/books/{book}").unwrap();
+        for (name, kind) in [
+            ("projects/p/books", Kind::WrongSegmentCount),
+            ("projects/p/books/b/c", Kind::WrongSegmentCount),
+            ("projects/p/shelves/b", Kind::UnexpectedLiteral),
+        ] {
+            assert_eq!(template.sscan(name).unwrap_err().kind, kind, "{name}");
+        }
+
+        let template = PathTemplate::parse("projects/{project}/keys/{a}~{b}").unwrap();
+        for (name, kind) in [
+            ("projects/p/keys", Kind::WrongSegmentCount),
+            ("projects/p/keys/a~b/c", Kind::WrongSegmentCount),
+            ("projects/p/books/a~b", Kind::UnexpectedLiteral),
+            ("projects/p/keys/a.b", Kind::UnexpectedLiteral),
+        ] {
+            assert_eq!(template.sscan(name).unwrap_err().kind, kind, "{name}");
+        }
+        assert_eq!(
+            template.sscan("projects/p/keys/a~b~c").unwrap(),
+            ["p", "a", "b~c"]
+        );
+
+        let template = PathTemplate::parse("{parent=users/*/shelves/*}/files/{path=**}").unwrap();
+        for (name, kind) in [
+            ("users/u/shelves/s/files", Kind::WrongSegmentCount),
+            ("users/u/books/s/files/a", Kind::UnexpectedLiteral),
+            ("users/u/shelves/s/books/a", Kind::UnexpectedLiteral),
+        ] {
+            assert_eq!(template.sscan(name).unwrap_err().kind, kind, "{name}");
+        }
+    }
+
+    #[test]
+    fn sprint_missing_variables() {
+        let template = PathTemplate::parse("projects/{project}/books/{book}").unwrap();
+        assert_eq!(template.sprint(&["p"]), "projects/p/books/");
+        assert!(!template.matches("projects/p/books"));
+    }
+}
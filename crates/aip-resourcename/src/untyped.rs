@@ -0,0 +1,284 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{
+    reject_wildcard, validate, validate_segment, ResourceName, ResourceNameError, WILDCARD,
+};
+
+/// A resource name of any type, e.g. referenced with `type: "*"`, made of collection/ID
+/// pairs. A collection without an ID at the end is a singleton, which must be nested under
+/// at least one pair, so that a bare collection such as `books` is not a name.
+///
+/// ```
+/// use aip_resourcename::UntypedResourceName;
+///
+/// let name: UntypedResourceName = "projects/my-project/books/rust-book".parse()?;
+/// assert_eq!(name.collection_ids().collect::<Vec<_>>(), ["projects", "books"]);
+/// assert_eq!(name.last_id(), Some("rust-book"));
+/// assert_eq!(name.parent().unwrap().to_string(), "projects/my-project");
+/// assert_eq!(name.parent().unwrap().parent(), None);
+///
+/// for (collection, id) in &name {
+///     println!("{collection}: {id:?}");
+/// }
+/// # Ok::<(), aip_resourcename::ResourceNameError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UntypedResourceName {
+    name: String,
+}
+
+impl UntypedResourceName {
+    /// Returns the name as a string.
+    pub fn as_str(&self) -> &str {
+        &self.name
+    }
+
+    /// Borrows the name as an [`UntypedResourceNameRef`].
+    pub fn as_borrowed(&self) -> UntypedResourceNameRef<'_> {
+        UntypedResourceNameRef { name: &self.name }
+    }
+
+    /// Returns the collection/ID pairs of the name, in order.
+    pub fn iter(&self) -> Pairs<'_> {
+        self.as_borrowed().iter()
+    }
+
+    /// Returns the collection IDs of the name, e.g. `projects` and `books`.
+    pub fn collection_ids(&self) -> impl Iterator<Item = &str> {
+        self.iter().map(|(collection, _)| collection)
+    }
+
+    /// Returns the ID of the resource, or `None` for singletons.
+    pub fn last_id(&self) -> Option<&str> {
+        self.iter().last().and_then(|(_, id)| id)
+    }
+
+    /// Returns the name of the parent resource, or `None` for top-level resources.
+    pub fn parent(&self) -> Option<UntypedResourceName> {
+        let kept = self
+            .iter()
+            .count()
+            .checked_sub(1)
+            .filter(|&kept| kept > 0)?;
+        let end = self
+            .name
+            .match_indices('/')
+            .map(|(i, _)| i)
+            .nth(2 * kept - 1)?;
+        Some(Self {
+            name: self.name[..end].to_string(),
+        })
+    }
+
+    /// Returns true if any ID is a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        self.as_borrowed().contains_wildcard()
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard ID matches any ID.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        self.iter().count() == concrete.iter().count()
+            && self
+                .iter()
+                .zip(concrete)
+                .all(|((collection, id), (other, other_id))| {
+                    collection == other
+                        && (id == other_id || (id == Some(WILDCARD) && other_id.is_some()))
+                })
+    }
+
+    /// Rejects wildcard IDs, e.g. in the name of a resource to create.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        self.iter()
+            .filter_map(|(_, id)| id)
+            .try_for_each(|id| reject_wildcard("id", id))
+    }
+
+    /// Converts the name into a typed resource name, e.g. `try_into::<BookResourceName>()`.
+    /// It takes the name by value so that it is not shadowed by [`TryInto::try_into`].
+    pub fn try_into<T: ResourceName>(self) -> Result<T, ResourceNameError> {
+        self.name.parse()
+    }
+}
+
+impl fmt::Display for UntypedResourceName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.name)
+    }
+}
+
+impl FromStr for UntypedResourceName {
+    type Err = ResourceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        UntypedResourceNameRef::parse_borrowed(s).map(UntypedResourceNameRef::to_owned)
+    }
+}
+
+impl<'a> IntoIterator for &'a UntypedResourceName {
+    type Item = (&'a str, Option<&'a str>);
+    type IntoIter = Pairs<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Borrowed view of an [`UntypedResourceName`] that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UntypedResourceNameRef<'a> {
+    name: &'a str,
+}
+
+impl<'a> UntypedResourceNameRef<'a> {
+    /// Parses a resource name of any type without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        validate(s)?;
+        if !s.contains('/') {
+            return Err(ResourceNameError::invalid_format(
+                "name",
+                "start with a collection/ID pair",
+                s,
+            ));
+        }
+        let name = Self { name: s };
+        for (collection, id) in name.iter() {
+            let mut chars = collection.chars();
+            if !chars.next().is_some_and(|c| c.is_ascii_lowercase())
+                || !chars.all(|c| c.is_ascii_alphanumeric())
+            {
+                return Err(ResourceNameError::invalid_format(
+                    "collection",
+                    "be a lowerCamelCase identifier",
+                    collection,
+                ));
+            }
+            if let Some(id) = id {
+                validate_segment("id", id)?;
+            }
+        }
+        Ok(name)
+    }
+
+    /// Returns the name as a string.
+    pub fn as_str(&self) -> &'a str {
+        self.name
+    }
+
+    /// Returns the collection/ID pairs of the name, in order.
+    pub fn iter(&self) -> Pairs<'a> {
+        Pairs(self.name.split('/'))
+    }
+
+    /// Returns true if any ID is a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        self.iter().any(|(_, id)| id == Some(WILDCARD))
+    }
+
+    /// Converts the borrowed view into an owned [`UntypedResourceName`].
+    pub fn to_owned(self) -> UntypedResourceName {
+        UntypedResourceName {
+            name: self.name.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for UntypedResourceNameRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name)
+    }
+}
+
+/// Iterator over the collection/ID pairs of an [`UntypedResourceName`]. The ID is `None` for
+/// a singleton at the end of the name.
+#[derive(Debug, Clone)]
+pub struct Pairs<'a>(std::str::Split<'a, char>);
+
+impl<'a> Iterator for Pairs<'a> {
+    type Item = (&'a str, Option<&'a str>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let collection = self.0.next()?;
+        Some((collection, self.0.next()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ResourceNameErrorKind as Kind, MAX_SEGMENT_LEN};
+
+    #[test]
+    fn parse_errors() {
+        for (name, kind) in [
+            ("", Kind::Empty),
+            ("/projects/p", Kind::Empty),
+            ("projects/p/", Kind::Empty),
+            ("projects//books/b", Kind::Empty),
+            ("Projects/p", Kind::InvalidFormat),
+            ("my_projects/p", Kind::InvalidFormat),
+            ("1projects/p", Kind::InvalidFormat),
+            ("projects/p q", Kind::IllegalChar),
+            ("projects/p/books/café", Kind::IllegalChar),
+        ] {
+            assert_eq!(
+                name.parse::<UntypedResourceName>().unwrap_err().kind,
+                kind,
+                "{name:?}"
+            );
+        }
+        let long = format!("projects/{}", "p".repeat(MAX_SEGMENT_LEN + 1));
+        assert_eq!(
+            long.parse::<UntypedResourceName>(),
+            Err(ResourceNameError::too_long(
+                "id",
+                MAX_SEGMENT_LEN,
+                MAX_SEGMENT_LEN + 1
+            ))
+        );
+    }
+
+    #[test]
+    fn singletons() {
+        let name: UntypedResourceName = "projects/p/settings".parse().unwrap();
+        assert_eq!(name.last_id(), None);
+        assert_eq!(name.parent().unwrap().as_str(), "projects/p");
+        assert_eq!(
+            name.iter().collect::<Vec<_>>(),
+            [("projects", Some("p")), ("settings", None)]
+        );
+        let top: UntypedResourceName = "projects/p".parse().unwrap();
+        assert_eq!(top.parent(), None);
+        // A singleton is nested under a resource, so a bare collection is not a name
+        for bare in ["settings", "x"] {
+            assert_eq!(
+                bare.parse::<UntypedResourceName>().unwrap_err().kind,
+                Kind::InvalidFormat,
+                "{bare:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn wildcards() {
+        let wild: UntypedResourceName = "projects/-/books/b".parse().unwrap();
+        let concrete: UntypedResourceName = "projects/p/books/b".parse().unwrap();
+        assert!(wild.matches(&concrete) && !concrete.matches(&wild));
+        assert_eq!(
+            wild.validate_concrete(),
+            Err(ResourceNameError::wildcard("id"))
+        );
+        let singleton: UntypedResourceName = "projects/p/settings".parse().unwrap();
+        let books: UntypedResourceName = "projects/-/books".parse().unwrap();
+        assert!(!books.matches(&singleton));
+        // A wildcard stands for an ID, never for a collection
+        assert_eq!(
+            "projects/p/-"
+                .parse::<UntypedResourceName>()
+                .unwrap_err()
+                .kind,
+            Kind::InvalidFormat
+        );
+    }
+}
@@ -0,0 +1,189 @@
+//! Compares the generated streaming `FromStr` parsers with the previous
+//! collect-into-`Vec` output of the plugin, and with the borrowed `Ref` views.
+//!
+//! Run with `make bench` or `cargo bench` from this directory. Each group benchmarks the
+//! previous parser as `legacy` next to the generated `from_str` and `parse_borrowed`.
+
+use std::hint::black_box;
+use std::str::FromStr;
+
+use build_with_buf::*;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+const REVIEW: &str = "projects/my-project/books/rust-programming/reviews/review-1";
+const PUBLISHER_FIRST: &str = "publishers/no-starch";
+const PUBLISHER_LAST: &str = "projects/my-project/publishers/no-starch";
+const PUBLISHER_INVALID: &str = "projects/my-project/shelves/no-starch";
+
+fn review(c: &mut Criterion) {
+    assert_eq!(
+        legacy::parse_review(REVIEW),
+        ReviewResourceName::from_str(REVIEW).is_ok(),
+        "old and new ReviewResourceName parsers disagree"
+    );
+    let mut group = c.benchmark_group("ReviewResourceName");
+    group.bench_function("legacy", |b| {
+        b.iter(|| legacy::parse_review(black_box(REVIEW)))
+    });
+    group.bench_function("from_str", |b| {
+        b.iter(|| ReviewResourceName::from_str(black_box(REVIEW)))
+    });
+    group.bench_function("parse_borrowed", |b| {
+        b.iter(|| ReviewResourceNameRef::parse_borrowed(black_box(REVIEW)))
+    });
+    group.finish();
+}
+
+fn publisher(c: &mut Criterion) {
+    let mut group = c.benchmark_group("PublisherResourceName");
+    for (pattern, name) in [
+        ("first pattern", PUBLISHER_FIRST),
+        ("last pattern", PUBLISHER_LAST),
+        ("no match", PUBLISHER_INVALID),
+    ] {
+        assert_eq!(
+            legacy::parse_publisher(name),
+            PublisherResourceName::from_str(name).is_ok(),
+            "old and new PublisherResourceName parsers disagree on {pattern}"
+        );
+        group.bench_function(format!("legacy/{pattern}"), |b| {
+            b.iter(|| legacy::parse_publisher(black_box(name)))
+        });
+        group.bench_function(format!("from_str/{pattern}"), |b| {
+            b.iter(|| PublisherResourceName::from_str(black_box(name)))
+        });
+        group.bench_function(format!("parse_borrowed/{pattern}"), |b| {
+            b.iter(|| PublisherResourceNameRef::parse_borrowed(black_box(name)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, review, publisher);
+criterion_main!(benches);
+
+/// The parsers as they were generated before the streaming rewrite.
+/// Only parse success is measured, so the parsed fields are never read.
+#[allow(dead_code)]
+mod legacy {
+    pub struct ReviewResourceName {
+        pub project: String,
+        pub book: String,
+        pub review: String,
+    }
+
+    pub struct PublishersPublisherResourceName {
+        pub publisher: String,
+    }
+
+    pub struct OrganizationsPublisherResourceName {
+        pub organization: String,
+        pub publisher: String,
+    }
+
+    pub struct ProjectsPublisherResourceName {
+        pub project: String,
+        pub publisher: String,
+    }
+
+    pub enum PublisherResourceName {
+        Publishers(PublishersPublisherResourceName),
+        Organizations(OrganizationsPublisherResourceName),
+        Projects(ProjectsPublisherResourceName),
+    }
+
+    fn validate(field: &str, value: &str) -> Result<(), String> {
+        if value.is_empty() {
+            return Err(format!("{field}: empty"));
+        }
+        if value.contains('/') {
+            return Err(format!("{field}: contains illegal character '/'"));
+        }
+        Ok(())
+    }
+
+    fn split<'a>(
+        s: &'a str,
+        literals: &[(usize, &str)],
+        len: usize,
+    ) -> Result<Vec<&'a str>, String> {
+        let parts: Vec<&str> = s.split('/').collect();
+        if parts.len() != len {
+            return Err(format!("expected {len} parts, got {}", parts.len()));
+        }
+        for &(i, literal) in literals {
+            if parts[i] != literal {
+                return Err(format!(
+                    "expected '{literal}' at position {i}, got '{}'",
+                    parts[i]
+                ));
+            }
+        }
+        Ok(parts)
+    }
+
+    fn review(s: &str) -> Result<ReviewResourceName, String> {
+        let parts = split(s, &[(0, "projects"), (2, "books"), (4, "reviews")], 6)?;
+        let result = ReviewResourceName {
+            project: parts[1].to_string(),
+            book: parts[3].to_string(),
+            review: parts[5].to_string(),
+        };
+        validate("project", &result.project)?;
+        validate("book", &result.book)?;
+        validate("review", &result.review)?;
+        Ok(result)
+    }
+
+    fn publishers(s: &str) -> Result<PublishersPublisherResourceName, String> {
+        let parts = split(s, &[(0, "publishers")], 2)?;
+        let result = PublishersPublisherResourceName {
+            publisher: parts[1].to_string(),
+        };
+        validate("publisher", &result.publisher)?;
+        Ok(result)
+    }
+
+    fn organizations(s: &str) -> Result<OrganizationsPublisherResourceName, String> {
+        let parts = split(s, &[(0, "organizations"), (2, "publishers")], 4)?;
+        let result = OrganizationsPublisherResourceName {
+            organization: parts[1].to_string(),
+            publisher: parts[3].to_string(),
+        };
+        validate("organization", &result.organization)?;
+        validate("publisher", &result.publisher)?;
+        Ok(result)
+    }
+
+    fn projects(s: &str) -> Result<ProjectsPublisherResourceName, String> {
+        let parts = split(s, &[(0, "projects"), (2, "publishers")], 4)?;
+        let result = ProjectsPublisherResourceName {
+            project: parts[1].to_string(),
+            publisher: parts[3].to_string(),
+        };
+        validate("project", &result.project)?;
+        validate("publisher", &result.publisher)?;
+        Ok(result)
+    }
+
+    fn publisher(s: &str) -> Result<PublisherResourceName, String> {
+        if let Ok(parsed) = publishers(s) {
+            return Ok(PublisherResourceName::Publishers(parsed));
+        }
+        if let Ok(parsed) = organizations(s) {
+            return Ok(PublisherResourceName::Organizations(parsed));
+        }
+        if let Ok(parsed) = projects(s) {
+            return Ok(PublisherResourceName::Projects(parsed));
+        }
+        Err("no matching pattern".to_string())
+    }
+
+    pub fn parse_review(s: &str) -> bool {
+        review(s).is_ok()
+    }
+
+    pub fn parse_publisher(s: &str) -> bool {
+        publisher(s).is_ok()
+    }
+}
@@ -22,14 +22,15 @@ impl StoreResourceName {
     }
 
     /// Validates the resource name.
-    pub fn validate(&self) -> Result<(), String> {
-        if self.store.is_empty() {
-            return Err("store: empty".to_string());
-        }
-        if self.store.contains('/') {
-            return Err("store: contains illegal character '/'".to_string());
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        self.as_borrowed().validate()
+    }
+
+    /// Borrows the resource name as a StoreResourceNameRef.
+    pub fn as_borrowed(&self) -> StoreResourceNameRef<'_> {
+        StoreResourceNameRef {
+            store: &self.store,
         }
-        Ok(())
     }
 
     /// Returns the resource type.
@@ -37,38 +38,175 @@ impl StoreResourceName {
         "bookstore.googleapis.com/Store"
     }
 
+    /// Returns the name of the service the resource belongs to.
+    pub fn service_name(&self) -> &'static str {
+        "bookstore.googleapis.com"
+    }
+
+    /// Returns the full resource name, prefixed with the service name.
+    pub fn to_full_name(&self) -> String {
+        format!("//{}/{}", self.service_name(), self)
+    }
+
+    /// Parses a full resource name, which must belong to the service of the resource.
+    pub fn from_full_name(full_name: &str) -> Result<Self, ResourceNameError> {
+        strip_service(full_name, "bookstore.googleapis.com")?.parse()
+    }
+
+    /// Returns the API version of the resource, from its proto package.
+    pub fn api_version(&self) -> &'static str {
+        "v1"
+    }
+
+    /// Returns the resource URI in the given API version, or else in the one of the resource.
+    pub fn to_uri(&self, version: Option<&str>) -> String {
+        let version = version.unwrap_or(self.api_version());
+        format!("https://{}/{}/{}", self.service_name(), version, self)
+    }
+
+    /// Parses a resource URI of the service of the resource, and returns the name with the
+    /// API version of the URI.
+    pub fn from_uri(uri: &str) -> Result<(Self, &str), ResourceNameError> {
+        let (version, name) = aip_resourcename::split_uri(uri, "bookstore.googleapis.com")?;
+        Ok((name.parse()?, version))
+    }
+
     /// Returns true if any field contains a wildcard.
     pub fn contains_wildcard(&self) -> bool {
         self.store == "-"
     }
 
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        self.store == "-" || self.store == concrete.store
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(mut self, variables: &[&str]) -> Self {
+        if variables.contains(&"store") {
+            self.store = "-".to_string();
+        }
+        self
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        self.validate()?;
+        reject_wildcard("store", &self.store)?;
+        Ok(())
+    }
+
+    /// Returns true if `other` is the name of a resource nested under this one.
+    pub fn is_ancestor_of(&self, other: &str) -> bool {
+        ResourceName::is_ancestor_of(self, other)
+    }
+
+    /// Returns true if the name matches a glob such as `projects/acme/books/*`, where `*`
+    /// matches one segment and a final `**` any suffix. Globs that cannot match names of
+    /// this type match nothing.
+    pub fn matches_glob(&self, glob: &str) -> bool {
+        ResourceName::matches_glob(self, glob)
+    }
+
 }
 
 impl fmt::Display for StoreResourceName {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "stores/{store}"
-            , store = self.store
-        )
+        fmt::Display::fmt(&self.as_borrowed(), f)
     }
 }
 
 impl FromStr for StoreResourceName {
-    type Err = String;
+    type Err = ResourceNameError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts: Vec<&str> = s.split('/').collect();
-        if parts.len() != 2 {
-            return Err(format!("expected {expected_parts} parts, got {}", parts.len(), expected_parts = 2));
-        }
-        if parts[0] != "stores" {
-            return Err(format!("expected 'stores' at position 0, got '{part}'", part = parts[0]));
+        StoreResourceNameRef::parse_borrowed(s).map(StoreResourceNameRef::to_owned)
+    }
+}
+
+impl ResourceName for StoreResourceName {
+    const TYPE: &'static str = "bookstore.googleapis.com/Store";
+    const PATTERNS: &'static [&'static str] = &["stores/{store}"];
+    const SINGULAR: &'static str = "store";
+    const PLURAL: &'static str = "stores";
+
+    fn validate(&self) -> Result<(), ResourceNameError> {
+        self.validate()
+    }
+
+    fn contains_wildcard(&self) -> bool {
+        self.contains_wildcard()
+    }
+}
+
+impl Serialize for StoreResourceName {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_variables(serializer, [("store", &self.store)])
+    }
+}
+
+impl<'de> Deserialize<'de> for StoreResourceName {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let [store] = deserialize_variables(deserializer, ["store"])?;
+        validated(Self { store })
+    }
+}
+
+/// Borrowed view of a StoreResourceName that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StoreResourceNameRef<'a> {
+    pub store: &'a str,
+}
+
+impl<'a> StoreResourceNameRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut scanner = Scanner::new(s, 2);
+        scanner.literal("stores")?;
+        let store = scanner.variable()?;
+        scanner.finish()?;
+        Self::from_segments(store)
+    }
+
+    /// Creates a StoreResourceNameRef from parsed segments and validates it.
+    fn from_segments(store: &'a str) -> Result<Self, ResourceNameError> {
+        let name = Self { store };
+        name.validate()?;
+        Ok(name)
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        validate_segment("store", self.store)?;
+        Ok(())
+    }
+
+    /// Converts the borrowed view into an owned StoreResourceName.
+    pub fn to_owned(self) -> StoreResourceName {
+        StoreResourceName {
+            store: self.store.to_string(),
         }
-        let store = parts[1].to_string();
-        let result = Self {
-            store,
-        };
-        result.validate()?;
-        Ok(result)
+    }
+}
+
+impl fmt::Display for StoreResourceNameRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "stores/{store}"
+            , store = self.store
+        )
+    }
+}
+
+impl example::bookstore::v1::Store {
+    /// Parses the `name` field as a StoreResourceName.
+    pub fn resource_name(&self) -> Result<StoreResourceName, ResourceNameError> {
+        self.name.parse()
+    }
+
+    /// Sets the `name` field from a StoreResourceName.
+    pub fn set_resource_name(&mut self, name: &StoreResourceName) {
+        self.name = name.to_string();
     }
 }
 
@@ -92,20 +230,16 @@ impl CategoryResourceName {
     }
 
     /// Validates the resource name.
-    pub fn validate(&self) -> Result<(), String> {
-        if self.store.is_empty() {
-            return Err("store: empty".to_string());
-        }
-        if self.store.contains('/') {
-            return Err("store: contains illegal character '/'".to_string());
-        }
-        if self.category.is_empty() {
-            return Err("category: empty".to_string());
-        }
-        if self.category.contains('/') {
-            return Err("category: contains illegal character '/'".to_string());
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        self.as_borrowed().validate()
+    }
+
+    /// Borrows the resource name as a CategoryResourceNameRef.
+    pub fn as_borrowed(&self) -> CategoryResourceNameRef<'_> {
+        CategoryResourceNameRef {
+            store: &self.store,
+            category: &self.category,
         }
-        Ok(())
     }
 
     /// Returns the resource type.
@@ -113,14 +247,171 @@ impl CategoryResourceName {
         "bookstore.googleapis.com/Category"
     }
 
+    /// Returns the name of the service the resource belongs to.
+    pub fn service_name(&self) -> &'static str {
+        "bookstore.googleapis.com"
+    }
+
+    /// Returns the full resource name, prefixed with the service name.
+    pub fn to_full_name(&self) -> String {
+        format!("//{}/{}", self.service_name(), self)
+    }
+
+    /// Parses a full resource name, which must belong to the service of the resource.
+    pub fn from_full_name(full_name: &str) -> Result<Self, ResourceNameError> {
+        strip_service(full_name, "bookstore.googleapis.com")?.parse()
+    }
+
+    /// Returns the API version of the resource, from its proto package.
+    pub fn api_version(&self) -> &'static str {
+        "v1"
+    }
+
+    /// Returns the resource URI in the given API version, or else in the one of the resource.
+    pub fn to_uri(&self, version: Option<&str>) -> String {
+        let version = version.unwrap_or(self.api_version());
+        format!("https://{}/{}/{}", self.service_name(), version, self)
+    }
+
+    /// Parses a resource URI of the service of the resource, and returns the name with the
+    /// API version of the URI.
+    pub fn from_uri(uri: &str) -> Result<(Self, &str), ResourceNameError> {
+        let (version, name) = aip_resourcename::split_uri(uri, "bookstore.googleapis.com")?;
+        Ok((name.parse()?, version))
+    }
+
     /// Returns true if any field contains a wildcard.
     pub fn contains_wildcard(&self) -> bool {
         self.store == "-" || self.category == "-"
     }
 
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        (self.store == "-" || self.store == concrete.store)
+            && (self.category == "-" || self.category == concrete.category)
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(mut self, variables: &[&str]) -> Self {
+        if variables.contains(&"store") {
+            self.store = "-".to_string();
+        }
+        if variables.contains(&"category") {
+            self.category = "-".to_string();
+        }
+        self
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        self.validate()?;
+        reject_wildcard("store", &self.store)?;
+        reject_wildcard("category", &self.category)?;
+        Ok(())
+    }
+
+    /// Returns true if `other` is the name of a resource nested under this one.
+    pub fn is_ancestor_of(&self, other: &str) -> bool {
+        ResourceName::is_ancestor_of(self, other)
+    }
+
+    /// Returns true if the name matches a glob such as `projects/acme/books/*`, where `*`
+    /// matches one segment and a final `**` any suffix. Globs that cannot match names of
+    /// this type match nothing.
+    pub fn matches_glob(&self, glob: &str) -> bool {
+        ResourceName::matches_glob(self, glob)
+    }
+
 }
 
 impl fmt::Display for CategoryResourceName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_borrowed(), f)
+    }
+}
+
+impl FromStr for CategoryResourceName {
+    type Err = ResourceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        CategoryResourceNameRef::parse_borrowed(s).map(CategoryResourceNameRef::to_owned)
+    }
+}
+
+impl ResourceName for CategoryResourceName {
+    const TYPE: &'static str = "bookstore.googleapis.com/Category";
+    const PATTERNS: &'static [&'static str] = &["stores/{store}/categories/{category}"];
+    const SINGULAR: &'static str = "category";
+    const PLURAL: &'static str = "categories";
+
+    fn validate(&self) -> Result<(), ResourceNameError> {
+        self.validate()
+    }
+
+    fn contains_wildcard(&self) -> bool {
+        self.contains_wildcard()
+    }
+}
+
+impl Serialize for CategoryResourceName {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_variables(serializer, [("store", &self.store), ("category", &self.category)])
+    }
+}
+
+impl<'de> Deserialize<'de> for CategoryResourceName {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let [store, category] = deserialize_variables(deserializer, ["store", "category"])?;
+        validated(Self { store, category })
+    }
+}
+
+/// Borrowed view of a CategoryResourceName that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CategoryResourceNameRef<'a> {
+    pub store: &'a str,
+    pub category: &'a str,
+}
+
+impl<'a> CategoryResourceNameRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut scanner = Scanner::new(s, 4);
+        scanner.literal("stores")?;
+        let store = scanner.variable()?;
+        scanner.literal("categories")?;
+        let category = scanner.variable()?;
+        scanner.finish()?;
+        Self::from_segments(store, category)
+    }
+
+    /// Creates a CategoryResourceNameRef from parsed segments and validates it.
+    fn from_segments(store: &'a str, category: &'a str) -> Result<Self, ResourceNameError> {
+        let name = Self { store, category };
+        name.validate()?;
+        Ok(name)
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        validate_segment("store", self.store)?;
+        validate_segment("category", self.category)?;
+        aip_resourcename::regex_rule!(CATEGORY_REGEX, "[a-z]+(-[a-z]+)*");
+        CATEGORY_REGEX.validate("category", self.category)?;
+        Ok(())
+    }
+
+    /// Converts the borrowed view into an owned CategoryResourceName.
+    pub fn to_owned(self) -> CategoryResourceName {
+        CategoryResourceName {
+            store: self.store.to_string(),
+            category: self.category.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for CategoryResourceNameRef<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "stores/{store}/categories/{category}"
             , store = self.store
@@ -129,28 +420,43 @@ impl fmt::Display for CategoryResourceName {
     }
 }
 
-impl FromStr for CategoryResourceName {
-    type Err = String;
+/// Parent of a CategoryResourceName.
+pub type CategoryParent = StoreResourceName;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts: Vec<&str> = s.split('/').collect();
-        if parts.len() != 4 {
-            return Err(format!("expected {expected_parts} parts, got {}", parts.len(), expected_parts = 4));
-        }
-        if parts[0] != "stores" {
-            return Err(format!("expected 'stores' at position 0, got '{part}'", part = parts[0]));
+impl CategoryResourceName {
+    /// Returns the name of the parent of this resource.
+    pub fn parent(&self) -> StoreResourceName {
+        StoreResourceName {
+            store: self.store.clone(),
         }
-        let store = parts[1].to_string();
-        if parts[2] != "categories" {
-            return Err(format!("expected 'categories' at position 2, got '{part}'", part = parts[2]));
+    }
+}
+
+impl CategoryResourceName {
+    /// Returns the name of the StoreResourceName this resource is nested under.
+    pub fn store(&self) -> StoreResourceName {
+        StoreResourceName {
+            store: self.store.clone(),
         }
-        let category = parts[3].to_string();
-        let result = Self {
-            store,
-            category,
-        };
-        result.validate()?;
-        Ok(result)
+    }
+}
+
+impl StoreResourceName {
+    /// Creates the name of a CategoryResourceName nested under this one.
+    pub fn category(&self, category: impl Into<String>) -> CategoryResourceName {
+        CategoryResourceName { store: self.store.clone(), category: category.into() }
+    }
+}
+
+impl example::bookstore::v1::Category {
+    /// Parses the `name` field as a CategoryResourceName.
+    pub fn resource_name(&self) -> Result<CategoryResourceName, ResourceNameError> {
+        self.name.parse()
+    }
+
+    /// Sets the `name` field from a CategoryResourceName.
+    pub fn set_resource_name(&mut self, name: &CategoryResourceName) {
+        self.name = name.to_string();
     }
 }
 
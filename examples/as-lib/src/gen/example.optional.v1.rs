@@ -10,9 +10,18 @@ pub struct TestResource {
     #[prost(int32, optional, tag="3")]
     pub optional_number: ::core::option::Option<i32>,
 }
+/// A test resource whose name field is optional
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Draft {
+    #[prost(string, optional, tag="1")]
+    pub name: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="2")]
+    pub content: ::prost::alloc::string::String,
+}
 /// Encoded file descriptor set for the `example.optional.v1` package
 pub const FILE_DESCRIPTOR_SET: &[u8] = &[
-    0x0a, 0xf8, 0x05, 0x0a, 0x13, 0x6f, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x61, 0x6c, 0x5f, 0x74, 0x65,
+    0x0a, 0xad, 0x09, 0x0a, 0x13, 0x6f, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x61, 0x6c, 0x5f, 0x74, 0x65,
     0x73, 0x74, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x13, 0x65, 0x78, 0x61, 0x6d, 0x70, 0x6c,
     0x65, 0x2e, 0x6f, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x61, 0x6c, 0x2e, 0x76, 0x31, 0x1a, 0x19, 0x67,
     0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2f, 0x61, 0x70, 0x69, 0x2f, 0x72, 0x65, 0x73, 0x6f, 0x75, 0x72,
@@ -32,34 +41,61 @@ pub const FILE_DESCRIPTOR_SET: &[u8] = &[
     0x05, 0x74, 0x65, 0x73, 0x74, 0x73, 0x32, 0x04, 0x74, 0x65, 0x73, 0x74, 0x42, 0x11, 0x0a, 0x0f,
     0x5f, 0x6f, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x61, 0x6c, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x42,
     0x12, 0x0a, 0x10, 0x5f, 0x6f, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x61, 0x6c, 0x5f, 0x6e, 0x75, 0x6d,
-    0x62, 0x65, 0x72, 0x42, 0x15, 0x5a, 0x13, 0x65, 0x78, 0x61, 0x6d, 0x70, 0x6c, 0x65, 0x2f, 0x6f,
-    0x70, 0x74, 0x69, 0x6f, 0x6e, 0x61, 0x6c, 0x2f, 0x76, 0x31, 0x4a, 0x96, 0x03, 0x0a, 0x06, 0x12,
-    0x04, 0x00, 0x00, 0x14, 0x01, 0x0a, 0x08, 0x0a, 0x01, 0x0c, 0x12, 0x03, 0x00, 0x00, 0x12, 0x0a,
-    0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x02, 0x00, 0x1c, 0x0a, 0x08, 0x0a, 0x01, 0x08, 0x12, 0x03,
-    0x04, 0x00, 0x2a, 0x0a, 0x09, 0x0a, 0x02, 0x08, 0x0b, 0x12, 0x03, 0x04, 0x00, 0x2a, 0x0a, 0x09,
-    0x0a, 0x02, 0x03, 0x00, 0x12, 0x03, 0x06, 0x00, 0x23, 0x0a, 0x32, 0x0a, 0x02, 0x04, 0x00, 0x12,
-    0x04, 0x09, 0x00, 0x14, 0x01, 0x1a, 0x26, 0x20, 0x41, 0x20, 0x74, 0x65, 0x73, 0x74, 0x20, 0x72,
-    0x65, 0x73, 0x6f, 0x75, 0x72, 0x63, 0x65, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x6f, 0x70, 0x74,
-    0x69, 0x6f, 0x6e, 0x61, 0x6c, 0x20, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x73, 0x0a, 0x0a, 0x0a, 0x0a,
-    0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x09, 0x08, 0x14, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x00, 0x07,
-    0x12, 0x04, 0x0a, 0x02, 0x0f, 0x04, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x00, 0x07, 0x9d, 0x08, 0x12,
-    0x04, 0x0a, 0x02, 0x0f, 0x04, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x00, 0x07, 0x9d, 0x08, 0x01, 0x12,
-    0x03, 0x0b, 0x04, 0x2c, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x07, 0x9d, 0x08, 0x02, 0x00, 0x12,
-    0x03, 0x0c, 0x04, 0x2e, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x00, 0x07, 0x9d, 0x08, 0x06, 0x12, 0x03,
-    0x0d, 0x04, 0x14, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x00, 0x07, 0x9d, 0x08, 0x05, 0x12, 0x03, 0x0e,
-    0x04, 0x13, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x11, 0x02, 0x12, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x05, 0x12, 0x03, 0x11, 0x02, 0x08, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x11, 0x09, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x11, 0x10, 0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02,
-    0x01, 0x12, 0x03, 0x12, 0x02, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x04, 0x12,
-    0x03, 0x12, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x05, 0x12, 0x03, 0x12,
-    0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x12, 0x12, 0x20,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x12, 0x23, 0x24, 0x0a, 0x0b,
-    0x0a, 0x04, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x13, 0x02, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x00, 0x02, 0x02, 0x04, 0x12, 0x03, 0x13, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
-    0x02, 0x05, 0x12, 0x03, 0x13, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x01,
-    0x12, 0x03, 0x13, 0x11, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x03, 0x12, 0x03,
-    0x13, 0x23, 0x24, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
+    0x62, 0x65, 0x72, 0x22, 0x95, 0x01, 0x0a, 0x05, 0x44, 0x72, 0x61, 0x66, 0x74, 0x12, 0x17, 0x0a,
+    0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x48, 0x00, 0x52, 0x04, 0x6e,
+    0x61, 0x6d, 0x65, 0x88, 0x01, 0x01, 0x12, 0x18, 0x0a, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e,
+    0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74,
+    0x3a, 0x50, 0xea, 0x41, 0x4d, 0x0a, 0x19, 0x74, 0x65, 0x73, 0x74, 0x2e, 0x67, 0x6f, 0x6f, 0x67,
+    0x6c, 0x65, 0x61, 0x70, 0x69, 0x73, 0x2e, 0x63, 0x6f, 0x6d, 0x2f, 0x44, 0x72, 0x61, 0x66, 0x74,
+    0x12, 0x21, 0x70, 0x72, 0x6f, 0x6a, 0x65, 0x63, 0x74, 0x73, 0x2f, 0x7b, 0x70, 0x72, 0x6f, 0x6a,
+    0x65, 0x63, 0x74, 0x7d, 0x2f, 0x64, 0x72, 0x61, 0x66, 0x74, 0x73, 0x2f, 0x7b, 0x64, 0x72, 0x61,
+    0x66, 0x74, 0x7d, 0x2a, 0x06, 0x64, 0x72, 0x61, 0x66, 0x74, 0x73, 0x32, 0x05, 0x64, 0x72, 0x61,
+    0x66, 0x74, 0x42, 0x07, 0x0a, 0x05, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x42, 0x15, 0x5a, 0x13, 0x65,
+    0x78, 0x61, 0x6d, 0x70, 0x6c, 0x65, 0x2f, 0x6f, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x61, 0x6c, 0x2f,
+    0x76, 0x31, 0x4a, 0xb3, 0x05, 0x0a, 0x06, 0x12, 0x04, 0x00, 0x00, 0x21, 0x01, 0x0a, 0x08, 0x0a,
+    0x01, 0x0c, 0x12, 0x03, 0x00, 0x00, 0x12, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x02, 0x00,
+    0x1c, 0x0a, 0x08, 0x0a, 0x01, 0x08, 0x12, 0x03, 0x04, 0x00, 0x2a, 0x0a, 0x09, 0x0a, 0x02, 0x08,
+    0x0b, 0x12, 0x03, 0x04, 0x00, 0x2a, 0x0a, 0x09, 0x0a, 0x02, 0x03, 0x00, 0x12, 0x03, 0x06, 0x00,
+    0x23, 0x0a, 0x32, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x09, 0x00, 0x14, 0x01, 0x1a, 0x26, 0x20,
+    0x41, 0x20, 0x74, 0x65, 0x73, 0x74, 0x20, 0x72, 0x65, 0x73, 0x6f, 0x75, 0x72, 0x63, 0x65, 0x20,
+    0x77, 0x69, 0x74, 0x68, 0x20, 0x6f, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x61, 0x6c, 0x20, 0x66, 0x69,
+    0x65, 0x6c, 0x64, 0x73, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x09, 0x08,
+    0x14, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x00, 0x07, 0x12, 0x04, 0x0a, 0x02, 0x0f, 0x04, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x00, 0x07, 0x9d, 0x08, 0x12, 0x04, 0x0a, 0x02, 0x0f, 0x04, 0x0a, 0x0d, 0x0a,
+    0x06, 0x04, 0x00, 0x07, 0x9d, 0x08, 0x01, 0x12, 0x03, 0x0b, 0x04, 0x2c, 0x0a, 0x0e, 0x0a, 0x07,
+    0x04, 0x00, 0x07, 0x9d, 0x08, 0x02, 0x00, 0x12, 0x03, 0x0c, 0x04, 0x2e, 0x0a, 0x0d, 0x0a, 0x06,
+    0x04, 0x00, 0x07, 0x9d, 0x08, 0x06, 0x12, 0x03, 0x0d, 0x04, 0x14, 0x0a, 0x0d, 0x0a, 0x06, 0x04,
+    0x00, 0x07, 0x9d, 0x08, 0x05, 0x12, 0x03, 0x0e, 0x04, 0x13, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00,
+    0x02, 0x00, 0x12, 0x03, 0x11, 0x02, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x05,
+    0x12, 0x03, 0x11, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03,
+    0x11, 0x09, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x11, 0x10,
+    0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x12, 0x02, 0x25, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x04, 0x12, 0x03, 0x12, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x00, 0x02, 0x01, 0x05, 0x12, 0x03, 0x12, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
+    0x02, 0x01, 0x01, 0x12, 0x03, 0x12, 0x12, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01,
+    0x03, 0x12, 0x03, 0x12, 0x23, 0x24, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03,
+    0x13, 0x02, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x04, 0x12, 0x03, 0x13, 0x02,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x05, 0x12, 0x03, 0x13, 0x0b, 0x10, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x13, 0x11, 0x20, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x02, 0x03, 0x12, 0x03, 0x13, 0x23, 0x24, 0x0a, 0x3a, 0x0a, 0x02, 0x04,
+    0x01, 0x12, 0x04, 0x17, 0x00, 0x21, 0x01, 0x1a, 0x2e, 0x20, 0x41, 0x20, 0x74, 0x65, 0x73, 0x74,
+    0x20, 0x72, 0x65, 0x73, 0x6f, 0x75, 0x72, 0x63, 0x65, 0x20, 0x77, 0x68, 0x6f, 0x73, 0x65, 0x20,
+    0x6e, 0x61, 0x6d, 0x65, 0x20, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x20, 0x69, 0x73, 0x20, 0x6f, 0x70,
+    0x74, 0x69, 0x6f, 0x6e, 0x61, 0x6c, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03,
+    0x17, 0x08, 0x0d, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x01, 0x07, 0x12, 0x04, 0x18, 0x02, 0x1d, 0x04,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x07, 0x9d, 0x08, 0x12, 0x04, 0x18, 0x02, 0x1d, 0x04, 0x0a,
+    0x0d, 0x0a, 0x06, 0x04, 0x01, 0x07, 0x9d, 0x08, 0x01, 0x12, 0x03, 0x19, 0x04, 0x25, 0x0a, 0x0e,
+    0x0a, 0x07, 0x04, 0x01, 0x07, 0x9d, 0x08, 0x02, 0x00, 0x12, 0x03, 0x1a, 0x04, 0x30, 0x0a, 0x0d,
+    0x0a, 0x06, 0x04, 0x01, 0x07, 0x9d, 0x08, 0x06, 0x12, 0x03, 0x1b, 0x04, 0x15, 0x0a, 0x0d, 0x0a,
+    0x06, 0x04, 0x01, 0x07, 0x9d, 0x08, 0x05, 0x12, 0x03, 0x1c, 0x04, 0x14, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x1f, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
+    0x00, 0x04, 0x12, 0x03, 0x1f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x05,
+    0x12, 0x03, 0x1f, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03,
+    0x1f, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x1f, 0x19,
+    0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x03, 0x20, 0x02, 0x15, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x05, 0x12, 0x03, 0x20, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x20, 0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x01, 0x03, 0x12, 0x03, 0x20, 0x13, 0x14, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
 ];
 include!("example.optional.v1.serde.rs");
 // @@protoc_insertion_point(module)
\ No newline at end of file
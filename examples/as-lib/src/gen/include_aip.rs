@@ -11,6 +11,203 @@
 use std::fmt;
 use std::str::FromStr;
 
+pub use aip_resourcename::{DynResourceName, ResourceName, ResourceNameError, ResourceNameErrorKind, UntypedResourceName, UntypedResourceNameRef};
+use aip_resourcename::{Scanner, reject_wildcard, strip_service, validate_segment};
+use aip_resourcename::serde::{Deserialize, Deserializer, Serialize, Serializer, deserialize_variables, serialize_variables, validated};
+
 include!("bookstore_aip.rs");
 include!("library_aip.rs");
 include!("optional_test_aip.rs");
+
+/// Name of any resource generated in this compilation.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AnyResourceName {
+    Category(CategoryResourceName),
+    Store(StoreResourceName),
+    Attachment(AttachmentResourceName),
+    Author(AuthorResourceName),
+    Book(BookResourceName),
+    Edition(EditionResourceName),
+    Loan(LoanResourceName),
+    Publisher(PublisherResourceName),
+    Review(ReviewResourceName),
+    Shelf(ShelfResourceName),
+    ShelfLayout(ShelfLayoutResourceName),
+    Draft(DraftResourceName),
+    TestResource(TestResourceResourceName),
+}
+
+impl AnyResourceName {
+    /// Returns the name as a DynResourceName.
+    pub fn as_dyn(&self) -> &dyn DynResourceName {
+        match self {
+            AnyResourceName::Category(inner) => inner,
+            AnyResourceName::Store(inner) => inner,
+            AnyResourceName::Attachment(inner) => inner,
+            AnyResourceName::Author(inner) => inner,
+            AnyResourceName::Book(inner) => inner,
+            AnyResourceName::Edition(inner) => inner,
+            AnyResourceName::Loan(inner) => inner,
+            AnyResourceName::Publisher(inner) => inner,
+            AnyResourceName::Review(inner) => inner,
+            AnyResourceName::Shelf(inner) => inner,
+            AnyResourceName::ShelfLayout(inner) => inner,
+            AnyResourceName::Draft(inner) => inner,
+            AnyResourceName::TestResource(inner) => inner,
+        }
+    }
+
+    /// Returns the resource type.
+    pub fn resource_type(&self) -> &'static str {
+        self.as_dyn().resource_type()
+    }
+}
+
+impl fmt::Display for AnyResourceName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_dyn(), f)
+    }
+}
+
+impl From<CategoryResourceName> for AnyResourceName {
+    fn from(name: CategoryResourceName) -> Self {
+        AnyResourceName::Category(name)
+    }
+}
+
+impl From<StoreResourceName> for AnyResourceName {
+    fn from(name: StoreResourceName) -> Self {
+        AnyResourceName::Store(name)
+    }
+}
+
+impl From<AttachmentResourceName> for AnyResourceName {
+    fn from(name: AttachmentResourceName) -> Self {
+        AnyResourceName::Attachment(name)
+    }
+}
+
+impl From<AuthorResourceName> for AnyResourceName {
+    fn from(name: AuthorResourceName) -> Self {
+        AnyResourceName::Author(name)
+    }
+}
+
+impl From<BookResourceName> for AnyResourceName {
+    fn from(name: BookResourceName) -> Self {
+        AnyResourceName::Book(name)
+    }
+}
+
+impl From<EditionResourceName> for AnyResourceName {
+    fn from(name: EditionResourceName) -> Self {
+        AnyResourceName::Edition(name)
+    }
+}
+
+impl From<LoanResourceName> for AnyResourceName {
+    fn from(name: LoanResourceName) -> Self {
+        AnyResourceName::Loan(name)
+    }
+}
+
+impl From<PublisherResourceName> for AnyResourceName {
+    fn from(name: PublisherResourceName) -> Self {
+        AnyResourceName::Publisher(name)
+    }
+}
+
+impl From<ReviewResourceName> for AnyResourceName {
+    fn from(name: ReviewResourceName) -> Self {
+        AnyResourceName::Review(name)
+    }
+}
+
+impl From<ShelfResourceName> for AnyResourceName {
+    fn from(name: ShelfResourceName) -> Self {
+        AnyResourceName::Shelf(name)
+    }
+}
+
+impl From<ShelfLayoutResourceName> for AnyResourceName {
+    fn from(name: ShelfLayoutResourceName) -> Self {
+        AnyResourceName::ShelfLayout(name)
+    }
+}
+
+impl From<DraftResourceName> for AnyResourceName {
+    fn from(name: DraftResourceName) -> Self {
+        AnyResourceName::Draft(name)
+    }
+}
+
+impl From<TestResourceResourceName> for AnyResourceName {
+    fn from(name: TestResourceResourceName) -> Self {
+        AnyResourceName::TestResource(name)
+    }
+}
+
+/// Parses the name of a resource of the given type, e.g. `library.googleapis.com/Book`.
+pub fn parse_any(resource_type: &str, name: &str) -> Result<AnyResourceName, ResourceNameError> {
+    match resource_type {
+        "bookstore.googleapis.com/Category" => name.parse().map(AnyResourceName::Category),
+        "bookstore.googleapis.com/Store" => name.parse().map(AnyResourceName::Store),
+        "library.googleapis.com/Attachment" => name.parse().map(AnyResourceName::Attachment),
+        "library.googleapis.com/Author" => name.parse().map(AnyResourceName::Author),
+        "library.googleapis.com/Book" => name.parse().map(AnyResourceName::Book),
+        "library.googleapis.com/Edition" => name.parse().map(AnyResourceName::Edition),
+        "library.googleapis.com/Loan" => name.parse().map(AnyResourceName::Loan),
+        "library.googleapis.com/Publisher" => name.parse().map(AnyResourceName::Publisher),
+        "library.googleapis.com/Review" => name.parse().map(AnyResourceName::Review),
+        "library.googleapis.com/Shelf" => name.parse().map(AnyResourceName::Shelf),
+        "library.googleapis.com/ShelfLayout" => name.parse().map(AnyResourceName::ShelfLayout),
+        "test.googleapis.com/Draft" => name.parse().map(AnyResourceName::Draft),
+        "test.googleapis.com/TestResource" => name.parse().map(AnyResourceName::TestResource),
+        _ => Err(ResourceNameError::unknown_type(resource_type)),
+    }
+}
+
+/// Returns the type of every resource with a pattern that matches the name.
+pub fn detect(name: &str) -> Vec<&'static str> {
+    let mut types = Vec::new();
+    if CategoryResourceNameRef::parse_borrowed(name).is_ok() {
+        types.push("bookstore.googleapis.com/Category");
+    }
+    if StoreResourceNameRef::parse_borrowed(name).is_ok() {
+        types.push("bookstore.googleapis.com/Store");
+    }
+    if AttachmentResourceNameRef::parse_borrowed(name).is_ok() {
+        types.push("library.googleapis.com/Attachment");
+    }
+    if AuthorResourceNameRef::parse_borrowed(name).is_ok_and(|name| !name.is_unknown()) {
+        types.push("library.googleapis.com/Author");
+    }
+    if BookResourceNameRef::parse_borrowed(name).is_ok() {
+        types.push("library.googleapis.com/Book");
+    }
+    if EditionResourceNameRef::parse_borrowed(name).is_ok() {
+        types.push("library.googleapis.com/Edition");
+    }
+    if LoanResourceNameRef::parse_borrowed(name).is_ok() {
+        types.push("library.googleapis.com/Loan");
+    }
+    if PublisherResourceNameRef::parse_borrowed(name).is_ok() {
+        types.push("library.googleapis.com/Publisher");
+    }
+    if ReviewResourceNameRef::parse_borrowed(name).is_ok() {
+        types.push("library.googleapis.com/Review");
+    }
+    if ShelfResourceNameRef::parse_borrowed(name).is_ok() {
+        types.push("library.googleapis.com/Shelf");
+    }
+    if ShelfLayoutResourceNameRef::parse_borrowed(name).is_ok() {
+        types.push("library.googleapis.com/ShelfLayout");
+    }
+    if DraftResourceNameRef::parse_borrowed(name).is_ok() {
+        types.push("test.googleapis.com/Draft");
+    }
+    if TestResourceResourceNameRef::parse_borrowed(name).is_ok() {
+        types.push("test.googleapis.com/TestResource");
+    }
+    types
+}
@@ -5,155 +5,295 @@
 // 	protoc (unknown)
 // source: library.proto
 
-/// Resource name for library.googleapis.com/Book
+/// Multi-pattern resource name for library.googleapis.com/ShelfLayout
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct BookResourceName {
-    pub project: String,
-    pub book: String,
+pub enum ShelfLayoutResourceName {
+    ProjectsShelves(ProjectsShelvesShelfLayoutResourceName),
+    UsersShelves(UsersShelvesShelfLayoutResourceName),
 }
 
-impl BookResourceName {
-    /// Creates a new BookResourceName.
-    pub fn new(
-        project: impl Into<String>,
-        book: impl Into<String>
-    ) -> Self {
-        Self {
-            project: project.into(),
-            book: book.into(),
+impl ShelfLayoutResourceName {
+    /// Returns the resource type.
+    pub fn resource_type(&self) -> &'static str {
+        "library.googleapis.com/ShelfLayout"
+    }
+
+    /// Returns true if any field contains a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        match self {
+            ShelfLayoutResourceName::ProjectsShelves(inner) => inner.contains_wildcard(),
+            ShelfLayoutResourceName::UsersShelves(inner) => inner.contains_wildcard(),
+        }
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    /// Names of different patterns are matched segment by segment.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        match (self, concrete) {
+            (ShelfLayoutResourceName::ProjectsShelves(inner), ShelfLayoutResourceName::ProjectsShelves(concrete)) => inner.matches(concrete),
+            (ShelfLayoutResourceName::UsersShelves(inner), ShelfLayoutResourceName::UsersShelves(concrete)) => inner.matches(concrete),
+            _ => aip_resourcename::wildcard_matches(&self.to_string(), &concrete.to_string()),
+        }
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(self, variables: &[&str]) -> Self {
+        match self {
+            ShelfLayoutResourceName::ProjectsShelves(inner) => ShelfLayoutResourceName::ProjectsShelves(inner.with_wildcards_in(variables)),
+            ShelfLayoutResourceName::UsersShelves(inner) => ShelfLayoutResourceName::UsersShelves(inner.with_wildcards_in(variables)),
+        }
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        match self {
+            ShelfLayoutResourceName::ProjectsShelves(inner) => inner.validate_concrete(),
+            ShelfLayoutResourceName::UsersShelves(inner) => inner.validate_concrete(),
         }
     }
 
     /// Validates the resource name.
-    pub fn validate(&self) -> Result<(), String> {
-        if self.project.is_empty() {
-            return Err("project: empty".to_string());
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        match self {
+            ShelfLayoutResourceName::ProjectsShelves(inner) => inner.validate(),
+            ShelfLayoutResourceName::UsersShelves(inner) => inner.validate(),
         }
-        if self.project.contains('/') {
-            return Err("project: contains illegal character '/'".to_string());
+    }
+
+    /// Borrows the resource name as a ShelfLayoutResourceNameRef.
+    pub fn as_borrowed(&self) -> ShelfLayoutResourceNameRef<'_> {
+        match self {
+            ShelfLayoutResourceName::ProjectsShelves(inner) => ShelfLayoutResourceNameRef::ProjectsShelves(inner.as_borrowed()),
+            ShelfLayoutResourceName::UsersShelves(inner) => ShelfLayoutResourceNameRef::UsersShelves(inner.as_borrowed()),
         }
-        if self.book.is_empty() {
-            return Err("book: empty".to_string());
+    }
+
+    /// Returns true if `other` is the name of a resource nested under this one.
+    pub fn is_ancestor_of(&self, other: &str) -> bool {
+        ResourceName::is_ancestor_of(self, other)
+    }
+
+    /// Returns true if the name matches a glob such as `projects/acme/books/*`, where `*`
+    /// matches one segment and a final `**` any suffix. Globs that cannot match names of
+    /// this type match nothing.
+    pub fn matches_glob(&self, glob: &str) -> bool {
+        ResourceName::matches_glob(self, glob)
+    }
+
+    /// Returns the name of the service the resource belongs to.
+    pub fn service_name(&self) -> &'static str {
+        match self {
+            ShelfLayoutResourceName::ProjectsShelves(inner) => inner.service_name(),
+            ShelfLayoutResourceName::UsersShelves(inner) => inner.service_name(),
         }
-        if self.book.contains('/') {
-            return Err("book: contains illegal character '/'".to_string());
+    }
+
+    /// Returns the full resource name, prefixed with the service name.
+    pub fn to_full_name(&self) -> String {
+        match self {
+            ShelfLayoutResourceName::ProjectsShelves(inner) => inner.to_full_name(),
+            ShelfLayoutResourceName::UsersShelves(inner) => inner.to_full_name(),
         }
-        Ok(())
     }
 
-    /// Returns the resource type.
-    pub fn resource_type(&self) -> &'static str {
-        "library.googleapis.com/Book"
+    /// Parses a full resource name, which must belong to the service of the resource.
+    pub fn from_full_name(full_name: &str) -> Result<Self, ResourceNameError> {
+        strip_service(full_name, "library.googleapis.com")?.parse()
     }
 
-    /// Returns true if any field contains a wildcard.
-    pub fn contains_wildcard(&self) -> bool {
-        self.project == "-" || self.book == "-"
+    /// Returns the API version of the resource, from its proto package.
+    pub fn api_version(&self) -> &'static str {
+        match self {
+            ShelfLayoutResourceName::ProjectsShelves(inner) => inner.api_version(),
+            ShelfLayoutResourceName::UsersShelves(inner) => inner.api_version(),
+        }
+    }
+
+    /// Returns the resource URI in the given API version, or else in the one of the resource.
+    pub fn to_uri(&self, version: Option<&str>) -> String {
+        match self {
+            ShelfLayoutResourceName::ProjectsShelves(inner) => inner.to_uri(version),
+            ShelfLayoutResourceName::UsersShelves(inner) => inner.to_uri(version),
+        }
+    }
+
+    /// Parses a resource URI of the service of the resource, and returns the name with the
+    /// API version of the URI.
+    pub fn from_uri(uri: &str) -> Result<(Self, &str), ResourceNameError> {
+        let (version, name) = aip_resourcename::split_uri(uri, "library.googleapis.com")?;
+        Ok((name.parse()?, version))
     }
 
 }
 
-impl fmt::Display for BookResourceName {
+impl fmt::Display for ShelfLayoutResourceName {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "projects/{project}/books/{book}"
-            , project = self.project
-            , book = self.book
-        )
+        match self {
+            ShelfLayoutResourceName::ProjectsShelves(inner) => write!(f, "{inner}"),
+            ShelfLayoutResourceName::UsersShelves(inner) => write!(f, "{inner}"),
+        }
     }
 }
 
-impl FromStr for BookResourceName {
-    type Err = String;
+impl FromStr for ShelfLayoutResourceName {
+    type Err = ResourceNameError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts: Vec<&str> = s.split('/').collect();
-        if parts.len() != 4 {
-            return Err(format!("expected {expected_parts} parts, got {}", parts.len(), expected_parts = 4));
+        ShelfLayoutResourceNameRef::parse_borrowed(s).map(ShelfLayoutResourceNameRef::to_owned)
+    }
+}
+
+impl ResourceName for ShelfLayoutResourceName {
+    const TYPE: &'static str = "library.googleapis.com/ShelfLayout";
+    const PATTERNS: &'static [&'static str] = &["projects/{project}/shelves/{shelf}/layout", "users/{user}/shelves/{shelf}/layout"];
+    const SINGULAR: &'static str = "shelfLayout";
+    const PLURAL: &'static str = "shelfLayouts";
+
+    fn validate(&self) -> Result<(), ResourceNameError> {
+        self.validate()
+    }
+
+    fn contains_wildcard(&self) -> bool {
+        self.contains_wildcard()
+    }
+}
+
+impl Serialize for ShelfLayoutResourceName {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            ShelfLayoutResourceName::ProjectsShelves(name) => name.serialize(serializer),
+            ShelfLayoutResourceName::UsersShelves(name) => name.serialize(serializer),
         }
-        if parts[0] != "projects" {
-            return Err(format!("expected 'projects' at position 0, got '{part}'", part = parts[0]));
+    }
+}
+
+impl<'de> Deserialize<'de> for ShelfLayoutResourceName {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut variables = aip_resourcename::serde::Variables::deserialize(deserializer)?;
+        if let Some([project, shelf]) = variables.take(["project", "shelf"]) {
+            return validated(ShelfLayoutResourceName::ProjectsShelves(ProjectsShelvesShelfLayoutResourceName { project, shelf }));
         }
-        let project = parts[1].to_string();
-        if parts[2] != "books" {
-            return Err(format!("expected 'books' at position 2, got '{part}'", part = parts[2]));
+        if let Some([user, shelf]) = variables.take(["user", "shelf"]) {
+            return validated(ShelfLayoutResourceName::UsersShelves(UsersShelvesShelfLayoutResourceName { user, shelf }));
         }
-        let book = parts[3].to_string();
-        let result = Self {
-            project,
-            book,
-        };
-        result.validate()?;
-        Ok(result)
+        Err(variables.no_match(&[&["project", "shelf"], &["user", "shelf"]]))
     }
 }
 
-/// Multi-pattern resource name for library.googleapis.com/Shelf
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum ShelfResourceName {
-    Projects(ProjectsShelfResourceName),
-    Users(UsersShelfResourceName),
+/// Borrowed view of a ShelfLayoutResourceName that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ShelfLayoutResourceNameRef<'a> {
+    ProjectsShelves(ProjectsShelvesShelfLayoutResourceNameRef<'a>),
+    UsersShelves(UsersShelvesShelfLayoutResourceNameRef<'a>),
 }
 
-impl ShelfResourceName {
-    /// Returns the resource type.
-    pub fn resource_type(&self) -> &'static str {
-        "library.googleapis.com/Shelf"
-    }
-
-    /// Returns true if any field contains a wildcard.
-    pub fn contains_wildcard(&self) -> bool {
-        match self {
-            ShelfResourceName::Projects(inner) => inner.contains_wildcard(),
-            ShelfResourceName::Users(inner) => inner.contains_wildcard(),
+impl<'a> ShelfLayoutResourceNameRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut parts = s.split('/');
+        match parts.next() {
+            Some("projects") => {
+                match parts.next() {
+                    Some(part1) => {
+                        match parts.next() {
+                            Some("shelves") => {
+                                match parts.next() {
+                                    Some(part3) => {
+                                        match parts.next() {
+                                            Some("layout") => {
+                                                match parts.next() {
+                                                    Some(part) => Err(ResourceNameError::no_matching_pattern("projects/{project}/shelves/{shelf}/layout", 5, Some(part))),
+                                                    None => ProjectsShelvesShelfLayoutResourceNameRef::from_segments(part1, part3).map(Self::ProjectsShelves),
+                                                }
+                                            }
+                                            Some(part) => Err(ResourceNameError::no_matching_pattern("projects/{project}/shelves/{shelf}/layout", 4, Some(part))),
+                                            None => Err(ResourceNameError::no_matching_pattern("projects/{project}/shelves/{shelf}/layout", 4, None)),
+                                        }
+                                    }
+                                    None => Err(ResourceNameError::no_matching_pattern("projects/{project}/shelves/{shelf}/layout", 3, None)),
+                                }
+                            }
+                            Some(part) => Err(ResourceNameError::no_matching_pattern("projects/{project}/shelves/{shelf}/layout", 2, Some(part))),
+                            None => Err(ResourceNameError::no_matching_pattern("projects/{project}/shelves/{shelf}/layout", 2, None)),
+                        }
+                    }
+                    None => Err(ResourceNameError::no_matching_pattern("projects/{project}/shelves/{shelf}/layout", 1, None)),
+                }
+            }
+            Some("users") => {
+                match parts.next() {
+                    Some(part1) => {
+                        match parts.next() {
+                            Some("shelves") => {
+                                match parts.next() {
+                                    Some(part3) => {
+                                        match parts.next() {
+                                            Some("layout") => {
+                                                match parts.next() {
+                                                    Some(part) => Err(ResourceNameError::no_matching_pattern("users/{user}/shelves/{shelf}/layout", 5, Some(part))),
+                                                    None => UsersShelvesShelfLayoutResourceNameRef::from_segments(part1, part3).map(Self::UsersShelves),
+                                                }
+                                            }
+                                            Some(part) => Err(ResourceNameError::no_matching_pattern("users/{user}/shelves/{shelf}/layout", 4, Some(part))),
+                                            None => Err(ResourceNameError::no_matching_pattern("users/{user}/shelves/{shelf}/layout", 4, None)),
+                                        }
+                                    }
+                                    None => Err(ResourceNameError::no_matching_pattern("users/{user}/shelves/{shelf}/layout", 3, None)),
+                                }
+                            }
+                            Some(part) => Err(ResourceNameError::no_matching_pattern("users/{user}/shelves/{shelf}/layout", 2, Some(part))),
+                            None => Err(ResourceNameError::no_matching_pattern("users/{user}/shelves/{shelf}/layout", 2, None)),
+                        }
+                    }
+                    None => Err(ResourceNameError::no_matching_pattern("users/{user}/shelves/{shelf}/layout", 1, None)),
+                }
+            }
+            Some(part) => Err(ResourceNameError::no_matching_pattern("projects/{project}/shelves/{shelf}/layout", 0, Some(part))),
+            None => Err(ResourceNameError::no_matching_pattern("projects/{project}/shelves/{shelf}/layout", 0, None)),
         }
     }
 
     /// Validates the resource name.
-    pub fn validate(&self) -> Result<(), String> {
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
         match self {
-            ShelfResourceName::Projects(inner) => inner.validate(),
-            ShelfResourceName::Users(inner) => inner.validate(),
+            ShelfLayoutResourceNameRef::ProjectsShelves(inner) => inner.validate(),
+            ShelfLayoutResourceNameRef::UsersShelves(inner) => inner.validate(),
         }
     }
-}
 
-impl fmt::Display for ShelfResourceName {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// Converts the borrowed view into an owned ShelfLayoutResourceName.
+    pub fn to_owned(self) -> ShelfLayoutResourceName {
         match self {
-            ShelfResourceName::Projects(inner) => write!(f, "{inner}"),
-            ShelfResourceName::Users(inner) => write!(f, "{inner}"),
+            ShelfLayoutResourceNameRef::ProjectsShelves(inner) => ShelfLayoutResourceName::ProjectsShelves(inner.to_owned()),
+            ShelfLayoutResourceNameRef::UsersShelves(inner) => ShelfLayoutResourceName::UsersShelves(inner.to_owned()),
         }
     }
 }
 
-impl FromStr for ShelfResourceName {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if let Ok(parsed) = ProjectsShelfResourceName::from_str(s) {
-            return Ok(ShelfResourceName::Projects(parsed));
-        }
-        if let Ok(parsed) = UsersShelfResourceName::from_str(s) {
-            return Ok(ShelfResourceName::Users(parsed));
+impl fmt::Display for ShelfLayoutResourceNameRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShelfLayoutResourceNameRef::ProjectsShelves(inner) => write!(f, "{inner}"),
+            ShelfLayoutResourceNameRef::UsersShelves(inner) => write!(f, "{inner}"),
         }
-        Err("no matching pattern".to_string())
     }
 }
 
 /// Parses a resource name string and returns the appropriate type.
-pub fn parse_shelf_resource_name(name: &str) -> Result<ShelfResourceName, String> {
-    ShelfResourceName::from_str(name)
+pub fn parse_shelf_layout_resource_name(name: &str) -> Result<ShelfLayoutResourceName, ResourceNameError> {
+    ShelfLayoutResourceName::from_str(name)
 }
 
-/// Resource name for library.googleapis.com/Shelf
+/// Resource name for library.googleapis.com/ShelfLayout, a singleton of its parent (AIP-156).
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ProjectsShelfResourceName {
+pub struct ProjectsShelvesShelfLayoutResourceName {
     pub project: String,
     pub shelf: String,
 }
 
-impl ProjectsShelfResourceName {
-    /// Creates a new ProjectsShelfResourceName.
+impl ProjectsShelvesShelfLayoutResourceName {
+    /// Creates a new ProjectsShelvesShelfLayoutResourceName.
     pub fn new(
         project: impl Into<String>,
         shelf: impl Into<String>
@@ -165,658 +305,6460 @@ impl ProjectsShelfResourceName {
     }
 
     /// Validates the resource name.
-    pub fn validate(&self) -> Result<(), String> {
-        if self.project.is_empty() {
-            return Err("project: empty".to_string());
-        }
-        if self.project.contains('/') {
-            return Err("project: contains illegal character '/'".to_string());
-        }
-        if self.shelf.is_empty() {
-            return Err("shelf: empty".to_string());
-        }
-        if self.shelf.contains('/') {
-            return Err("shelf: contains illegal character '/'".to_string());
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        self.as_borrowed().validate()
+    }
+
+    /// Borrows the resource name as a ProjectsShelvesShelfLayoutResourceNameRef.
+    pub fn as_borrowed(&self) -> ProjectsShelvesShelfLayoutResourceNameRef<'_> {
+        ProjectsShelvesShelfLayoutResourceNameRef {
+            project: &self.project,
+            shelf: &self.shelf,
         }
-        Ok(())
     }
 
     /// Returns the resource type.
     pub fn resource_type(&self) -> &'static str {
-        "library.googleapis.com/Shelf"
+        "library.googleapis.com/ShelfLayout"
     }
 
-    /// Returns true if any field contains a wildcard.
-    pub fn contains_wildcard(&self) -> bool {
-        self.project == "-" || self.shelf == "-"
+    /// Returns the name of the service the resource belongs to.
+    pub fn service_name(&self) -> &'static str {
+        "library.googleapis.com"
     }
 
-}
+    /// Returns the full resource name, prefixed with the service name.
+    pub fn to_full_name(&self) -> String {
+        format!("//{}/{}", self.service_name(), self)
+    }
 
-impl fmt::Display for ProjectsShelfResourceName {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "projects/{project}/shelves/{shelf}"
-            , project = self.project
-            , shelf = self.shelf
-        )
+    /// Parses a full resource name, which must belong to the service of the resource.
+    pub fn from_full_name(full_name: &str) -> Result<Self, ResourceNameError> {
+        strip_service(full_name, "library.googleapis.com")?.parse()
     }
-}
 
-impl FromStr for ProjectsShelfResourceName {
-    type Err = String;
+    /// Returns the API version of the resource, from its proto package.
+    pub fn api_version(&self) -> &'static str {
+        "v1"
+    }
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts: Vec<&str> = s.split('/').collect();
-        if parts.len() != 4 {
-            return Err(format!("expected {expected_parts} parts, got {}", parts.len(), expected_parts = 4));
-        }
-        if parts[0] != "projects" {
-            return Err(format!("expected 'projects' at position 0, got '{part}'", part = parts[0]));
-        }
-        let project = parts[1].to_string();
-        if parts[2] != "shelves" {
-            return Err(format!("expected 'shelves' at position 2, got '{part}'", part = parts[2]));
-        }
-        let shelf = parts[3].to_string();
-        let result = Self {
-            project,
-            shelf,
-        };
-        result.validate()?;
-        Ok(result)
+    /// Returns the resource URI in the given API version, or else in the one of the resource.
+    pub fn to_uri(&self, version: Option<&str>) -> String {
+        let version = version.unwrap_or(self.api_version());
+        format!("https://{}/{}/{}", self.service_name(), version, self)
     }
-}
 
-/// Resource name for library.googleapis.com/Shelf
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct UsersShelfResourceName {
-    pub user: String,
-    pub shelf: String,
-}
+    /// Parses a resource URI of the service of the resource, and returns the name with the
+    /// API version of the URI.
+    pub fn from_uri(uri: &str) -> Result<(Self, &str), ResourceNameError> {
+        let (version, name) = aip_resourcename::split_uri(uri, "library.googleapis.com")?;
+        Ok((name.parse()?, version))
+    }
 
-impl UsersShelfResourceName {
-    /// Creates a new UsersShelfResourceName.
-    pub fn new(
-        user: impl Into<String>,
-        shelf: impl Into<String>
-    ) -> Self {
-        Self {
-            user: user.into(),
-            shelf: shelf.into(),
-        }
+    /// Returns true if any field contains a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        self.project == "-" || self.shelf == "-"
     }
 
-    /// Validates the resource name.
-    pub fn validate(&self) -> Result<(), String> {
-        if self.user.is_empty() {
-            return Err("user: empty".to_string());
-        }
-        if self.user.contains('/') {
-            return Err("user: contains illegal character '/'".to_string());
-        }
-        if self.shelf.is_empty() {
-            return Err("shelf: empty".to_string());
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        (self.project == "-" || self.project == concrete.project)
+            && (self.shelf == "-" || self.shelf == concrete.shelf)
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(mut self, variables: &[&str]) -> Self {
+        if variables.contains(&"project") {
+            self.project = "-".to_string();
         }
-        if self.shelf.contains('/') {
-            return Err("shelf: contains illegal character '/'".to_string());
+        if variables.contains(&"shelf") {
+            self.shelf = "-".to_string();
         }
+        self
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        self.validate()?;
+        reject_wildcard("project", &self.project)?;
+        reject_wildcard("shelf", &self.shelf)?;
         Ok(())
     }
 
-    /// Returns the resource type.
-    pub fn resource_type(&self) -> &'static str {
-        "library.googleapis.com/Shelf"
+    /// Returns true if `other` is the name of a resource nested under this one.
+    pub fn is_ancestor_of(&self, other: &str) -> bool {
+        ResourceName::is_ancestor_of(self, other)
     }
 
-    /// Returns true if any field contains a wildcard.
-    pub fn contains_wildcard(&self) -> bool {
-        self.user == "-" || self.shelf == "-"
+    /// Returns true if the name matches a glob such as `projects/acme/books/*`, where `*`
+    /// matches one segment and a final `**` any suffix. Globs that cannot match names of
+    /// this type match nothing.
+    pub fn matches_glob(&self, glob: &str) -> bool {
+        ResourceName::matches_glob(self, glob)
     }
 
 }
 
-impl fmt::Display for UsersShelfResourceName {
+impl fmt::Display for ProjectsShelvesShelfLayoutResourceName {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "users/{user}/shelves/{shelf}"
-            , user = self.user
-            , shelf = self.shelf
-        )
+        fmt::Display::fmt(&self.as_borrowed(), f)
     }
 }
 
-impl FromStr for UsersShelfResourceName {
-    type Err = String;
+impl FromStr for ProjectsShelvesShelfLayoutResourceName {
+    type Err = ResourceNameError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts: Vec<&str> = s.split('/').collect();
-        if parts.len() != 4 {
-            return Err(format!("expected {expected_parts} parts, got {}", parts.len(), expected_parts = 4));
-        }
-        if parts[0] != "users" {
-            return Err(format!("expected 'users' at position 0, got '{part}'", part = parts[0]));
-        }
-        let user = parts[1].to_string();
-        if parts[2] != "shelves" {
-            return Err(format!("expected 'shelves' at position 2, got '{part}'", part = parts[2]));
-        }
-        let shelf = parts[3].to_string();
-        let result = Self {
-            user,
-            shelf,
-        };
-        result.validate()?;
-        Ok(result)
+        ProjectsShelvesShelfLayoutResourceNameRef::parse_borrowed(s).map(ProjectsShelvesShelfLayoutResourceNameRef::to_owned)
     }
 }
 
-/// Multi-pattern resource name for library.googleapis.com/Author
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum AuthorResourceName {
-    Authors(AuthorsAuthorResourceName),
-}
+impl ResourceName for ProjectsShelvesShelfLayoutResourceName {
+    const TYPE: &'static str = "library.googleapis.com/ShelfLayout";
+    const PATTERNS: &'static [&'static str] = &["projects/{project}/shelves/{shelf}/layout"];
+    const SINGULAR: &'static str = "shelfLayout";
+    const PLURAL: &'static str = "shelfLayouts";
 
-impl AuthorResourceName {
-    /// Returns the resource type.
-    pub fn resource_type(&self) -> &'static str {
-        "library.googleapis.com/Author"
+    fn validate(&self) -> Result<(), ResourceNameError> {
+        self.validate()
     }
 
-    /// Returns true if any field contains a wildcard.
-    pub fn contains_wildcard(&self) -> bool {
-        match self {
-            AuthorResourceName::Authors(inner) => inner.contains_wildcard(),
-        }
+    fn contains_wildcard(&self) -> bool {
+        self.contains_wildcard()
     }
+}
 
-    /// Validates the resource name.
-    pub fn validate(&self) -> Result<(), String> {
-        match self {
-            AuthorResourceName::Authors(inner) => inner.validate(),
-        }
+impl Serialize for ProjectsShelvesShelfLayoutResourceName {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_variables(serializer, [("project", &self.project), ("shelf", &self.shelf)])
     }
 }
 
-impl fmt::Display for AuthorResourceName {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            AuthorResourceName::Authors(inner) => write!(f, "{inner}"),
-        }
+impl<'de> Deserialize<'de> for ProjectsShelvesShelfLayoutResourceName {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let [project, shelf] = deserialize_variables(deserializer, ["project", "shelf"])?;
+        validated(Self { project, shelf })
     }
 }
 
-impl FromStr for AuthorResourceName {
-    type Err = String;
+/// Borrowed view of a ProjectsShelvesShelfLayoutResourceName that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ProjectsShelvesShelfLayoutResourceNameRef<'a> {
+    pub project: &'a str,
+    pub shelf: &'a str,
+}
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if let Ok(parsed) = AuthorsAuthorResourceName::from_str(s) {
-            return Ok(AuthorResourceName::Authors(parsed));
+impl<'a> ProjectsShelvesShelfLayoutResourceNameRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut scanner = Scanner::new(s, 5);
+        scanner.literal("projects")?;
+        let project = scanner.variable()?;
+        scanner.literal("shelves")?;
+        let shelf = scanner.variable()?;
+        scanner.literal("layout")?;
+        scanner.finish()?;
+        Self::from_segments(project, shelf)
+    }
+
+    /// Creates a ProjectsShelvesShelfLayoutResourceNameRef from parsed segments and validates it.
+    fn from_segments(project: &'a str, shelf: &'a str) -> Result<Self, ResourceNameError> {
+        let name = Self { project, shelf };
+        name.validate()?;
+        Ok(name)
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        validate_segment("project", self.project)?;
+        validate_segment("shelf", self.shelf)?;
+        Ok(())
+    }
+
+    /// Converts the borrowed view into an owned ProjectsShelvesShelfLayoutResourceName.
+    pub fn to_owned(self) -> ProjectsShelvesShelfLayoutResourceName {
+        ProjectsShelvesShelfLayoutResourceName {
+            project: self.project.to_string(),
+            shelf: self.shelf.to_string(),
         }
-        Err("no matching pattern".to_string())
     }
 }
 
-/// Parses a resource name string and returns the appropriate type.
-pub fn parse_author_resource_name(name: &str) -> Result<AuthorResourceName, String> {
-    AuthorResourceName::from_str(name)
+impl fmt::Display for ProjectsShelvesShelfLayoutResourceNameRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "projects/{project}/shelves/{shelf}/layout"
+            , project = self.project
+            , shelf = self.shelf
+        )
+    }
 }
 
-/// Resource name for library.googleapis.com/Author
+/// Resource name for library.googleapis.com/ShelfLayout, a singleton of its parent (AIP-156).
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct AuthorsAuthorResourceName {
-    pub author: String,
+pub struct UsersShelvesShelfLayoutResourceName {
+    pub user: String,
+    pub shelf: String,
 }
 
-impl AuthorsAuthorResourceName {
-    /// Creates a new AuthorsAuthorResourceName.
+impl UsersShelvesShelfLayoutResourceName {
+    /// Creates a new UsersShelvesShelfLayoutResourceName.
     pub fn new(
-        author: impl Into<String>
+        user: impl Into<String>,
+        shelf: impl Into<String>
     ) -> Self {
         Self {
-            author: author.into(),
+            user: user.into(),
+            shelf: shelf.into(),
         }
     }
 
     /// Validates the resource name.
-    pub fn validate(&self) -> Result<(), String> {
-        if self.author.is_empty() {
-            return Err("author: empty".to_string());
-        }
-        if self.author.contains('/') {
-            return Err("author: contains illegal character '/'".to_string());
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        self.as_borrowed().validate()
+    }
+
+    /// Borrows the resource name as a UsersShelvesShelfLayoutResourceNameRef.
+    pub fn as_borrowed(&self) -> UsersShelvesShelfLayoutResourceNameRef<'_> {
+        UsersShelvesShelfLayoutResourceNameRef {
+            user: &self.user,
+            shelf: &self.shelf,
         }
-        Ok(())
     }
 
     /// Returns the resource type.
     pub fn resource_type(&self) -> &'static str {
-        "library.googleapis.com/Author"
+        "library.googleapis.com/ShelfLayout"
     }
 
-    /// Returns true if any field contains a wildcard.
-    pub fn contains_wildcard(&self) -> bool {
-        self.author == "-"
+    /// Returns the name of the service the resource belongs to.
+    pub fn service_name(&self) -> &'static str {
+        "library.googleapis.com"
     }
 
-}
+    /// Returns the full resource name, prefixed with the service name.
+    pub fn to_full_name(&self) -> String {
+        format!("//{}/{}", self.service_name(), self)
+    }
 
-impl fmt::Display for AuthorsAuthorResourceName {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "authors/{author}"
-            , author = self.author
-        )
+    /// Parses a full resource name, which must belong to the service of the resource.
+    pub fn from_full_name(full_name: &str) -> Result<Self, ResourceNameError> {
+        strip_service(full_name, "library.googleapis.com")?.parse()
     }
+
+    /// Returns the API version of the resource, from its proto package.
+    pub fn api_version(&self) -> &'static str {
+        "v1"
+    }
+
+    /// Returns the resource URI in the given API version, or else in the one of the resource.
+    pub fn to_uri(&self, version: Option<&str>) -> String {
+        let version = version.unwrap_or(self.api_version());
+        format!("https://{}/{}/{}", self.service_name(), version, self)
+    }
+
+    /// Parses a resource URI of the service of the resource, and returns the name with the
+    /// API version of the URI.
+    pub fn from_uri(uri: &str) -> Result<(Self, &str), ResourceNameError> {
+        let (version, name) = aip_resourcename::split_uri(uri, "library.googleapis.com")?;
+        Ok((name.parse()?, version))
+    }
+
+    /// Returns true if any field contains a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        self.user == "-" || self.shelf == "-"
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        (self.user == "-" || self.user == concrete.user)
+            && (self.shelf == "-" || self.shelf == concrete.shelf)
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(mut self, variables: &[&str]) -> Self {
+        if variables.contains(&"user") {
+            self.user = "-".to_string();
+        }
+        if variables.contains(&"shelf") {
+            self.shelf = "-".to_string();
+        }
+        self
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        self.validate()?;
+        reject_wildcard("user", &self.user)?;
+        reject_wildcard("shelf", &self.shelf)?;
+        Ok(())
+    }
+
+    /// Returns true if `other` is the name of a resource nested under this one.
+    pub fn is_ancestor_of(&self, other: &str) -> bool {
+        ResourceName::is_ancestor_of(self, other)
+    }
+
+    /// Returns true if the name matches a glob such as `projects/acme/books/*`, where `*`
+    /// matches one segment and a final `**` any suffix. Globs that cannot match names of
+    /// this type match nothing.
+    pub fn matches_glob(&self, glob: &str) -> bool {
+        ResourceName::matches_glob(self, glob)
+    }
+
 }
 
-impl FromStr for AuthorsAuthorResourceName {
-    type Err = String;
+impl fmt::Display for UsersShelvesShelfLayoutResourceName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_borrowed(), f)
+    }
+}
+
+impl FromStr for UsersShelvesShelfLayoutResourceName {
+    type Err = ResourceNameError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts: Vec<&str> = s.split('/').collect();
-        if parts.len() != 2 {
-            return Err(format!("expected {expected_parts} parts, got {}", parts.len(), expected_parts = 2));
+        UsersShelvesShelfLayoutResourceNameRef::parse_borrowed(s).map(UsersShelvesShelfLayoutResourceNameRef::to_owned)
+    }
+}
+
+impl ResourceName for UsersShelvesShelfLayoutResourceName {
+    const TYPE: &'static str = "library.googleapis.com/ShelfLayout";
+    const PATTERNS: &'static [&'static str] = &["users/{user}/shelves/{shelf}/layout"];
+    const SINGULAR: &'static str = "shelfLayout";
+    const PLURAL: &'static str = "shelfLayouts";
+
+    fn validate(&self) -> Result<(), ResourceNameError> {
+        self.validate()
+    }
+
+    fn contains_wildcard(&self) -> bool {
+        self.contains_wildcard()
+    }
+}
+
+impl Serialize for UsersShelvesShelfLayoutResourceName {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_variables(serializer, [("user", &self.user), ("shelf", &self.shelf)])
+    }
+}
+
+impl<'de> Deserialize<'de> for UsersShelvesShelfLayoutResourceName {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let [user, shelf] = deserialize_variables(deserializer, ["user", "shelf"])?;
+        validated(Self { user, shelf })
+    }
+}
+
+/// Borrowed view of a UsersShelvesShelfLayoutResourceName that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UsersShelvesShelfLayoutResourceNameRef<'a> {
+    pub user: &'a str,
+    pub shelf: &'a str,
+}
+
+impl<'a> UsersShelvesShelfLayoutResourceNameRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut scanner = Scanner::new(s, 5);
+        scanner.literal("users")?;
+        let user = scanner.variable()?;
+        scanner.literal("shelves")?;
+        let shelf = scanner.variable()?;
+        scanner.literal("layout")?;
+        scanner.finish()?;
+        Self::from_segments(user, shelf)
+    }
+
+    /// Creates a UsersShelvesShelfLayoutResourceNameRef from parsed segments and validates it.
+    fn from_segments(user: &'a str, shelf: &'a str) -> Result<Self, ResourceNameError> {
+        let name = Self { user, shelf };
+        name.validate()?;
+        Ok(name)
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        validate_segment("user", self.user)?;
+        validate_segment("shelf", self.shelf)?;
+        Ok(())
+    }
+
+    /// Converts the borrowed view into an owned UsersShelvesShelfLayoutResourceName.
+    pub fn to_owned(self) -> UsersShelvesShelfLayoutResourceName {
+        UsersShelvesShelfLayoutResourceName {
+            user: self.user.to_string(),
+            shelf: self.shelf.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for UsersShelvesShelfLayoutResourceNameRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "users/{user}/shelves/{shelf}/layout"
+            , user = self.user
+            , shelf = self.shelf
+        )
+    }
+}
+
+/// Parent of a ShelfLayoutResourceName.
+pub type ShelfLayoutParent = ShelfResourceName;
+
+impl ProjectsShelvesShelfLayoutResourceName {
+    /// Returns the name of the parent of this resource.
+    pub fn parent(&self) -> ProjectsShelfResourceName {
+        ProjectsShelfResourceName {
+            project: self.project.clone(),
+            shelf: self.shelf.clone(),
         }
-        if parts[0] != "authors" {
-            return Err(format!("expected 'authors' at position 0, got '{part}'", part = parts[0]));
+    }
+}
+
+impl UsersShelvesShelfLayoutResourceName {
+    /// Returns the name of the parent of this resource.
+    pub fn parent(&self) -> UsersShelfResourceName {
+        UsersShelfResourceName {
+            user: self.user.clone(),
+            shelf: self.shelf.clone(),
         }
-        let author = parts[1].to_string();
-        let result = Self {
-            author,
-        };
-        result.validate()?;
-        Ok(result)
     }
 }
 
-/// Resource name for library.googleapis.com/Review
+impl ShelfLayoutResourceName {
+    /// Returns the name of the parent of this resource.
+    pub fn parent(&self) -> ShelfResourceName {
+        match self {
+            ShelfLayoutResourceName::ProjectsShelves(inner) => ShelfResourceName::Projects(inner.parent()),
+            ShelfLayoutResourceName::UsersShelves(inner) => ShelfResourceName::Users(inner.parent()),
+        }
+    }
+}
+
+impl ProjectsShelvesShelfLayoutResourceName {
+    /// Returns the name of the ShelfResourceName this resource is nested under.
+    pub fn shelf(&self) -> ShelfResourceName {
+        ShelfResourceName::Projects(ProjectsShelfResourceName {
+            project: self.project.clone(),
+            shelf: self.shelf.clone(),
+        })
+    }
+}
+
+impl UsersShelvesShelfLayoutResourceName {
+    /// Returns the name of the ShelfResourceName this resource is nested under.
+    pub fn shelf(&self) -> ShelfResourceName {
+        ShelfResourceName::Users(UsersShelfResourceName {
+            user: self.user.clone(),
+            shelf: self.shelf.clone(),
+        })
+    }
+}
+
+impl ShelfLayoutResourceName {
+    /// Returns the name of the ShelfResourceName this resource is nested under.
+    pub fn shelf(&self) -> ShelfResourceName {
+        match self {
+            ShelfLayoutResourceName::ProjectsShelves(inner) => inner.shelf(),
+            ShelfLayoutResourceName::UsersShelves(inner) => inner.shelf(),
+        }
+    }
+}
+
+impl ProjectsShelfResourceName {
+    /// Creates the name of a ShelfLayoutResourceName nested under this one.
+    pub fn shelf_layout(&self) -> ShelfLayoutResourceName {
+        ShelfLayoutResourceName::ProjectsShelves(ProjectsShelvesShelfLayoutResourceName { project: self.project.clone(), shelf: self.shelf.clone() })
+    }
+}
+
+impl UsersShelfResourceName {
+    /// Creates the name of a ShelfLayoutResourceName nested under this one.
+    pub fn shelf_layout(&self) -> ShelfLayoutResourceName {
+        ShelfLayoutResourceName::UsersShelves(UsersShelvesShelfLayoutResourceName { user: self.user.clone(), shelf: self.shelf.clone() })
+    }
+}
+
+impl ShelfResourceName {
+    /// Creates the name of a ShelfLayoutResourceName nested under this one.
+    pub fn shelf_layout(&self) -> ShelfLayoutResourceName {
+        match self {
+            ShelfResourceName::Projects(inner) => inner.shelf_layout(),
+            ShelfResourceName::Users(inner) => inner.shelf_layout(),
+        }
+    }
+}
+
+/// Resource name for library.googleapis.com/Edition
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ReviewResourceName {
+pub struct EditionResourceName {
     pub project: String,
     pub book: String,
-    pub review: String,
+    pub year: String,
+    pub format: String,
 }
 
-impl ReviewResourceName {
-    /// Creates a new ReviewResourceName.
+impl EditionResourceName {
+    /// Creates a new EditionResourceName.
     pub fn new(
         project: impl Into<String>,
         book: impl Into<String>,
-        review: impl Into<String>
+        year: impl Into<String>,
+        format: impl Into<String>
     ) -> Self {
         Self {
             project: project.into(),
             book: book.into(),
-            review: review.into(),
+            year: year.into(),
+            format: format.into(),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        self.as_borrowed().validate()
+    }
+
+    /// Borrows the resource name as a EditionResourceNameRef.
+    pub fn as_borrowed(&self) -> EditionResourceNameRef<'_> {
+        EditionResourceNameRef {
+            project: &self.project,
+            book: &self.book,
+            year: &self.year,
+            format: &self.format,
+        }
+    }
+
+    /// Returns the resource type.
+    pub fn resource_type(&self) -> &'static str {
+        "library.googleapis.com/Edition"
+    }
+
+    /// Returns the name of the service the resource belongs to.
+    pub fn service_name(&self) -> &'static str {
+        "library.googleapis.com"
+    }
+
+    /// Returns the full resource name, prefixed with the service name.
+    pub fn to_full_name(&self) -> String {
+        format!("//{}/{}", self.service_name(), self)
+    }
+
+    /// Parses a full resource name, which must belong to the service of the resource.
+    pub fn from_full_name(full_name: &str) -> Result<Self, ResourceNameError> {
+        strip_service(full_name, "library.googleapis.com")?.parse()
+    }
+
+    /// Returns the API version of the resource, from its proto package.
+    pub fn api_version(&self) -> &'static str {
+        "v1"
+    }
+
+    /// Returns the resource URI in the given API version, or else in the one of the resource.
+    pub fn to_uri(&self, version: Option<&str>) -> String {
+        let version = version.unwrap_or(self.api_version());
+        format!("https://{}/{}/{}", self.service_name(), version, self)
+    }
+
+    /// Parses a resource URI of the service of the resource, and returns the name with the
+    /// API version of the URI.
+    pub fn from_uri(uri: &str) -> Result<(Self, &str), ResourceNameError> {
+        let (version, name) = aip_resourcename::split_uri(uri, "library.googleapis.com")?;
+        Ok((name.parse()?, version))
+    }
+
+    /// Returns true if any field contains a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        self.project == "-" || self.book == "-" || self.year == "-" || self.format == "-"
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        (self.project == "-" || self.project == concrete.project)
+            && (self.book == "-" || self.book == concrete.book)
+            && (self.year == "-" || self.year == concrete.year)
+            && (self.format == "-" || self.format == concrete.format)
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(mut self, variables: &[&str]) -> Self {
+        if variables.contains(&"project") {
+            self.project = "-".to_string();
+        }
+        if variables.contains(&"book") {
+            self.book = "-".to_string();
+        }
+        if variables.contains(&"year") {
+            self.year = "-".to_string();
+        }
+        if variables.contains(&"format") {
+            self.format = "-".to_string();
+        }
+        self
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        self.validate()?;
+        reject_wildcard("project", &self.project)?;
+        reject_wildcard("book", &self.book)?;
+        reject_wildcard("year", &self.year)?;
+        reject_wildcard("format", &self.format)?;
+        Ok(())
+    }
+
+    /// Returns true if `other` is the name of a resource nested under this one.
+    pub fn is_ancestor_of(&self, other: &str) -> bool {
+        ResourceName::is_ancestor_of(self, other)
+    }
+
+    /// Returns true if the name matches a glob such as `projects/acme/books/*`, where `*`
+    /// matches one segment and a final `**` any suffix. Globs that cannot match names of
+    /// this type match nothing.
+    pub fn matches_glob(&self, glob: &str) -> bool {
+        ResourceName::matches_glob(self, glob)
+    }
+
+}
+
+impl fmt::Display for EditionResourceName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_borrowed(), f)
+    }
+}
+
+impl FromStr for EditionResourceName {
+    type Err = ResourceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        EditionResourceNameRef::parse_borrowed(s).map(EditionResourceNameRef::to_owned)
+    }
+}
+
+impl ResourceName for EditionResourceName {
+    const TYPE: &'static str = "library.googleapis.com/Edition";
+    const PATTERNS: &'static [&'static str] = &["projects/{project}/books/{book}/editions/{year}~{format}"];
+    const SINGULAR: &'static str = "edition";
+    const PLURAL: &'static str = "editions";
+
+    fn validate(&self) -> Result<(), ResourceNameError> {
+        self.validate()
+    }
+
+    fn contains_wildcard(&self) -> bool {
+        self.contains_wildcard()
+    }
+}
+
+impl Serialize for EditionResourceName {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_variables(serializer, [("project", &self.project), ("book", &self.book), ("year", &self.year), ("format", &self.format)])
+    }
+}
+
+impl<'de> Deserialize<'de> for EditionResourceName {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let [project, book, year, format] = deserialize_variables(deserializer, ["project", "book", "year", "format"])?;
+        validated(Self { project, book, year, format })
+    }
+}
+
+/// Borrowed view of a EditionResourceName that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EditionResourceNameRef<'a> {
+    pub project: &'a str,
+    pub book: &'a str,
+    pub year: &'a str,
+    pub format: &'a str,
+}
+
+impl<'a> EditionResourceNameRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut scanner = Scanner::new(s, 6);
+        scanner.literal("projects")?;
+        let project = scanner.variable()?;
+        scanner.literal("books")?;
+        let book = scanner.variable()?;
+        scanner.literal("editions")?;
+        let [year, format] = aip_resourcename::split_segment("{year}~{format}", scanner.variable()?, &['~'])?;
+        scanner.finish()?;
+        Self::from_segments(project, book, year, format)
+    }
+
+    /// Creates a EditionResourceNameRef from parsed segments and validates it.
+    fn from_segments(project: &'a str, book: &'a str, year: &'a str, format: &'a str) -> Result<Self, ResourceNameError> {
+        let name = Self { project, book, year, format };
+        name.validate()?;
+        Ok(name)
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        validate_segment("project", self.project)?;
+        validate_segment("book", self.book)?;
+        validate_segment("year", self.year)?;
+        validate_segment("format", self.format)?;
+        aip_resourcename::reject_separator("year", self.year, '~')?;
+        Ok(())
+    }
+
+    /// Converts the borrowed view into an owned EditionResourceName.
+    pub fn to_owned(self) -> EditionResourceName {
+        EditionResourceName {
+            project: self.project.to_string(),
+            book: self.book.to_string(),
+            year: self.year.to_string(),
+            format: self.format.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for EditionResourceNameRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "projects/{project}/books/{book}/editions/{year}~{format}"
+            , project = self.project
+            , book = self.book
+            , year = self.year
+            , format = self.format
+        )
+    }
+}
+
+/// Parent of a EditionResourceName.
+pub type EditionParent = BookResourceName;
+
+impl EditionResourceName {
+    /// Returns the name of the parent of this resource.
+    pub fn parent(&self) -> BookResourceName {
+        BookResourceName {
+            project: self.project.clone(),
+            book: self.book.clone(),
+        }
+    }
+}
+
+impl EditionResourceName {
+    /// Returns the name of the BookResourceName this resource is nested under.
+    pub fn book(&self) -> BookResourceName {
+        BookResourceName {
+            project: self.project.clone(),
+            book: self.book.clone(),
+        }
+    }
+}
+
+impl BookResourceName {
+    /// Creates the name of a EditionResourceName nested under this one.
+    pub fn edition(&self, year: impl Into<String>, format: impl Into<String>) -> EditionResourceName {
+        EditionResourceName { project: self.project.clone(), book: self.book.clone(), year: year.into(), format: format.into() }
+    }
+}
+
+/// Multi-pattern resource name for library.googleapis.com/Attachment
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AttachmentResourceName {
+    ProjectsBooks(ProjectsBooksAttachmentResourceName),
+    UsersShelves(UsersShelvesAttachmentResourceName),
+}
+
+impl AttachmentResourceName {
+    /// Returns the resource type.
+    pub fn resource_type(&self) -> &'static str {
+        "library.googleapis.com/Attachment"
+    }
+
+    /// Returns true if any field contains a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        match self {
+            AttachmentResourceName::ProjectsBooks(inner) => inner.contains_wildcard(),
+            AttachmentResourceName::UsersShelves(inner) => inner.contains_wildcard(),
+        }
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    /// Names of different patterns are matched segment by segment.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        match (self, concrete) {
+            (AttachmentResourceName::ProjectsBooks(inner), AttachmentResourceName::ProjectsBooks(concrete)) => inner.matches(concrete),
+            (AttachmentResourceName::UsersShelves(inner), AttachmentResourceName::UsersShelves(concrete)) => inner.matches(concrete),
+            _ => aip_resourcename::wildcard_matches(&self.to_string(), &concrete.to_string()),
+        }
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(self, variables: &[&str]) -> Self {
+        match self {
+            AttachmentResourceName::ProjectsBooks(inner) => AttachmentResourceName::ProjectsBooks(inner.with_wildcards_in(variables)),
+            AttachmentResourceName::UsersShelves(inner) => AttachmentResourceName::UsersShelves(inner.with_wildcards_in(variables)),
+        }
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        match self {
+            AttachmentResourceName::ProjectsBooks(inner) => inner.validate_concrete(),
+            AttachmentResourceName::UsersShelves(inner) => inner.validate_concrete(),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        match self {
+            AttachmentResourceName::ProjectsBooks(inner) => inner.validate(),
+            AttachmentResourceName::UsersShelves(inner) => inner.validate(),
+        }
+    }
+
+    /// Borrows the resource name as a AttachmentResourceNameRef.
+    pub fn as_borrowed(&self) -> AttachmentResourceNameRef<'_> {
+        match self {
+            AttachmentResourceName::ProjectsBooks(inner) => AttachmentResourceNameRef::ProjectsBooks(inner.as_borrowed()),
+            AttachmentResourceName::UsersShelves(inner) => AttachmentResourceNameRef::UsersShelves(inner.as_borrowed()),
+        }
+    }
+
+    /// Returns true if `other` is the name of a resource nested under this one.
+    pub fn is_ancestor_of(&self, other: &str) -> bool {
+        ResourceName::is_ancestor_of(self, other)
+    }
+
+    /// Returns true if the name matches a glob such as `projects/acme/books/*`, where `*`
+    /// matches one segment and a final `**` any suffix. Globs that cannot match names of
+    /// this type match nothing.
+    pub fn matches_glob(&self, glob: &str) -> bool {
+        ResourceName::matches_glob(self, glob)
+    }
+
+    /// Returns the name of the service the resource belongs to.
+    pub fn service_name(&self) -> &'static str {
+        match self {
+            AttachmentResourceName::ProjectsBooks(inner) => inner.service_name(),
+            AttachmentResourceName::UsersShelves(inner) => inner.service_name(),
+        }
+    }
+
+    /// Returns the full resource name, prefixed with the service name.
+    pub fn to_full_name(&self) -> String {
+        match self {
+            AttachmentResourceName::ProjectsBooks(inner) => inner.to_full_name(),
+            AttachmentResourceName::UsersShelves(inner) => inner.to_full_name(),
         }
     }
 
-    /// Validates the resource name.
-    pub fn validate(&self) -> Result<(), String> {
-        if self.project.is_empty() {
-            return Err("project: empty".to_string());
-        }
-        if self.project.contains('/') {
-            return Err("project: contains illegal character '/'".to_string());
-        }
-        if self.book.is_empty() {
-            return Err("book: empty".to_string());
-        }
-        if self.book.contains('/') {
-            return Err("book: contains illegal character '/'".to_string());
-        }
-        if self.review.is_empty() {
-            return Err("review: empty".to_string());
-        }
-        if self.review.contains('/') {
-            return Err("review: contains illegal character '/'".to_string());
-        }
-        Ok(())
+    /// Parses a full resource name, which must belong to the service of the resource.
+    pub fn from_full_name(full_name: &str) -> Result<Self, ResourceNameError> {
+        strip_service(full_name, "library.googleapis.com")?.parse()
+    }
+
+    /// Returns the API version of the resource, from its proto package.
+    pub fn api_version(&self) -> &'static str {
+        match self {
+            AttachmentResourceName::ProjectsBooks(inner) => inner.api_version(),
+            AttachmentResourceName::UsersShelves(inner) => inner.api_version(),
+        }
+    }
+
+    /// Returns the resource URI in the given API version, or else in the one of the resource.
+    pub fn to_uri(&self, version: Option<&str>) -> String {
+        match self {
+            AttachmentResourceName::ProjectsBooks(inner) => inner.to_uri(version),
+            AttachmentResourceName::UsersShelves(inner) => inner.to_uri(version),
+        }
+    }
+
+    /// Parses a resource URI of the service of the resource, and returns the name with the
+    /// API version of the URI.
+    pub fn from_uri(uri: &str) -> Result<(Self, &str), ResourceNameError> {
+        let (version, name) = aip_resourcename::split_uri(uri, "library.googleapis.com")?;
+        Ok((name.parse()?, version))
+    }
+
+}
+
+impl fmt::Display for AttachmentResourceName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AttachmentResourceName::ProjectsBooks(inner) => write!(f, "{inner}"),
+            AttachmentResourceName::UsersShelves(inner) => write!(f, "{inner}"),
+        }
+    }
+}
+
+impl FromStr for AttachmentResourceName {
+    type Err = ResourceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        AttachmentResourceNameRef::parse_borrowed(s).map(AttachmentResourceNameRef::to_owned)
+    }
+}
+
+impl ResourceName for AttachmentResourceName {
+    const TYPE: &'static str = "library.googleapis.com/Attachment";
+    const PATTERNS: &'static [&'static str] = &["projects/{project}/books/{book}/attachments/{path=**}", "{parent=users/*/shelves/*}/attachments/{path=**}"];
+    const SINGULAR: &'static str = "attachment";
+    const PLURAL: &'static str = "attachments";
+
+    fn validate(&self) -> Result<(), ResourceNameError> {
+        self.validate()
+    }
+
+    fn contains_wildcard(&self) -> bool {
+        self.contains_wildcard()
+    }
+}
+
+impl Serialize for AttachmentResourceName {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            AttachmentResourceName::ProjectsBooks(name) => name.serialize(serializer),
+            AttachmentResourceName::UsersShelves(name) => name.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for AttachmentResourceName {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut variables = aip_resourcename::serde::Variables::deserialize(deserializer)?;
+        if let Some([project, book, path]) = variables.take(["project", "book", "path"]) {
+            return validated(AttachmentResourceName::ProjectsBooks(ProjectsBooksAttachmentResourceName { project, book, path }));
+        }
+        if let Some([parent, path]) = variables.take(["parent", "path"]) {
+            return validated(AttachmentResourceName::UsersShelves(UsersShelvesAttachmentResourceName { parent, path }));
+        }
+        Err(variables.no_match(&[&["project", "book", "path"], &["parent", "path"]]))
+    }
+}
+
+/// Borrowed view of a AttachmentResourceName that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AttachmentResourceNameRef<'a> {
+    ProjectsBooks(ProjectsBooksAttachmentResourceNameRef<'a>),
+    UsersShelves(UsersShelvesAttachmentResourceNameRef<'a>),
+}
+
+impl<'a> AttachmentResourceNameRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut parts = s.split('/');
+        match parts.next() {
+            Some("projects") => {
+                match parts.next() {
+                    Some(part1) => {
+                        match parts.next() {
+                            Some("books") => {
+                                match parts.next() {
+                                    Some(part3) => {
+                                        match parts.next() {
+                                            Some("attachments") => {
+                                                match parts.next() {
+                                                    Some(part5) => {
+                                                        ProjectsBooksAttachmentResourceNameRef::from_segments(part1, part3, aip_resourcename::span_from(s, part5)).map(Self::ProjectsBooks)
+                                                    }
+                                                    None => Err(ResourceNameError::no_matching_pattern("projects/{project}/books/{book}/attachments/{path=**}", 5, None)),
+                                                }
+                                            }
+                                            Some(part) => Err(ResourceNameError::no_matching_pattern("projects/{project}/books/{book}/attachments/{path=**}", 4, Some(part))),
+                                            None => Err(ResourceNameError::no_matching_pattern("projects/{project}/books/{book}/attachments/{path=**}", 4, None)),
+                                        }
+                                    }
+                                    None => Err(ResourceNameError::no_matching_pattern("projects/{project}/books/{book}/attachments/{path=**}", 3, None)),
+                                }
+                            }
+                            Some(part) => Err(ResourceNameError::no_matching_pattern("projects/{project}/books/{book}/attachments/{path=**}", 2, Some(part))),
+                            None => Err(ResourceNameError::no_matching_pattern("projects/{project}/books/{book}/attachments/{path=**}", 2, None)),
+                        }
+                    }
+                    None => Err(ResourceNameError::no_matching_pattern("projects/{project}/books/{book}/attachments/{path=**}", 1, None)),
+                }
+            }
+            Some(part0 @ "users") => {
+                match parts.next() {
+                    Some(_) => {
+                        match parts.next() {
+                            Some("shelves") => {
+                                match parts.next() {
+                                    Some(part3) => {
+                                        match parts.next() {
+                                            Some("attachments") => {
+                                                match parts.next() {
+                                                    Some(part5) => {
+                                                        UsersShelvesAttachmentResourceNameRef::from_segments(aip_resourcename::span(s, part0, part3), aip_resourcename::span_from(s, part5)).map(Self::UsersShelves)
+                                                    }
+                                                    None => Err(ResourceNameError::no_matching_pattern("{parent=users/*/shelves/*}/attachments/{path=**}", 5, None)),
+                                                }
+                                            }
+                                            Some(part) => Err(ResourceNameError::no_matching_pattern("{parent=users/*/shelves/*}/attachments/{path=**}", 4, Some(part))),
+                                            None => Err(ResourceNameError::no_matching_pattern("{parent=users/*/shelves/*}/attachments/{path=**}", 4, None)),
+                                        }
+                                    }
+                                    None => Err(ResourceNameError::no_matching_pattern("{parent=users/*/shelves/*}/attachments/{path=**}", 3, None)),
+                                }
+                            }
+                            Some(part) => Err(ResourceNameError::no_matching_pattern("{parent=users/*/shelves/*}/attachments/{path=**}", 2, Some(part))),
+                            None => Err(ResourceNameError::no_matching_pattern("{parent=users/*/shelves/*}/attachments/{path=**}", 2, None)),
+                        }
+                    }
+                    None => Err(ResourceNameError::no_matching_pattern("{parent=users/*/shelves/*}/attachments/{path=**}", 1, None)),
+                }
+            }
+            Some(part) => Err(ResourceNameError::no_matching_pattern("projects/{project}/books/{book}/attachments/{path=**}", 0, Some(part))),
+            None => Err(ResourceNameError::no_matching_pattern("projects/{project}/books/{book}/attachments/{path=**}", 0, None)),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        match self {
+            AttachmentResourceNameRef::ProjectsBooks(inner) => inner.validate(),
+            AttachmentResourceNameRef::UsersShelves(inner) => inner.validate(),
+        }
+    }
+
+    /// Converts the borrowed view into an owned AttachmentResourceName.
+    pub fn to_owned(self) -> AttachmentResourceName {
+        match self {
+            AttachmentResourceNameRef::ProjectsBooks(inner) => AttachmentResourceName::ProjectsBooks(inner.to_owned()),
+            AttachmentResourceNameRef::UsersShelves(inner) => AttachmentResourceName::UsersShelves(inner.to_owned()),
+        }
+    }
+}
+
+impl fmt::Display for AttachmentResourceNameRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AttachmentResourceNameRef::ProjectsBooks(inner) => write!(f, "{inner}"),
+            AttachmentResourceNameRef::UsersShelves(inner) => write!(f, "{inner}"),
+        }
+    }
+}
+
+/// Parses a resource name string and returns the appropriate type.
+pub fn parse_attachment_resource_name(name: &str) -> Result<AttachmentResourceName, ResourceNameError> {
+    AttachmentResourceName::from_str(name)
+}
+
+/// Resource name for library.googleapis.com/Attachment
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ProjectsBooksAttachmentResourceName {
+    pub project: String,
+    pub book: String,
+    pub path: String,
+}
+
+impl ProjectsBooksAttachmentResourceName {
+    /// Creates a new ProjectsBooksAttachmentResourceName.
+    pub fn new(
+        project: impl Into<String>,
+        book: impl Into<String>,
+        path: impl Into<String>
+    ) -> Self {
+        Self {
+            project: project.into(),
+            book: book.into(),
+            path: path.into(),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        self.as_borrowed().validate()
+    }
+
+    /// Borrows the resource name as a ProjectsBooksAttachmentResourceNameRef.
+    pub fn as_borrowed(&self) -> ProjectsBooksAttachmentResourceNameRef<'_> {
+        ProjectsBooksAttachmentResourceNameRef {
+            project: &self.project,
+            book: &self.book,
+            path: &self.path,
+        }
+    }
+
+    /// Returns the resource type.
+    pub fn resource_type(&self) -> &'static str {
+        "library.googleapis.com/Attachment"
+    }
+
+    /// Returns the name of the service the resource belongs to.
+    pub fn service_name(&self) -> &'static str {
+        "library.googleapis.com"
+    }
+
+    /// Returns the full resource name, prefixed with the service name.
+    pub fn to_full_name(&self) -> String {
+        format!("//{}/{}", self.service_name(), self)
+    }
+
+    /// Parses a full resource name, which must belong to the service of the resource.
+    pub fn from_full_name(full_name: &str) -> Result<Self, ResourceNameError> {
+        strip_service(full_name, "library.googleapis.com")?.parse()
+    }
+
+    /// Returns the API version of the resource, from its proto package.
+    pub fn api_version(&self) -> &'static str {
+        "v1"
+    }
+
+    /// Returns the resource URI in the given API version, or else in the one of the resource.
+    pub fn to_uri(&self, version: Option<&str>) -> String {
+        let version = version.unwrap_or(self.api_version());
+        format!("https://{}/{}/{}", self.service_name(), version, self)
+    }
+
+    /// Parses a resource URI of the service of the resource, and returns the name with the
+    /// API version of the URI.
+    pub fn from_uri(uri: &str) -> Result<(Self, &str), ResourceNameError> {
+        let (version, name) = aip_resourcename::split_uri(uri, "library.googleapis.com")?;
+        Ok((name.parse()?, version))
+    }
+
+    /// Returns true if any field contains a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        self.project == "-" || self.book == "-" || self.path.split('/').any(|segment| segment == "-")
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        (self.project == "-" || self.project == concrete.project)
+            && (self.book == "-" || self.book == concrete.book)
+            && (aip_resourcename::capture_matches("**", &self.path, &concrete.path))
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(mut self, variables: &[&str]) -> Self {
+        if variables.contains(&"project") {
+            self.project = "-".to_string();
+        }
+        if variables.contains(&"book") {
+            self.book = "-".to_string();
+        }
+        if variables.contains(&"path") {
+            self.path = aip_resourcename::capture_wildcard("**");
+        }
+        self
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        self.validate()?;
+        reject_wildcard("project", &self.project)?;
+        reject_wildcard("book", &self.book)?;
+        for segment in self.path.split('/') {
+            reject_wildcard("path", segment)?;
+        }
+        Ok(())
+    }
+
+    /// Returns true if `other` is the name of a resource nested under this one.
+    pub fn is_ancestor_of(&self, other: &str) -> bool {
+        ResourceName::is_ancestor_of(self, other)
+    }
+
+    /// Returns true if the name matches a glob such as `projects/acme/books/*`, where `*`
+    /// matches one segment and a final `**` any suffix. Globs that cannot match names of
+    /// this type match nothing.
+    pub fn matches_glob(&self, glob: &str) -> bool {
+        ResourceName::matches_glob(self, glob)
+    }
+
+}
+
+impl fmt::Display for ProjectsBooksAttachmentResourceName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_borrowed(), f)
+    }
+}
+
+impl FromStr for ProjectsBooksAttachmentResourceName {
+    type Err = ResourceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ProjectsBooksAttachmentResourceNameRef::parse_borrowed(s).map(ProjectsBooksAttachmentResourceNameRef::to_owned)
+    }
+}
+
+impl ResourceName for ProjectsBooksAttachmentResourceName {
+    const TYPE: &'static str = "library.googleapis.com/Attachment";
+    const PATTERNS: &'static [&'static str] = &["projects/{project}/books/{book}/attachments/{path=**}"];
+    const SINGULAR: &'static str = "attachment";
+    const PLURAL: &'static str = "attachments";
+
+    fn validate(&self) -> Result<(), ResourceNameError> {
+        self.validate()
+    }
+
+    fn contains_wildcard(&self) -> bool {
+        self.contains_wildcard()
+    }
+}
+
+impl Serialize for ProjectsBooksAttachmentResourceName {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_variables(serializer, [("project", &self.project), ("book", &self.book), ("path", &self.path)])
+    }
+}
+
+impl<'de> Deserialize<'de> for ProjectsBooksAttachmentResourceName {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let [project, book, path] = deserialize_variables(deserializer, ["project", "book", "path"])?;
+        validated(Self { project, book, path })
+    }
+}
+
+/// Borrowed view of a ProjectsBooksAttachmentResourceName that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ProjectsBooksAttachmentResourceNameRef<'a> {
+    pub project: &'a str,
+    pub book: &'a str,
+    pub path: &'a str,
+}
+
+impl<'a> ProjectsBooksAttachmentResourceNameRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut scanner = Scanner::new(s, 6);
+        scanner.literal("projects")?;
+        let project = scanner.variable()?;
+        scanner.literal("books")?;
+        let book = scanner.variable()?;
+        scanner.literal("attachments")?;
+        let path = scanner.capture("**")?;
+        scanner.finish()?;
+        Self::from_segments(project, book, path)
+    }
+
+    /// Creates a ProjectsBooksAttachmentResourceNameRef from parsed segments and validates it.
+    fn from_segments(project: &'a str, book: &'a str, path: &'a str) -> Result<Self, ResourceNameError> {
+        let name = Self { project, book, path };
+        name.validate()?;
+        Ok(name)
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        validate_segment("project", self.project)?;
+        validate_segment("book", self.book)?;
+        for segment in aip_resourcename::capture_segments("path", self.path, "**")? {
+            validate_segment("path", segment)?;
+        }
+        Ok(())
+    }
+
+    /// Converts the borrowed view into an owned ProjectsBooksAttachmentResourceName.
+    pub fn to_owned(self) -> ProjectsBooksAttachmentResourceName {
+        ProjectsBooksAttachmentResourceName {
+            project: self.project.to_string(),
+            book: self.book.to_string(),
+            path: self.path.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ProjectsBooksAttachmentResourceNameRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "projects/{project}/books/{book}/attachments/{path}"
+            , project = self.project
+            , book = self.book
+            , path = self.path
+        )
+    }
+}
+
+/// Resource name for library.googleapis.com/Attachment
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UsersShelvesAttachmentResourceName {
+    pub parent: String,
+    pub path: String,
+}
+
+impl UsersShelvesAttachmentResourceName {
+    /// Creates a new UsersShelvesAttachmentResourceName.
+    pub fn new(
+        parent: impl Into<String>,
+        path: impl Into<String>
+    ) -> Self {
+        Self {
+            parent: parent.into(),
+            path: path.into(),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        self.as_borrowed().validate()
+    }
+
+    /// Borrows the resource name as a UsersShelvesAttachmentResourceNameRef.
+    pub fn as_borrowed(&self) -> UsersShelvesAttachmentResourceNameRef<'_> {
+        UsersShelvesAttachmentResourceNameRef {
+            parent: &self.parent,
+            path: &self.path,
+        }
+    }
+
+    /// Returns the resource type.
+    pub fn resource_type(&self) -> &'static str {
+        "library.googleapis.com/Attachment"
+    }
+
+    /// Returns the name of the service the resource belongs to.
+    pub fn service_name(&self) -> &'static str {
+        "library.googleapis.com"
+    }
+
+    /// Returns the full resource name, prefixed with the service name.
+    pub fn to_full_name(&self) -> String {
+        format!("//{}/{}", self.service_name(), self)
+    }
+
+    /// Parses a full resource name, which must belong to the service of the resource.
+    pub fn from_full_name(full_name: &str) -> Result<Self, ResourceNameError> {
+        strip_service(full_name, "library.googleapis.com")?.parse()
+    }
+
+    /// Returns the API version of the resource, from its proto package.
+    pub fn api_version(&self) -> &'static str {
+        "v1"
+    }
+
+    /// Returns the resource URI in the given API version, or else in the one of the resource.
+    pub fn to_uri(&self, version: Option<&str>) -> String {
+        let version = version.unwrap_or(self.api_version());
+        format!("https://{}/{}/{}", self.service_name(), version, self)
+    }
+
+    /// Parses a resource URI of the service of the resource, and returns the name with the
+    /// API version of the URI.
+    pub fn from_uri(uri: &str) -> Result<(Self, &str), ResourceNameError> {
+        let (version, name) = aip_resourcename::split_uri(uri, "library.googleapis.com")?;
+        Ok((name.parse()?, version))
+    }
+
+    /// Returns true if any field contains a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        self.parent.split('/').any(|segment| segment == "-") || self.path.split('/').any(|segment| segment == "-")
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        (aip_resourcename::capture_matches("users/*/shelves/*", &self.parent, &concrete.parent))
+            && (aip_resourcename::capture_matches("**", &self.path, &concrete.path))
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(mut self, variables: &[&str]) -> Self {
+        if variables.contains(&"parent") {
+            self.parent = aip_resourcename::capture_wildcard("users/*/shelves/*");
+        }
+        if variables.contains(&"path") {
+            self.path = aip_resourcename::capture_wildcard("**");
+        }
+        self
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        self.validate()?;
+        for segment in self.parent.split('/') {
+            reject_wildcard("parent", segment)?;
+        }
+        for segment in self.path.split('/') {
+            reject_wildcard("path", segment)?;
+        }
+        Ok(())
+    }
+
+    /// Returns true if `other` is the name of a resource nested under this one.
+    pub fn is_ancestor_of(&self, other: &str) -> bool {
+        ResourceName::is_ancestor_of(self, other)
+    }
+
+    /// Returns true if the name matches a glob such as `projects/acme/books/*`, where `*`
+    /// matches one segment and a final `**` any suffix. Globs that cannot match names of
+    /// this type match nothing.
+    pub fn matches_glob(&self, glob: &str) -> bool {
+        ResourceName::matches_glob(self, glob)
+    }
+
+}
+
+impl fmt::Display for UsersShelvesAttachmentResourceName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_borrowed(), f)
+    }
+}
+
+impl FromStr for UsersShelvesAttachmentResourceName {
+    type Err = ResourceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        UsersShelvesAttachmentResourceNameRef::parse_borrowed(s).map(UsersShelvesAttachmentResourceNameRef::to_owned)
+    }
+}
+
+impl ResourceName for UsersShelvesAttachmentResourceName {
+    const TYPE: &'static str = "library.googleapis.com/Attachment";
+    const PATTERNS: &'static [&'static str] = &["{parent=users/*/shelves/*}/attachments/{path=**}"];
+    const SINGULAR: &'static str = "attachment";
+    const PLURAL: &'static str = "attachments";
+
+    fn validate(&self) -> Result<(), ResourceNameError> {
+        self.validate()
+    }
+
+    fn contains_wildcard(&self) -> bool {
+        self.contains_wildcard()
+    }
+}
+
+impl Serialize for UsersShelvesAttachmentResourceName {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_variables(serializer, [("parent", &self.parent), ("path", &self.path)])
+    }
+}
+
+impl<'de> Deserialize<'de> for UsersShelvesAttachmentResourceName {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let [parent, path] = deserialize_variables(deserializer, ["parent", "path"])?;
+        validated(Self { parent, path })
+    }
+}
+
+/// Borrowed view of a UsersShelvesAttachmentResourceName that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UsersShelvesAttachmentResourceNameRef<'a> {
+    pub parent: &'a str,
+    pub path: &'a str,
+}
+
+impl<'a> UsersShelvesAttachmentResourceNameRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut scanner = Scanner::new(s, 6);
+        let parent = scanner.capture("users/*/shelves/*")?;
+        scanner.literal("attachments")?;
+        let path = scanner.capture("**")?;
+        scanner.finish()?;
+        Self::from_segments(parent, path)
+    }
+
+    /// Creates a UsersShelvesAttachmentResourceNameRef from parsed segments and validates it.
+    fn from_segments(parent: &'a str, path: &'a str) -> Result<Self, ResourceNameError> {
+        let name = Self { parent, path };
+        name.validate()?;
+        Ok(name)
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        for segment in aip_resourcename::capture_segments("parent", self.parent, "users/*/shelves/*")? {
+            validate_segment("parent", segment)?;
+        }
+        for segment in aip_resourcename::capture_segments("path", self.path, "**")? {
+            validate_segment("path", segment)?;
+        }
+        Ok(())
+    }
+
+    /// Converts the borrowed view into an owned UsersShelvesAttachmentResourceName.
+    pub fn to_owned(self) -> UsersShelvesAttachmentResourceName {
+        UsersShelvesAttachmentResourceName {
+            parent: self.parent.to_string(),
+            path: self.path.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for UsersShelvesAttachmentResourceNameRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{parent}/attachments/{path}"
+            , parent = self.parent
+            , path = self.path
+        )
+    }
+}
+
+/// Parent of a AttachmentResourceName.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AttachmentParent {
+    ProjectsBooks(ProjectsBooksAttachmentParent),
+    UsersShelves(UsersShelvesAttachmentParent),
+}
+
+impl AttachmentParent {
+    /// Returns true if any field contains a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        match self {
+            AttachmentParent::ProjectsBooks(inner) => inner.contains_wildcard(),
+            AttachmentParent::UsersShelves(inner) => inner.contains_wildcard(),
+        }
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    /// Names of different patterns are matched segment by segment.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        match (self, concrete) {
+            (AttachmentParent::ProjectsBooks(inner), AttachmentParent::ProjectsBooks(concrete)) => inner.matches(concrete),
+            (AttachmentParent::UsersShelves(inner), AttachmentParent::UsersShelves(concrete)) => inner.matches(concrete),
+            _ => aip_resourcename::wildcard_matches(&self.to_string(), &concrete.to_string()),
+        }
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(self, variables: &[&str]) -> Self {
+        match self {
+            AttachmentParent::ProjectsBooks(inner) => AttachmentParent::ProjectsBooks(inner.with_wildcards_in(variables)),
+            AttachmentParent::UsersShelves(inner) => AttachmentParent::UsersShelves(inner.with_wildcards_in(variables)),
+        }
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        match self {
+            AttachmentParent::ProjectsBooks(inner) => inner.validate_concrete(),
+            AttachmentParent::UsersShelves(inner) => inner.validate_concrete(),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        match self {
+            AttachmentParent::ProjectsBooks(inner) => inner.validate(),
+            AttachmentParent::UsersShelves(inner) => inner.validate(),
+        }
+    }
+
+    /// Borrows the resource name as a AttachmentParentRef.
+    pub fn as_borrowed(&self) -> AttachmentParentRef<'_> {
+        match self {
+            AttachmentParent::ProjectsBooks(inner) => AttachmentParentRef::ProjectsBooks(inner.as_borrowed()),
+            AttachmentParent::UsersShelves(inner) => AttachmentParentRef::UsersShelves(inner.as_borrowed()),
+        }
+    }
+}
+
+impl fmt::Display for AttachmentParent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AttachmentParent::ProjectsBooks(inner) => write!(f, "{inner}"),
+            AttachmentParent::UsersShelves(inner) => write!(f, "{inner}"),
+        }
+    }
+}
+
+impl FromStr for AttachmentParent {
+    type Err = ResourceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        AttachmentParentRef::parse_borrowed(s).map(AttachmentParentRef::to_owned)
+    }
+}
+
+/// Borrowed view of a AttachmentParent that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AttachmentParentRef<'a> {
+    ProjectsBooks(ProjectsBooksAttachmentParentRef<'a>),
+    UsersShelves(UsersShelvesAttachmentParentRef<'a>),
+}
+
+impl<'a> AttachmentParentRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut parts = s.split('/');
+        match parts.next() {
+            Some("projects") => {
+                match parts.next() {
+                    Some(part1) => {
+                        match parts.next() {
+                            Some("books") => {
+                                match parts.next() {
+                                    Some(part3) => {
+                                        match parts.next() {
+                                            Some(part) => Err(ResourceNameError::no_matching_pattern("projects/{project}/books/{book}", 4, Some(part))),
+                                            None => ProjectsBooksAttachmentParentRef::from_segments(part1, part3).map(Self::ProjectsBooks),
+                                        }
+                                    }
+                                    None => Err(ResourceNameError::no_matching_pattern("projects/{project}/books/{book}", 3, None)),
+                                }
+                            }
+                            Some(part) => Err(ResourceNameError::no_matching_pattern("projects/{project}/books/{book}", 2, Some(part))),
+                            None => Err(ResourceNameError::no_matching_pattern("projects/{project}/books/{book}", 2, None)),
+                        }
+                    }
+                    None => Err(ResourceNameError::no_matching_pattern("projects/{project}/books/{book}", 1, None)),
+                }
+            }
+            Some(part0 @ "users") => {
+                match parts.next() {
+                    Some(_) => {
+                        match parts.next() {
+                            Some("shelves") => {
+                                match parts.next() {
+                                    Some(part3) => {
+                                        match parts.next() {
+                                            Some(part) => Err(ResourceNameError::no_matching_pattern("{parent=users/*/shelves/*}", 4, Some(part))),
+                                            None => UsersShelvesAttachmentParentRef::from_segments(aip_resourcename::span(s, part0, part3)).map(Self::UsersShelves),
+                                        }
+                                    }
+                                    None => Err(ResourceNameError::no_matching_pattern("{parent=users/*/shelves/*}", 3, None)),
+                                }
+                            }
+                            Some(part) => Err(ResourceNameError::no_matching_pattern("{parent=users/*/shelves/*}", 2, Some(part))),
+                            None => Err(ResourceNameError::no_matching_pattern("{parent=users/*/shelves/*}", 2, None)),
+                        }
+                    }
+                    None => Err(ResourceNameError::no_matching_pattern("{parent=users/*/shelves/*}", 1, None)),
+                }
+            }
+            Some(part) => Err(ResourceNameError::no_matching_pattern("projects/{project}/books/{book}", 0, Some(part))),
+            None => Err(ResourceNameError::no_matching_pattern("projects/{project}/books/{book}", 0, None)),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        match self {
+            AttachmentParentRef::ProjectsBooks(inner) => inner.validate(),
+            AttachmentParentRef::UsersShelves(inner) => inner.validate(),
+        }
+    }
+
+    /// Converts the borrowed view into an owned AttachmentParent.
+    pub fn to_owned(self) -> AttachmentParent {
+        match self {
+            AttachmentParentRef::ProjectsBooks(inner) => AttachmentParent::ProjectsBooks(inner.to_owned()),
+            AttachmentParentRef::UsersShelves(inner) => AttachmentParent::UsersShelves(inner.to_owned()),
+        }
+    }
+}
+
+impl fmt::Display for AttachmentParentRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AttachmentParentRef::ProjectsBooks(inner) => write!(f, "{inner}"),
+            AttachmentParentRef::UsersShelves(inner) => write!(f, "{inner}"),
+        }
+    }
+}
+
+/// Parent of a AttachmentResourceName: `projects/{project}/books/{book}`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ProjectsBooksAttachmentParent {
+    pub project: String,
+    pub book: String,
+}
+
+impl ProjectsBooksAttachmentParent {
+    /// Creates a new ProjectsBooksAttachmentParent.
+    pub fn new(
+        project: impl Into<String>,
+        book: impl Into<String>
+    ) -> Self {
+        Self {
+            project: project.into(),
+            book: book.into(),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        self.as_borrowed().validate()
+    }
+
+    /// Borrows the resource name as a ProjectsBooksAttachmentParentRef.
+    pub fn as_borrowed(&self) -> ProjectsBooksAttachmentParentRef<'_> {
+        ProjectsBooksAttachmentParentRef {
+            project: &self.project,
+            book: &self.book,
+        }
+    }
+
+    /// Returns true if any field contains a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        self.project == "-" || self.book == "-"
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        (self.project == "-" || self.project == concrete.project)
+            && (self.book == "-" || self.book == concrete.book)
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(mut self, variables: &[&str]) -> Self {
+        if variables.contains(&"project") {
+            self.project = "-".to_string();
+        }
+        if variables.contains(&"book") {
+            self.book = "-".to_string();
+        }
+        self
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        self.validate()?;
+        reject_wildcard("project", &self.project)?;
+        reject_wildcard("book", &self.book)?;
+        Ok(())
+    }
+
+}
+
+impl fmt::Display for ProjectsBooksAttachmentParent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_borrowed(), f)
+    }
+}
+
+impl FromStr for ProjectsBooksAttachmentParent {
+    type Err = ResourceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ProjectsBooksAttachmentParentRef::parse_borrowed(s).map(ProjectsBooksAttachmentParentRef::to_owned)
+    }
+}
+
+/// Borrowed view of a ProjectsBooksAttachmentParent that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ProjectsBooksAttachmentParentRef<'a> {
+    pub project: &'a str,
+    pub book: &'a str,
+}
+
+impl<'a> ProjectsBooksAttachmentParentRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut scanner = Scanner::new(s, 4);
+        scanner.literal("projects")?;
+        let project = scanner.variable()?;
+        scanner.literal("books")?;
+        let book = scanner.variable()?;
+        scanner.finish()?;
+        Self::from_segments(project, book)
+    }
+
+    /// Creates a ProjectsBooksAttachmentParentRef from parsed segments and validates it.
+    fn from_segments(project: &'a str, book: &'a str) -> Result<Self, ResourceNameError> {
+        let name = Self { project, book };
+        name.validate()?;
+        Ok(name)
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        validate_segment("project", self.project)?;
+        validate_segment("book", self.book)?;
+        Ok(())
+    }
+
+    /// Converts the borrowed view into an owned ProjectsBooksAttachmentParent.
+    pub fn to_owned(self) -> ProjectsBooksAttachmentParent {
+        ProjectsBooksAttachmentParent {
+            project: self.project.to_string(),
+            book: self.book.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ProjectsBooksAttachmentParentRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "projects/{project}/books/{book}"
+            , project = self.project
+            , book = self.book
+        )
+    }
+}
+
+/// Parent of a AttachmentResourceName: `{parent=users/*/shelves/*}`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UsersShelvesAttachmentParent {
+    pub parent: String,
+}
+
+impl UsersShelvesAttachmentParent {
+    /// Creates a new UsersShelvesAttachmentParent.
+    pub fn new(
+        parent: impl Into<String>
+    ) -> Self {
+        Self {
+            parent: parent.into(),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        self.as_borrowed().validate()
+    }
+
+    /// Borrows the resource name as a UsersShelvesAttachmentParentRef.
+    pub fn as_borrowed(&self) -> UsersShelvesAttachmentParentRef<'_> {
+        UsersShelvesAttachmentParentRef {
+            parent: &self.parent,
+        }
+    }
+
+    /// Returns true if any field contains a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        self.parent.split('/').any(|segment| segment == "-")
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        aip_resourcename::capture_matches("users/*/shelves/*", &self.parent, &concrete.parent)
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(mut self, variables: &[&str]) -> Self {
+        if variables.contains(&"parent") {
+            self.parent = aip_resourcename::capture_wildcard("users/*/shelves/*");
+        }
+        self
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        self.validate()?;
+        for segment in self.parent.split('/') {
+            reject_wildcard("parent", segment)?;
+        }
+        Ok(())
+    }
+
+}
+
+impl fmt::Display for UsersShelvesAttachmentParent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_borrowed(), f)
+    }
+}
+
+impl FromStr for UsersShelvesAttachmentParent {
+    type Err = ResourceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        UsersShelvesAttachmentParentRef::parse_borrowed(s).map(UsersShelvesAttachmentParentRef::to_owned)
+    }
+}
+
+/// Borrowed view of a UsersShelvesAttachmentParent that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UsersShelvesAttachmentParentRef<'a> {
+    pub parent: &'a str,
+}
+
+impl<'a> UsersShelvesAttachmentParentRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut scanner = Scanner::new(s, 4);
+        let parent = scanner.capture("users/*/shelves/*")?;
+        scanner.finish()?;
+        Self::from_segments(parent)
+    }
+
+    /// Creates a UsersShelvesAttachmentParentRef from parsed segments and validates it.
+    fn from_segments(parent: &'a str) -> Result<Self, ResourceNameError> {
+        let name = Self { parent };
+        name.validate()?;
+        Ok(name)
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        for segment in aip_resourcename::capture_segments("parent", self.parent, "users/*/shelves/*")? {
+            validate_segment("parent", segment)?;
+        }
+        Ok(())
+    }
+
+    /// Converts the borrowed view into an owned UsersShelvesAttachmentParent.
+    pub fn to_owned(self) -> UsersShelvesAttachmentParent {
+        UsersShelvesAttachmentParent {
+            parent: self.parent.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for UsersShelvesAttachmentParentRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{parent}"
+            , parent = self.parent
+        )
+    }
+}
+
+impl ProjectsBooksAttachmentResourceName {
+    /// Returns the name of the parent of this resource.
+    pub fn parent(&self) -> ProjectsBooksAttachmentParent {
+        ProjectsBooksAttachmentParent {
+            project: self.project.clone(),
+            book: self.book.clone(),
+        }
+    }
+}
+
+impl UsersShelvesAttachmentResourceName {
+    /// Returns the name of the parent of this resource.
+    pub fn parent(&self) -> UsersShelvesAttachmentParent {
+        UsersShelvesAttachmentParent {
+            parent: self.parent.clone(),
+        }
+    }
+}
+
+impl AttachmentResourceName {
+    /// Returns the name of the parent of this resource.
+    pub fn parent(&self) -> AttachmentParent {
+        match self {
+            AttachmentResourceName::ProjectsBooks(inner) => AttachmentParent::ProjectsBooks(inner.parent()),
+            AttachmentResourceName::UsersShelves(inner) => AttachmentParent::UsersShelves(inner.parent()),
+        }
+    }
+}
+
+impl ProjectsBooksAttachmentParent {
+    /// Creates the name of a AttachmentResourceName nested under this one.
+    pub fn attachment(&self, path: impl Into<String>) -> AttachmentResourceName {
+        AttachmentResourceName::ProjectsBooks(ProjectsBooksAttachmentResourceName { project: self.project.clone(), book: self.book.clone(), path: path.into() })
+    }
+}
+
+impl UsersShelvesAttachmentParent {
+    /// Creates the name of a AttachmentResourceName nested under this one.
+    pub fn attachment(&self, path: impl Into<String>) -> AttachmentResourceName {
+        AttachmentResourceName::UsersShelves(UsersShelvesAttachmentResourceName { parent: self.parent.clone(), path: path.into() })
+    }
+}
+
+impl AttachmentParent {
+    /// Creates the name of a AttachmentResourceName nested under this one.
+    pub fn attachment(&self, path: impl Into<String>) -> AttachmentResourceName {
+        match self {
+            AttachmentParent::ProjectsBooks(inner) => inner.attachment(path),
+            AttachmentParent::UsersShelves(inner) => inner.attachment(path),
+        }
+    }
+}
+
+impl ProjectsBooksAttachmentResourceName {
+    /// Returns the name of the BookResourceName this resource is nested under.
+    pub fn book(&self) -> BookResourceName {
+        BookResourceName {
+            project: self.project.clone(),
+            book: self.book.clone(),
+        }
+    }
+}
+
+impl AttachmentResourceName {
+    /// Returns the name of the BookResourceName this resource is nested under, if any.
+    pub fn book(&self) -> Option<BookResourceName> {
+        match self {
+            AttachmentResourceName::ProjectsBooks(inner) => Some(inner.book()),
+            AttachmentResourceName::UsersShelves(_) => None,
+        }
+    }
+}
+
+impl BookResourceName {
+    /// Creates the name of a AttachmentResourceName nested under this one.
+    pub fn attachment(&self, path: impl Into<String>) -> AttachmentResourceName {
+        AttachmentResourceName::ProjectsBooks(ProjectsBooksAttachmentResourceName { project: self.project.clone(), book: self.book.clone(), path: path.into() })
+    }
+}
+
+/// Multi-pattern resource name for library.googleapis.com/Loan
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum LoanResourceName {
+    Projects(ProjectsLoanResourceName),
+    ProjectsArchivedLoans(ProjectsArchivedLoansLoanResourceName),
+}
+
+impl LoanResourceName {
+    /// Returns the resource type.
+    pub fn resource_type(&self) -> &'static str {
+        "library.googleapis.com/Loan"
+    }
+
+    /// Returns true if any field contains a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        match self {
+            LoanResourceName::Projects(inner) => inner.contains_wildcard(),
+            LoanResourceName::ProjectsArchivedLoans(inner) => inner.contains_wildcard(),
+        }
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    /// Names of different patterns are matched segment by segment.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        match (self, concrete) {
+            (LoanResourceName::Projects(inner), LoanResourceName::Projects(concrete)) => inner.matches(concrete),
+            (LoanResourceName::ProjectsArchivedLoans(inner), LoanResourceName::ProjectsArchivedLoans(concrete)) => inner.matches(concrete),
+            _ => aip_resourcename::wildcard_matches(&self.to_string(), &concrete.to_string()),
+        }
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(self, variables: &[&str]) -> Self {
+        match self {
+            LoanResourceName::Projects(inner) => LoanResourceName::Projects(inner.with_wildcards_in(variables)),
+            LoanResourceName::ProjectsArchivedLoans(inner) => LoanResourceName::ProjectsArchivedLoans(inner.with_wildcards_in(variables)),
+        }
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        match self {
+            LoanResourceName::Projects(inner) => inner.validate_concrete(),
+            LoanResourceName::ProjectsArchivedLoans(inner) => inner.validate_concrete(),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        match self {
+            LoanResourceName::Projects(inner) => inner.validate(),
+            LoanResourceName::ProjectsArchivedLoans(inner) => inner.validate(),
+        }
+    }
+
+    /// Borrows the resource name as a LoanResourceNameRef.
+    pub fn as_borrowed(&self) -> LoanResourceNameRef<'_> {
+        match self {
+            LoanResourceName::Projects(inner) => LoanResourceNameRef::Projects(inner.as_borrowed()),
+            LoanResourceName::ProjectsArchivedLoans(inner) => LoanResourceNameRef::ProjectsArchivedLoans(inner.as_borrowed()),
+        }
+    }
+
+    /// Returns true if `other` is the name of a resource nested under this one.
+    pub fn is_ancestor_of(&self, other: &str) -> bool {
+        ResourceName::is_ancestor_of(self, other)
+    }
+
+    /// Returns true if the name matches a glob such as `projects/acme/books/*`, where `*`
+    /// matches one segment and a final `**` any suffix. Globs that cannot match names of
+    /// this type match nothing.
+    pub fn matches_glob(&self, glob: &str) -> bool {
+        ResourceName::matches_glob(self, glob)
+    }
+
+    /// Returns the name of the service the resource belongs to.
+    pub fn service_name(&self) -> &'static str {
+        match self {
+            LoanResourceName::Projects(inner) => inner.service_name(),
+            LoanResourceName::ProjectsArchivedLoans(inner) => inner.service_name(),
+        }
+    }
+
+    /// Returns the full resource name, prefixed with the service name.
+    pub fn to_full_name(&self) -> String {
+        match self {
+            LoanResourceName::Projects(inner) => inner.to_full_name(),
+            LoanResourceName::ProjectsArchivedLoans(inner) => inner.to_full_name(),
+        }
+    }
+
+    /// Parses a full resource name, which must belong to the service of the resource.
+    pub fn from_full_name(full_name: &str) -> Result<Self, ResourceNameError> {
+        strip_service(full_name, "library.googleapis.com")?.parse()
+    }
+
+    /// Returns the API version of the resource, from its proto package.
+    pub fn api_version(&self) -> &'static str {
+        match self {
+            LoanResourceName::Projects(inner) => inner.api_version(),
+            LoanResourceName::ProjectsArchivedLoans(inner) => inner.api_version(),
+        }
+    }
+
+    /// Returns the resource URI in the given API version, or else in the one of the resource.
+    pub fn to_uri(&self, version: Option<&str>) -> String {
+        match self {
+            LoanResourceName::Projects(inner) => inner.to_uri(version),
+            LoanResourceName::ProjectsArchivedLoans(inner) => inner.to_uri(version),
+        }
+    }
+
+    /// Parses a resource URI of the service of the resource, and returns the name with the
+    /// API version of the URI.
+    pub fn from_uri(uri: &str) -> Result<(Self, &str), ResourceNameError> {
+        let (version, name) = aip_resourcename::split_uri(uri, "library.googleapis.com")?;
+        Ok((name.parse()?, version))
+    }
+
+}
+
+impl fmt::Display for LoanResourceName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoanResourceName::Projects(inner) => write!(f, "{inner}"),
+            LoanResourceName::ProjectsArchivedLoans(inner) => write!(f, "{inner}"),
+        }
+    }
+}
+
+impl FromStr for LoanResourceName {
+    type Err = ResourceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        LoanResourceNameRef::parse_borrowed(s).map(LoanResourceNameRef::to_owned)
+    }
+}
+
+impl ResourceName for LoanResourceName {
+    const TYPE: &'static str = "library.googleapis.com/Loan";
+    const PATTERNS: &'static [&'static str] = &["projects/{project}/loans/{loan}", "projects/{project}/archivedLoans/{loan}"];
+    const SINGULAR: &'static str = "loan";
+    const PLURAL: &'static str = "loans";
+
+    fn validate(&self) -> Result<(), ResourceNameError> {
+        self.validate()
+    }
+
+    fn contains_wildcard(&self) -> bool {
+        self.contains_wildcard()
+    }
+}
+
+impl Serialize for LoanResourceName {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        aip_resourcename::serde::serialize_str(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for LoanResourceName {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        aip_resourcename::serde::deserialize_str(deserializer)
+    }
+}
+
+/// Borrowed view of a LoanResourceName that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LoanResourceNameRef<'a> {
+    Projects(ProjectsLoanResourceNameRef<'a>),
+    ProjectsArchivedLoans(ProjectsArchivedLoansLoanResourceNameRef<'a>),
+}
+
+impl<'a> LoanResourceNameRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut parts = s.split('/');
+        match parts.next() {
+            Some("projects") => {
+                match parts.next() {
+                    Some(part1) => {
+                        match parts.next() {
+                            Some("loans") => {
+                                match parts.next() {
+                                    Some(part3) => {
+                                        match parts.next() {
+                                            Some(part) => Err(ResourceNameError::no_matching_pattern("projects/{project}/loans/{loan}", 4, Some(part))),
+                                            None => ProjectsLoanResourceNameRef::from_segments(part1, part3).map(Self::Projects),
+                                        }
+                                    }
+                                    None => Err(ResourceNameError::no_matching_pattern("projects/{project}/loans/{loan}", 3, None)),
+                                }
+                            }
+                            Some("archivedLoans") => {
+                                match parts.next() {
+                                    Some(part3) => {
+                                        match parts.next() {
+                                            Some(part) => Err(ResourceNameError::no_matching_pattern("projects/{project}/archivedLoans/{loan}", 4, Some(part))),
+                                            None => ProjectsArchivedLoansLoanResourceNameRef::from_segments(part1, part3).map(Self::ProjectsArchivedLoans),
+                                        }
+                                    }
+                                    None => Err(ResourceNameError::no_matching_pattern("projects/{project}/archivedLoans/{loan}", 3, None)),
+                                }
+                            }
+                            Some(part) => Err(ResourceNameError::no_matching_pattern("projects/{project}/loans/{loan}", 2, Some(part))),
+                            None => Err(ResourceNameError::no_matching_pattern("projects/{project}/loans/{loan}", 2, None)),
+                        }
+                    }
+                    None => Err(ResourceNameError::no_matching_pattern("projects/{project}/loans/{loan}", 1, None)),
+                }
+            }
+            Some(part) => Err(ResourceNameError::no_matching_pattern("projects/{project}/loans/{loan}", 0, Some(part))),
+            None => Err(ResourceNameError::no_matching_pattern("projects/{project}/loans/{loan}", 0, None)),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        match self {
+            LoanResourceNameRef::Projects(inner) => inner.validate(),
+            LoanResourceNameRef::ProjectsArchivedLoans(inner) => inner.validate(),
+        }
+    }
+
+    /// Converts the borrowed view into an owned LoanResourceName.
+    pub fn to_owned(self) -> LoanResourceName {
+        match self {
+            LoanResourceNameRef::Projects(inner) => LoanResourceName::Projects(inner.to_owned()),
+            LoanResourceNameRef::ProjectsArchivedLoans(inner) => LoanResourceName::ProjectsArchivedLoans(inner.to_owned()),
+        }
+    }
+}
+
+impl fmt::Display for LoanResourceNameRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoanResourceNameRef::Projects(inner) => write!(f, "{inner}"),
+            LoanResourceNameRef::ProjectsArchivedLoans(inner) => write!(f, "{inner}"),
+        }
+    }
+}
+
+/// Parses a resource name string and returns the appropriate type.
+pub fn parse_loan_resource_name(name: &str) -> Result<LoanResourceName, ResourceNameError> {
+    LoanResourceName::from_str(name)
+}
+
+/// Resource name for library.googleapis.com/Loan
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ProjectsLoanResourceName {
+    pub project: String,
+    pub loan: String,
+}
+
+impl ProjectsLoanResourceName {
+    /// Creates a new ProjectsLoanResourceName.
+    pub fn new(
+        project: impl Into<String>,
+        loan: impl Into<String>
+    ) -> Self {
+        Self {
+            project: project.into(),
+            loan: loan.into(),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        self.as_borrowed().validate()
+    }
+
+    /// Borrows the resource name as a ProjectsLoanResourceNameRef.
+    pub fn as_borrowed(&self) -> ProjectsLoanResourceNameRef<'_> {
+        ProjectsLoanResourceNameRef {
+            project: &self.project,
+            loan: &self.loan,
+        }
+    }
+
+    /// Returns the resource type.
+    pub fn resource_type(&self) -> &'static str {
+        "library.googleapis.com/Loan"
+    }
+
+    /// Returns the name of the service the resource belongs to.
+    pub fn service_name(&self) -> &'static str {
+        "library.googleapis.com"
+    }
+
+    /// Returns the full resource name, prefixed with the service name.
+    pub fn to_full_name(&self) -> String {
+        format!("//{}/{}", self.service_name(), self)
+    }
+
+    /// Parses a full resource name, which must belong to the service of the resource.
+    pub fn from_full_name(full_name: &str) -> Result<Self, ResourceNameError> {
+        strip_service(full_name, "library.googleapis.com")?.parse()
+    }
+
+    /// Returns the API version of the resource, from its proto package.
+    pub fn api_version(&self) -> &'static str {
+        "v1"
+    }
+
+    /// Returns the resource URI in the given API version, or else in the one of the resource.
+    pub fn to_uri(&self, version: Option<&str>) -> String {
+        let version = version.unwrap_or(self.api_version());
+        format!("https://{}/{}/{}", self.service_name(), version, self)
+    }
+
+    /// Parses a resource URI of the service of the resource, and returns the name with the
+    /// API version of the URI.
+    pub fn from_uri(uri: &str) -> Result<(Self, &str), ResourceNameError> {
+        let (version, name) = aip_resourcename::split_uri(uri, "library.googleapis.com")?;
+        Ok((name.parse()?, version))
+    }
+
+    /// Returns true if any field contains a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        self.project == "-" || self.loan == "-"
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        (self.project == "-" || self.project == concrete.project)
+            && (self.loan == "-" || self.loan == concrete.loan)
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(mut self, variables: &[&str]) -> Self {
+        if variables.contains(&"project") {
+            self.project = "-".to_string();
+        }
+        if variables.contains(&"loan") {
+            self.loan = "-".to_string();
+        }
+        self
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        self.validate()?;
+        reject_wildcard("project", &self.project)?;
+        reject_wildcard("loan", &self.loan)?;
+        Ok(())
+    }
+
+    /// Returns true if `other` is the name of a resource nested under this one.
+    pub fn is_ancestor_of(&self, other: &str) -> bool {
+        ResourceName::is_ancestor_of(self, other)
+    }
+
+    /// Returns true if the name matches a glob such as `projects/acme/books/*`, where `*`
+    /// matches one segment and a final `**` any suffix. Globs that cannot match names of
+    /// this type match nothing.
+    pub fn matches_glob(&self, glob: &str) -> bool {
+        ResourceName::matches_glob(self, glob)
+    }
+
+}
+
+impl fmt::Display for ProjectsLoanResourceName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_borrowed(), f)
+    }
+}
+
+impl FromStr for ProjectsLoanResourceName {
+    type Err = ResourceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ProjectsLoanResourceNameRef::parse_borrowed(s).map(ProjectsLoanResourceNameRef::to_owned)
+    }
+}
+
+impl ResourceName for ProjectsLoanResourceName {
+    const TYPE: &'static str = "library.googleapis.com/Loan";
+    const PATTERNS: &'static [&'static str] = &["projects/{project}/loans/{loan}"];
+    const SINGULAR: &'static str = "loan";
+    const PLURAL: &'static str = "loans";
+
+    fn validate(&self) -> Result<(), ResourceNameError> {
+        self.validate()
+    }
+
+    fn contains_wildcard(&self) -> bool {
+        self.contains_wildcard()
+    }
+}
+
+impl Serialize for ProjectsLoanResourceName {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_variables(serializer, [("project", &self.project), ("loan", &self.loan)])
+    }
+}
+
+impl<'de> Deserialize<'de> for ProjectsLoanResourceName {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let [project, loan] = deserialize_variables(deserializer, ["project", "loan"])?;
+        validated(Self { project, loan })
+    }
+}
+
+/// Borrowed view of a ProjectsLoanResourceName that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ProjectsLoanResourceNameRef<'a> {
+    pub project: &'a str,
+    pub loan: &'a str,
+}
+
+impl<'a> ProjectsLoanResourceNameRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut scanner = Scanner::new(s, 4);
+        scanner.literal("projects")?;
+        let project = scanner.variable()?;
+        scanner.literal("loans")?;
+        let loan = scanner.variable()?;
+        scanner.finish()?;
+        Self::from_segments(project, loan)
+    }
+
+    /// Creates a ProjectsLoanResourceNameRef from parsed segments and validates it.
+    fn from_segments(project: &'a str, loan: &'a str) -> Result<Self, ResourceNameError> {
+        let name = Self { project, loan };
+        name.validate()?;
+        Ok(name)
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        validate_segment("project", self.project)?;
+        validate_segment("loan", self.loan)?;
+        Ok(())
+    }
+
+    /// Converts the borrowed view into an owned ProjectsLoanResourceName.
+    pub fn to_owned(self) -> ProjectsLoanResourceName {
+        ProjectsLoanResourceName {
+            project: self.project.to_string(),
+            loan: self.loan.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ProjectsLoanResourceNameRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "projects/{project}/loans/{loan}"
+            , project = self.project
+            , loan = self.loan
+        )
+    }
+}
+
+/// Resource name for library.googleapis.com/Loan
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ProjectsArchivedLoansLoanResourceName {
+    pub project: String,
+    pub loan: String,
+}
+
+impl ProjectsArchivedLoansLoanResourceName {
+    /// Creates a new ProjectsArchivedLoansLoanResourceName.
+    pub fn new(
+        project: impl Into<String>,
+        loan: impl Into<String>
+    ) -> Self {
+        Self {
+            project: project.into(),
+            loan: loan.into(),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        self.as_borrowed().validate()
+    }
+
+    /// Borrows the resource name as a ProjectsArchivedLoansLoanResourceNameRef.
+    pub fn as_borrowed(&self) -> ProjectsArchivedLoansLoanResourceNameRef<'_> {
+        ProjectsArchivedLoansLoanResourceNameRef {
+            project: &self.project,
+            loan: &self.loan,
+        }
+    }
+
+    /// Returns the resource type.
+    pub fn resource_type(&self) -> &'static str {
+        "library.googleapis.com/Loan"
+    }
+
+    /// Returns the name of the service the resource belongs to.
+    pub fn service_name(&self) -> &'static str {
+        "library.googleapis.com"
+    }
+
+    /// Returns the full resource name, prefixed with the service name.
+    pub fn to_full_name(&self) -> String {
+        format!("//{}/{}", self.service_name(), self)
+    }
+
+    /// Parses a full resource name, which must belong to the service of the resource.
+    pub fn from_full_name(full_name: &str) -> Result<Self, ResourceNameError> {
+        strip_service(full_name, "library.googleapis.com")?.parse()
+    }
+
+    /// Returns the API version of the resource, from its proto package.
+    pub fn api_version(&self) -> &'static str {
+        "v1"
+    }
+
+    /// Returns the resource URI in the given API version, or else in the one of the resource.
+    pub fn to_uri(&self, version: Option<&str>) -> String {
+        let version = version.unwrap_or(self.api_version());
+        format!("https://{}/{}/{}", self.service_name(), version, self)
+    }
+
+    /// Parses a resource URI of the service of the resource, and returns the name with the
+    /// API version of the URI.
+    pub fn from_uri(uri: &str) -> Result<(Self, &str), ResourceNameError> {
+        let (version, name) = aip_resourcename::split_uri(uri, "library.googleapis.com")?;
+        Ok((name.parse()?, version))
+    }
+
+    /// Returns true if any field contains a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        self.project == "-" || self.loan == "-"
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        (self.project == "-" || self.project == concrete.project)
+            && (self.loan == "-" || self.loan == concrete.loan)
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(mut self, variables: &[&str]) -> Self {
+        if variables.contains(&"project") {
+            self.project = "-".to_string();
+        }
+        if variables.contains(&"loan") {
+            self.loan = "-".to_string();
+        }
+        self
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        self.validate()?;
+        reject_wildcard("project", &self.project)?;
+        reject_wildcard("loan", &self.loan)?;
+        Ok(())
+    }
+
+    /// Returns true if `other` is the name of a resource nested under this one.
+    pub fn is_ancestor_of(&self, other: &str) -> bool {
+        ResourceName::is_ancestor_of(self, other)
+    }
+
+    /// Returns true if the name matches a glob such as `projects/acme/books/*`, where `*`
+    /// matches one segment and a final `**` any suffix. Globs that cannot match names of
+    /// this type match nothing.
+    pub fn matches_glob(&self, glob: &str) -> bool {
+        ResourceName::matches_glob(self, glob)
+    }
+
+}
+
+impl fmt::Display for ProjectsArchivedLoansLoanResourceName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_borrowed(), f)
+    }
+}
+
+impl FromStr for ProjectsArchivedLoansLoanResourceName {
+    type Err = ResourceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ProjectsArchivedLoansLoanResourceNameRef::parse_borrowed(s).map(ProjectsArchivedLoansLoanResourceNameRef::to_owned)
+    }
+}
+
+impl ResourceName for ProjectsArchivedLoansLoanResourceName {
+    const TYPE: &'static str = "library.googleapis.com/Loan";
+    const PATTERNS: &'static [&'static str] = &["projects/{project}/archivedLoans/{loan}"];
+    const SINGULAR: &'static str = "loan";
+    const PLURAL: &'static str = "loans";
+
+    fn validate(&self) -> Result<(), ResourceNameError> {
+        self.validate()
+    }
+
+    fn contains_wildcard(&self) -> bool {
+        self.contains_wildcard()
+    }
+}
+
+impl Serialize for ProjectsArchivedLoansLoanResourceName {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_variables(serializer, [("project", &self.project), ("loan", &self.loan)])
+    }
+}
+
+impl<'de> Deserialize<'de> for ProjectsArchivedLoansLoanResourceName {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let [project, loan] = deserialize_variables(deserializer, ["project", "loan"])?;
+        validated(Self { project, loan })
+    }
+}
+
+/// Borrowed view of a ProjectsArchivedLoansLoanResourceName that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ProjectsArchivedLoansLoanResourceNameRef<'a> {
+    pub project: &'a str,
+    pub loan: &'a str,
+}
+
+impl<'a> ProjectsArchivedLoansLoanResourceNameRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut scanner = Scanner::new(s, 4);
+        scanner.literal("projects")?;
+        let project = scanner.variable()?;
+        scanner.literal("archivedLoans")?;
+        let loan = scanner.variable()?;
+        scanner.finish()?;
+        Self::from_segments(project, loan)
+    }
+
+    /// Creates a ProjectsArchivedLoansLoanResourceNameRef from parsed segments and validates it.
+    fn from_segments(project: &'a str, loan: &'a str) -> Result<Self, ResourceNameError> {
+        let name = Self { project, loan };
+        name.validate()?;
+        Ok(name)
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        validate_segment("project", self.project)?;
+        validate_segment("loan", self.loan)?;
+        Ok(())
+    }
+
+    /// Converts the borrowed view into an owned ProjectsArchivedLoansLoanResourceName.
+    pub fn to_owned(self) -> ProjectsArchivedLoansLoanResourceName {
+        ProjectsArchivedLoansLoanResourceName {
+            project: self.project.to_string(),
+            loan: self.loan.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ProjectsArchivedLoansLoanResourceNameRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "projects/{project}/archivedLoans/{loan}"
+            , project = self.project
+            , loan = self.loan
+        )
+    }
+}
+
+/// Parent of a LoanResourceName: `projects/{project}`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LoanParent {
+    pub project: String,
+}
+
+impl LoanParent {
+    /// Creates a new LoanParent.
+    pub fn new(
+        project: impl Into<String>
+    ) -> Self {
+        Self {
+            project: project.into(),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        self.as_borrowed().validate()
+    }
+
+    /// Borrows the resource name as a LoanParentRef.
+    pub fn as_borrowed(&self) -> LoanParentRef<'_> {
+        LoanParentRef {
+            project: &self.project,
+        }
+    }
+
+    /// Returns true if any field contains a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        self.project == "-"
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        self.project == "-" || self.project == concrete.project
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(mut self, variables: &[&str]) -> Self {
+        if variables.contains(&"project") {
+            self.project = "-".to_string();
+        }
+        self
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        self.validate()?;
+        reject_wildcard("project", &self.project)?;
+        Ok(())
+    }
+
+}
+
+impl fmt::Display for LoanParent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_borrowed(), f)
+    }
+}
+
+impl FromStr for LoanParent {
+    type Err = ResourceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        LoanParentRef::parse_borrowed(s).map(LoanParentRef::to_owned)
+    }
+}
+
+/// Borrowed view of a LoanParent that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LoanParentRef<'a> {
+    pub project: &'a str,
+}
+
+impl<'a> LoanParentRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut scanner = Scanner::new(s, 2);
+        scanner.literal("projects")?;
+        let project = scanner.variable()?;
+        scanner.finish()?;
+        Self::from_segments(project)
+    }
+
+    /// Creates a LoanParentRef from parsed segments and validates it.
+    fn from_segments(project: &'a str) -> Result<Self, ResourceNameError> {
+        let name = Self { project };
+        name.validate()?;
+        Ok(name)
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        validate_segment("project", self.project)?;
+        Ok(())
+    }
+
+    /// Converts the borrowed view into an owned LoanParent.
+    pub fn to_owned(self) -> LoanParent {
+        LoanParent {
+            project: self.project.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for LoanParentRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "projects/{project}"
+            , project = self.project
+        )
+    }
+}
+
+impl ProjectsLoanResourceName {
+    /// Returns the name of the parent of this resource.
+    pub fn parent(&self) -> LoanParent {
+        LoanParent {
+            project: self.project.clone(),
+        }
+    }
+}
+
+impl ProjectsArchivedLoansLoanResourceName {
+    /// Returns the name of the parent of this resource.
+    pub fn parent(&self) -> LoanParent {
+        LoanParent {
+            project: self.project.clone(),
+        }
+    }
+}
+
+impl LoanResourceName {
+    /// Returns the name of the parent of this resource.
+    pub fn parent(&self) -> LoanParent {
+        match self {
+            LoanResourceName::Projects(inner) => inner.parent(),
+            LoanResourceName::ProjectsArchivedLoans(inner) => inner.parent(),
+        }
+    }
+}
+
+impl LoanParent {
+    /// Creates the name of a LoanResourceName nested under this one.
+    pub fn loan(&self, loan: impl Into<String>) -> LoanResourceName {
+        LoanResourceName::Projects(ProjectsLoanResourceName { project: self.project.clone(), loan: loan.into() })
+    }
+}
+
+/// Resource name for library.googleapis.com/Book
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BookResourceName {
+    pub project: String,
+    pub book: String,
+}
+
+impl BookResourceName {
+    /// Creates a new BookResourceName.
+    pub fn new(
+        project: impl Into<String>,
+        book: impl Into<String>
+    ) -> Self {
+        Self {
+            project: project.into(),
+            book: book.into(),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        self.as_borrowed().validate()
+    }
+
+    /// Borrows the resource name as a BookResourceNameRef.
+    pub fn as_borrowed(&self) -> BookResourceNameRef<'_> {
+        BookResourceNameRef {
+            project: &self.project,
+            book: &self.book,
+        }
+    }
+
+    /// Returns the resource type.
+    pub fn resource_type(&self) -> &'static str {
+        "library.googleapis.com/Book"
+    }
+
+    /// Returns the name of the service the resource belongs to.
+    pub fn service_name(&self) -> &'static str {
+        "library.googleapis.com"
+    }
+
+    /// Returns the full resource name, prefixed with the service name.
+    pub fn to_full_name(&self) -> String {
+        format!("//{}/{}", self.service_name(), self)
+    }
+
+    /// Parses a full resource name, which must belong to the service of the resource.
+    pub fn from_full_name(full_name: &str) -> Result<Self, ResourceNameError> {
+        strip_service(full_name, "library.googleapis.com")?.parse()
+    }
+
+    /// Returns the API version of the resource, from its proto package.
+    pub fn api_version(&self) -> &'static str {
+        "v1"
+    }
+
+    /// Returns the resource URI in the given API version, or else in the one of the resource.
+    pub fn to_uri(&self, version: Option<&str>) -> String {
+        let version = version.unwrap_or(self.api_version());
+        format!("https://{}/{}/{}", self.service_name(), version, self)
+    }
+
+    /// Parses a resource URI of the service of the resource, and returns the name with the
+    /// API version of the URI.
+    pub fn from_uri(uri: &str) -> Result<(Self, &str), ResourceNameError> {
+        let (version, name) = aip_resourcename::split_uri(uri, "library.googleapis.com")?;
+        Ok((name.parse()?, version))
+    }
+
+    /// Returns true if any field contains a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        self.project == "-" || self.book == "-"
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        (self.project == "-" || self.project == concrete.project)
+            && (self.book == "-" || self.book == concrete.book)
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(mut self, variables: &[&str]) -> Self {
+        if variables.contains(&"project") {
+            self.project = "-".to_string();
+        }
+        if variables.contains(&"book") {
+            self.book = "-".to_string();
+        }
+        self
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        self.validate()?;
+        reject_wildcard("project", &self.project)?;
+        reject_wildcard("book", &self.book)?;
+        Ok(())
+    }
+
+    /// Returns true if `other` is the name of a resource nested under this one.
+    pub fn is_ancestor_of(&self, other: &str) -> bool {
+        ResourceName::is_ancestor_of(self, other)
+    }
+
+    /// Returns true if the name matches a glob such as `projects/acme/books/*`, where `*`
+    /// matches one segment and a final `**` any suffix. Globs that cannot match names of
+    /// this type match nothing.
+    pub fn matches_glob(&self, glob: &str) -> bool {
+        ResourceName::matches_glob(self, glob)
+    }
+
+}
+
+impl fmt::Display for BookResourceName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_borrowed(), f)
+    }
+}
+
+impl FromStr for BookResourceName {
+    type Err = ResourceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        BookResourceNameRef::parse_borrowed(s).map(BookResourceNameRef::to_owned)
+    }
+}
+
+impl ResourceName for BookResourceName {
+    const TYPE: &'static str = "library.googleapis.com/Book";
+    const PATTERNS: &'static [&'static str] = &["projects/{project}/books/{book}"];
+    const SINGULAR: &'static str = "book";
+    const PLURAL: &'static str = "books";
+
+    fn validate(&self) -> Result<(), ResourceNameError> {
+        self.validate()
+    }
+
+    fn contains_wildcard(&self) -> bool {
+        self.contains_wildcard()
+    }
+}
+
+impl Serialize for BookResourceName {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_variables(serializer, [("project", &self.project), ("book", &self.book)])
+    }
+}
+
+impl<'de> Deserialize<'de> for BookResourceName {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let [project, book] = deserialize_variables(deserializer, ["project", "book"])?;
+        validated(Self { project, book })
+    }
+}
+
+/// Borrowed view of a BookResourceName that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BookResourceNameRef<'a> {
+    pub project: &'a str,
+    pub book: &'a str,
+}
+
+impl<'a> BookResourceNameRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut scanner = Scanner::new(s, 4);
+        scanner.literal("projects")?;
+        let project = scanner.variable()?;
+        scanner.literal("books")?;
+        let book = scanner.variable()?;
+        scanner.finish()?;
+        Self::from_segments(project, book)
+    }
+
+    /// Creates a BookResourceNameRef from parsed segments and validates it.
+    fn from_segments(project: &'a str, book: &'a str) -> Result<Self, ResourceNameError> {
+        let name = Self { project, book };
+        name.validate()?;
+        Ok(name)
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        validate_segment("project", self.project)?;
+        validate_segment("book", self.book)?;
+        aip_resourcename::reject_revision_separator("book", self.book, "BookRevisionName")?;
+        Ok(())
+    }
+
+    /// Converts the borrowed view into an owned BookResourceName.
+    pub fn to_owned(self) -> BookResourceName {
+        BookResourceName {
+            project: self.project.to_string(),
+            book: self.book.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for BookResourceNameRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "projects/{project}/books/{book}"
+            , project = self.project
+            , book = self.book
+        )
+    }
+}
+
+/// Parent of a BookResourceName: `projects/{project}`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BookParent {
+    pub project: String,
+}
+
+impl BookParent {
+    /// Creates a new BookParent.
+    pub fn new(
+        project: impl Into<String>
+    ) -> Self {
+        Self {
+            project: project.into(),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        self.as_borrowed().validate()
+    }
+
+    /// Borrows the resource name as a BookParentRef.
+    pub fn as_borrowed(&self) -> BookParentRef<'_> {
+        BookParentRef {
+            project: &self.project,
+        }
+    }
+
+    /// Returns true if any field contains a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        self.project == "-"
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        self.project == "-" || self.project == concrete.project
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(mut self, variables: &[&str]) -> Self {
+        if variables.contains(&"project") {
+            self.project = "-".to_string();
+        }
+        self
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        self.validate()?;
+        reject_wildcard("project", &self.project)?;
+        Ok(())
+    }
+
+}
+
+impl fmt::Display for BookParent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_borrowed(), f)
+    }
+}
+
+impl FromStr for BookParent {
+    type Err = ResourceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        BookParentRef::parse_borrowed(s).map(BookParentRef::to_owned)
+    }
+}
+
+/// Borrowed view of a BookParent that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BookParentRef<'a> {
+    pub project: &'a str,
+}
+
+impl<'a> BookParentRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut scanner = Scanner::new(s, 2);
+        scanner.literal("projects")?;
+        let project = scanner.variable()?;
+        scanner.finish()?;
+        Self::from_segments(project)
+    }
+
+    /// Creates a BookParentRef from parsed segments and validates it.
+    fn from_segments(project: &'a str) -> Result<Self, ResourceNameError> {
+        let name = Self { project };
+        name.validate()?;
+        Ok(name)
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        validate_segment("project", self.project)?;
+        Ok(())
+    }
+
+    /// Converts the borrowed view into an owned BookParent.
+    pub fn to_owned(self) -> BookParent {
+        BookParent {
+            project: self.project.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for BookParentRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "projects/{project}"
+            , project = self.project
+        )
+    }
+}
+
+impl BookResourceName {
+    /// Returns the name of the parent of this resource.
+    pub fn parent(&self) -> BookParent {
+        BookParent {
+            project: self.project.clone(),
+        }
+    }
+}
+
+impl BookParent {
+    /// Creates the name of a BookResourceName nested under this one.
+    pub fn book(&self, book: impl Into<String>) -> BookResourceName {
+        BookResourceName { project: self.project.clone(), book: book.into() }
+    }
+}
+
+/// Revision-qualified name of a BookResourceName, e.g. `name@revision`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BookRevisionName {
+    pub name: BookResourceName,
+    pub revision: Option<String>,
+}
+
+impl BookRevisionName {
+    /// Creates a new BookRevisionName for a revision of the resource.
+    pub fn new(name: BookResourceName, revision: impl Into<String>) -> Self {
+        Self {
+            name,
+            revision: Some(revision.into()),
+        }
+    }
+
+    /// Validates the resource name and the revision ID.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        self.name.validate()?;
+        match &self.revision {
+            Some(revision) => aip_resourcename::validate_revision_id(revision),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns the name of the resource, without the revision.
+    pub fn without_revision(&self) -> BookResourceName {
+        self.name.clone()
+    }
+}
+
+impl From<BookResourceName> for BookRevisionName {
+    fn from(name: BookResourceName) -> Self {
+        Self {
+            name,
+            revision: None,
+        }
+    }
+}
+
+impl fmt::Display for BookRevisionName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.revision {
+            Some(revision) => write!(f, "{}@{}", self.name, revision),
+            None => write!(f, "{}", self.name),
+        }
+    }
+}
+
+impl FromStr for BookRevisionName {
+    type Err = ResourceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, revision) = aip_resourcename::split_revision(s);
+        if let Some(revision) = revision {
+            aip_resourcename::validate_revision_id(revision)?;
+        }
+        Ok(Self {
+            name: name.parse()?,
+            revision: revision.map(str::to_string),
+        })
+    }
+}
+
+impl example::library::v1::Book {
+    /// Parses the `name` field as a BookResourceName.
+    pub fn resource_name(&self) -> Result<BookResourceName, ResourceNameError> {
+        self.name.parse()
+    }
+
+    /// Sets the `name` field from a BookResourceName.
+    pub fn set_resource_name(&mut self, name: &BookResourceName) {
+        self.name = name.to_string();
+    }
+}
+
+/// Multi-pattern resource name for library.googleapis.com/Shelf
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ShelfResourceName {
+    Projects(ProjectsShelfResourceName),
+    Users(UsersShelfResourceName),
+}
+
+impl ShelfResourceName {
+    /// Returns the resource type.
+    pub fn resource_type(&self) -> &'static str {
+        "library.googleapis.com/Shelf"
+    }
+
+    /// Returns true if any field contains a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        match self {
+            ShelfResourceName::Projects(inner) => inner.contains_wildcard(),
+            ShelfResourceName::Users(inner) => inner.contains_wildcard(),
+        }
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    /// Names of different patterns are matched segment by segment.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        match (self, concrete) {
+            (ShelfResourceName::Projects(inner), ShelfResourceName::Projects(concrete)) => inner.matches(concrete),
+            (ShelfResourceName::Users(inner), ShelfResourceName::Users(concrete)) => inner.matches(concrete),
+            _ => aip_resourcename::wildcard_matches(&self.to_string(), &concrete.to_string()),
+        }
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(self, variables: &[&str]) -> Self {
+        match self {
+            ShelfResourceName::Projects(inner) => ShelfResourceName::Projects(inner.with_wildcards_in(variables)),
+            ShelfResourceName::Users(inner) => ShelfResourceName::Users(inner.with_wildcards_in(variables)),
+        }
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        match self {
+            ShelfResourceName::Projects(inner) => inner.validate_concrete(),
+            ShelfResourceName::Users(inner) => inner.validate_concrete(),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        match self {
+            ShelfResourceName::Projects(inner) => inner.validate(),
+            ShelfResourceName::Users(inner) => inner.validate(),
+        }
+    }
+
+    /// Borrows the resource name as a ShelfResourceNameRef.
+    pub fn as_borrowed(&self) -> ShelfResourceNameRef<'_> {
+        match self {
+            ShelfResourceName::Projects(inner) => ShelfResourceNameRef::Projects(inner.as_borrowed()),
+            ShelfResourceName::Users(inner) => ShelfResourceNameRef::Users(inner.as_borrowed()),
+        }
+    }
+
+    /// Returns true if `other` is the name of a resource nested under this one.
+    pub fn is_ancestor_of(&self, other: &str) -> bool {
+        ResourceName::is_ancestor_of(self, other)
+    }
+
+    /// Returns true if the name matches a glob such as `projects/acme/books/*`, where `*`
+    /// matches one segment and a final `**` any suffix. Globs that cannot match names of
+    /// this type match nothing.
+    pub fn matches_glob(&self, glob: &str) -> bool {
+        ResourceName::matches_glob(self, glob)
+    }
+
+    /// Returns the name of the service the resource belongs to.
+    pub fn service_name(&self) -> &'static str {
+        match self {
+            ShelfResourceName::Projects(inner) => inner.service_name(),
+            ShelfResourceName::Users(inner) => inner.service_name(),
+        }
+    }
+
+    /// Returns the full resource name, prefixed with the service name.
+    pub fn to_full_name(&self) -> String {
+        match self {
+            ShelfResourceName::Projects(inner) => inner.to_full_name(),
+            ShelfResourceName::Users(inner) => inner.to_full_name(),
+        }
+    }
+
+    /// Parses a full resource name, which must belong to the service of the resource.
+    pub fn from_full_name(full_name: &str) -> Result<Self, ResourceNameError> {
+        strip_service(full_name, "library.googleapis.com")?.parse()
+    }
+
+    /// Returns the API version of the resource, from its proto package.
+    pub fn api_version(&self) -> &'static str {
+        match self {
+            ShelfResourceName::Projects(inner) => inner.api_version(),
+            ShelfResourceName::Users(inner) => inner.api_version(),
+        }
+    }
+
+    /// Returns the resource URI in the given API version, or else in the one of the resource.
+    pub fn to_uri(&self, version: Option<&str>) -> String {
+        match self {
+            ShelfResourceName::Projects(inner) => inner.to_uri(version),
+            ShelfResourceName::Users(inner) => inner.to_uri(version),
+        }
+    }
+
+    /// Parses a resource URI of the service of the resource, and returns the name with the
+    /// API version of the URI.
+    pub fn from_uri(uri: &str) -> Result<(Self, &str), ResourceNameError> {
+        let (version, name) = aip_resourcename::split_uri(uri, "library.googleapis.com")?;
+        Ok((name.parse()?, version))
+    }
+
+}
+
+impl fmt::Display for ShelfResourceName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShelfResourceName::Projects(inner) => write!(f, "{inner}"),
+            ShelfResourceName::Users(inner) => write!(f, "{inner}"),
+        }
+    }
+}
+
+impl FromStr for ShelfResourceName {
+    type Err = ResourceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ShelfResourceNameRef::parse_borrowed(s).map(ShelfResourceNameRef::to_owned)
+    }
+}
+
+impl ResourceName for ShelfResourceName {
+    const TYPE: &'static str = "library.googleapis.com/Shelf";
+    const PATTERNS: &'static [&'static str] = &["projects/{project}/shelves/{shelf}", "users/{user}/shelves/{shelf}"];
+    const SINGULAR: &'static str = "shelf";
+    const PLURAL: &'static str = "shelves";
+
+    fn validate(&self) -> Result<(), ResourceNameError> {
+        self.validate()
+    }
+
+    fn contains_wildcard(&self) -> bool {
+        self.contains_wildcard()
+    }
+}
+
+impl Serialize for ShelfResourceName {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            ShelfResourceName::Projects(name) => name.serialize(serializer),
+            ShelfResourceName::Users(name) => name.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ShelfResourceName {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut variables = aip_resourcename::serde::Variables::deserialize(deserializer)?;
+        if let Some([project, shelf]) = variables.take(["project", "shelf"]) {
+            return validated(ShelfResourceName::Projects(ProjectsShelfResourceName { project, shelf }));
+        }
+        if let Some([user, shelf]) = variables.take(["user", "shelf"]) {
+            return validated(ShelfResourceName::Users(UsersShelfResourceName { user, shelf }));
+        }
+        Err(variables.no_match(&[&["project", "shelf"], &["user", "shelf"]]))
+    }
+}
+
+/// Borrowed view of a ShelfResourceName that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ShelfResourceNameRef<'a> {
+    Projects(ProjectsShelfResourceNameRef<'a>),
+    Users(UsersShelfResourceNameRef<'a>),
+}
+
+impl<'a> ShelfResourceNameRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut parts = s.split('/');
+        match parts.next() {
+            Some("projects") => {
+                match parts.next() {
+                    Some(part1) => {
+                        match parts.next() {
+                            Some("shelves") => {
+                                match parts.next() {
+                                    Some(part3) => {
+                                        match parts.next() {
+                                            Some(part) => Err(ResourceNameError::no_matching_pattern("projects/{project}/shelves/{shelf}", 4, Some(part))),
+                                            None => ProjectsShelfResourceNameRef::from_segments(part1, part3).map(Self::Projects),
+                                        }
+                                    }
+                                    None => Err(ResourceNameError::no_matching_pattern("projects/{project}/shelves/{shelf}", 3, None)),
+                                }
+                            }
+                            Some(part) => Err(ResourceNameError::no_matching_pattern("projects/{project}/shelves/{shelf}", 2, Some(part))),
+                            None => Err(ResourceNameError::no_matching_pattern("projects/{project}/shelves/{shelf}", 2, None)),
+                        }
+                    }
+                    None => Err(ResourceNameError::no_matching_pattern("projects/{project}/shelves/{shelf}", 1, None)),
+                }
+            }
+            Some("users") => {
+                match parts.next() {
+                    Some(part1) => {
+                        match parts.next() {
+                            Some("shelves") => {
+                                match parts.next() {
+                                    Some(part3) => {
+                                        match parts.next() {
+                                            Some(part) => Err(ResourceNameError::no_matching_pattern("users/{user}/shelves/{shelf}", 4, Some(part))),
+                                            None => UsersShelfResourceNameRef::from_segments(part1, part3).map(Self::Users),
+                                        }
+                                    }
+                                    None => Err(ResourceNameError::no_matching_pattern("users/{user}/shelves/{shelf}", 3, None)),
+                                }
+                            }
+                            Some(part) => Err(ResourceNameError::no_matching_pattern("users/{user}/shelves/{shelf}", 2, Some(part))),
+                            None => Err(ResourceNameError::no_matching_pattern("users/{user}/shelves/{shelf}", 2, None)),
+                        }
+                    }
+                    None => Err(ResourceNameError::no_matching_pattern("users/{user}/shelves/{shelf}", 1, None)),
+                }
+            }
+            Some(part) => Err(ResourceNameError::no_matching_pattern("projects/{project}/shelves/{shelf}", 0, Some(part))),
+            None => Err(ResourceNameError::no_matching_pattern("projects/{project}/shelves/{shelf}", 0, None)),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        match self {
+            ShelfResourceNameRef::Projects(inner) => inner.validate(),
+            ShelfResourceNameRef::Users(inner) => inner.validate(),
+        }
+    }
+
+    /// Converts the borrowed view into an owned ShelfResourceName.
+    pub fn to_owned(self) -> ShelfResourceName {
+        match self {
+            ShelfResourceNameRef::Projects(inner) => ShelfResourceName::Projects(inner.to_owned()),
+            ShelfResourceNameRef::Users(inner) => ShelfResourceName::Users(inner.to_owned()),
+        }
+    }
+}
+
+impl fmt::Display for ShelfResourceNameRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShelfResourceNameRef::Projects(inner) => write!(f, "{inner}"),
+            ShelfResourceNameRef::Users(inner) => write!(f, "{inner}"),
+        }
+    }
+}
+
+/// Parses a resource name string and returns the appropriate type.
+pub fn parse_shelf_resource_name(name: &str) -> Result<ShelfResourceName, ResourceNameError> {
+    ShelfResourceName::from_str(name)
+}
+
+/// Resource name for library.googleapis.com/Shelf
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ProjectsShelfResourceName {
+    pub project: String,
+    pub shelf: String,
+}
+
+impl ProjectsShelfResourceName {
+    /// Creates a new ProjectsShelfResourceName.
+    pub fn new(
+        project: impl Into<String>,
+        shelf: impl Into<String>
+    ) -> Self {
+        Self {
+            project: project.into(),
+            shelf: shelf.into(),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        self.as_borrowed().validate()
+    }
+
+    /// Borrows the resource name as a ProjectsShelfResourceNameRef.
+    pub fn as_borrowed(&self) -> ProjectsShelfResourceNameRef<'_> {
+        ProjectsShelfResourceNameRef {
+            project: &self.project,
+            shelf: &self.shelf,
+        }
+    }
+
+    /// Returns the resource type.
+    pub fn resource_type(&self) -> &'static str {
+        "library.googleapis.com/Shelf"
+    }
+
+    /// Returns the name of the service the resource belongs to.
+    pub fn service_name(&self) -> &'static str {
+        "library.googleapis.com"
+    }
+
+    /// Returns the full resource name, prefixed with the service name.
+    pub fn to_full_name(&self) -> String {
+        format!("//{}/{}", self.service_name(), self)
+    }
+
+    /// Parses a full resource name, which must belong to the service of the resource.
+    pub fn from_full_name(full_name: &str) -> Result<Self, ResourceNameError> {
+        strip_service(full_name, "library.googleapis.com")?.parse()
+    }
+
+    /// Returns the API version of the resource, from its proto package.
+    pub fn api_version(&self) -> &'static str {
+        "v1"
+    }
+
+    /// Returns the resource URI in the given API version, or else in the one of the resource.
+    pub fn to_uri(&self, version: Option<&str>) -> String {
+        let version = version.unwrap_or(self.api_version());
+        format!("https://{}/{}/{}", self.service_name(), version, self)
+    }
+
+    /// Parses a resource URI of the service of the resource, and returns the name with the
+    /// API version of the URI.
+    pub fn from_uri(uri: &str) -> Result<(Self, &str), ResourceNameError> {
+        let (version, name) = aip_resourcename::split_uri(uri, "library.googleapis.com")?;
+        Ok((name.parse()?, version))
+    }
+
+    /// Returns true if any field contains a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        self.project == "-" || self.shelf == "-"
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        (self.project == "-" || self.project == concrete.project)
+            && (self.shelf == "-" || self.shelf == concrete.shelf)
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(mut self, variables: &[&str]) -> Self {
+        if variables.contains(&"project") {
+            self.project = "-".to_string();
+        }
+        if variables.contains(&"shelf") {
+            self.shelf = "-".to_string();
+        }
+        self
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        self.validate()?;
+        reject_wildcard("project", &self.project)?;
+        reject_wildcard("shelf", &self.shelf)?;
+        Ok(())
+    }
+
+    /// Returns true if `other` is the name of a resource nested under this one.
+    pub fn is_ancestor_of(&self, other: &str) -> bool {
+        ResourceName::is_ancestor_of(self, other)
+    }
+
+    /// Returns true if the name matches a glob such as `projects/acme/books/*`, where `*`
+    /// matches one segment and a final `**` any suffix. Globs that cannot match names of
+    /// this type match nothing.
+    pub fn matches_glob(&self, glob: &str) -> bool {
+        ResourceName::matches_glob(self, glob)
+    }
+
+}
+
+impl fmt::Display for ProjectsShelfResourceName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_borrowed(), f)
+    }
+}
+
+impl FromStr for ProjectsShelfResourceName {
+    type Err = ResourceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ProjectsShelfResourceNameRef::parse_borrowed(s).map(ProjectsShelfResourceNameRef::to_owned)
+    }
+}
+
+impl ResourceName for ProjectsShelfResourceName {
+    const TYPE: &'static str = "library.googleapis.com/Shelf";
+    const PATTERNS: &'static [&'static str] = &["projects/{project}/shelves/{shelf}"];
+    const SINGULAR: &'static str = "shelf";
+    const PLURAL: &'static str = "shelves";
+
+    fn validate(&self) -> Result<(), ResourceNameError> {
+        self.validate()
+    }
+
+    fn contains_wildcard(&self) -> bool {
+        self.contains_wildcard()
+    }
+}
+
+impl Serialize for ProjectsShelfResourceName {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_variables(serializer, [("project", &self.project), ("shelf", &self.shelf)])
+    }
+}
+
+impl<'de> Deserialize<'de> for ProjectsShelfResourceName {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let [project, shelf] = deserialize_variables(deserializer, ["project", "shelf"])?;
+        validated(Self { project, shelf })
+    }
+}
+
+/// Borrowed view of a ProjectsShelfResourceName that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ProjectsShelfResourceNameRef<'a> {
+    pub project: &'a str,
+    pub shelf: &'a str,
+}
+
+impl<'a> ProjectsShelfResourceNameRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut scanner = Scanner::new(s, 4);
+        scanner.literal("projects")?;
+        let project = scanner.variable()?;
+        scanner.literal("shelves")?;
+        let shelf = scanner.variable()?;
+        scanner.finish()?;
+        Self::from_segments(project, shelf)
+    }
+
+    /// Creates a ProjectsShelfResourceNameRef from parsed segments and validates it.
+    fn from_segments(project: &'a str, shelf: &'a str) -> Result<Self, ResourceNameError> {
+        let name = Self { project, shelf };
+        name.validate()?;
+        Ok(name)
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        validate_segment("project", self.project)?;
+        validate_segment("shelf", self.shelf)?;
+        Ok(())
+    }
+
+    /// Converts the borrowed view into an owned ProjectsShelfResourceName.
+    pub fn to_owned(self) -> ProjectsShelfResourceName {
+        ProjectsShelfResourceName {
+            project: self.project.to_string(),
+            shelf: self.shelf.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ProjectsShelfResourceNameRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "projects/{project}/shelves/{shelf}"
+            , project = self.project
+            , shelf = self.shelf
+        )
+    }
+}
+
+/// Resource name for library.googleapis.com/Shelf
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UsersShelfResourceName {
+    pub user: String,
+    pub shelf: String,
+}
+
+impl UsersShelfResourceName {
+    /// Creates a new UsersShelfResourceName.
+    pub fn new(
+        user: impl Into<String>,
+        shelf: impl Into<String>
+    ) -> Self {
+        Self {
+            user: user.into(),
+            shelf: shelf.into(),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        self.as_borrowed().validate()
+    }
+
+    /// Borrows the resource name as a UsersShelfResourceNameRef.
+    pub fn as_borrowed(&self) -> UsersShelfResourceNameRef<'_> {
+        UsersShelfResourceNameRef {
+            user: &self.user,
+            shelf: &self.shelf,
+        }
+    }
+
+    /// Returns the resource type.
+    pub fn resource_type(&self) -> &'static str {
+        "library.googleapis.com/Shelf"
+    }
+
+    /// Returns the name of the service the resource belongs to.
+    pub fn service_name(&self) -> &'static str {
+        "library.googleapis.com"
+    }
+
+    /// Returns the full resource name, prefixed with the service name.
+    pub fn to_full_name(&self) -> String {
+        format!("//{}/{}", self.service_name(), self)
+    }
+
+    /// Parses a full resource name, which must belong to the service of the resource.
+    pub fn from_full_name(full_name: &str) -> Result<Self, ResourceNameError> {
+        strip_service(full_name, "library.googleapis.com")?.parse()
+    }
+
+    /// Returns the API version of the resource, from its proto package.
+    pub fn api_version(&self) -> &'static str {
+        "v1"
+    }
+
+    /// Returns the resource URI in the given API version, or else in the one of the resource.
+    pub fn to_uri(&self, version: Option<&str>) -> String {
+        let version = version.unwrap_or(self.api_version());
+        format!("https://{}/{}/{}", self.service_name(), version, self)
+    }
+
+    /// Parses a resource URI of the service of the resource, and returns the name with the
+    /// API version of the URI.
+    pub fn from_uri(uri: &str) -> Result<(Self, &str), ResourceNameError> {
+        let (version, name) = aip_resourcename::split_uri(uri, "library.googleapis.com")?;
+        Ok((name.parse()?, version))
+    }
+
+    /// Returns true if any field contains a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        self.user == "-" || self.shelf == "-"
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        (self.user == "-" || self.user == concrete.user)
+            && (self.shelf == "-" || self.shelf == concrete.shelf)
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(mut self, variables: &[&str]) -> Self {
+        if variables.contains(&"user") {
+            self.user = "-".to_string();
+        }
+        if variables.contains(&"shelf") {
+            self.shelf = "-".to_string();
+        }
+        self
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        self.validate()?;
+        reject_wildcard("user", &self.user)?;
+        reject_wildcard("shelf", &self.shelf)?;
+        Ok(())
+    }
+
+    /// Returns true if `other` is the name of a resource nested under this one.
+    pub fn is_ancestor_of(&self, other: &str) -> bool {
+        ResourceName::is_ancestor_of(self, other)
+    }
+
+    /// Returns true if the name matches a glob such as `projects/acme/books/*`, where `*`
+    /// matches one segment and a final `**` any suffix. Globs that cannot match names of
+    /// this type match nothing.
+    pub fn matches_glob(&self, glob: &str) -> bool {
+        ResourceName::matches_glob(self, glob)
+    }
+
+}
+
+impl fmt::Display for UsersShelfResourceName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_borrowed(), f)
+    }
+}
+
+impl FromStr for UsersShelfResourceName {
+    type Err = ResourceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        UsersShelfResourceNameRef::parse_borrowed(s).map(UsersShelfResourceNameRef::to_owned)
+    }
+}
+
+impl ResourceName for UsersShelfResourceName {
+    const TYPE: &'static str = "library.googleapis.com/Shelf";
+    const PATTERNS: &'static [&'static str] = &["users/{user}/shelves/{shelf}"];
+    const SINGULAR: &'static str = "shelf";
+    const PLURAL: &'static str = "shelves";
+
+    fn validate(&self) -> Result<(), ResourceNameError> {
+        self.validate()
+    }
+
+    fn contains_wildcard(&self) -> bool {
+        self.contains_wildcard()
+    }
+}
+
+impl Serialize for UsersShelfResourceName {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_variables(serializer, [("user", &self.user), ("shelf", &self.shelf)])
+    }
+}
+
+impl<'de> Deserialize<'de> for UsersShelfResourceName {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let [user, shelf] = deserialize_variables(deserializer, ["user", "shelf"])?;
+        validated(Self { user, shelf })
+    }
+}
+
+/// Borrowed view of a UsersShelfResourceName that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UsersShelfResourceNameRef<'a> {
+    pub user: &'a str,
+    pub shelf: &'a str,
+}
+
+impl<'a> UsersShelfResourceNameRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut scanner = Scanner::new(s, 4);
+        scanner.literal("users")?;
+        let user = scanner.variable()?;
+        scanner.literal("shelves")?;
+        let shelf = scanner.variable()?;
+        scanner.finish()?;
+        Self::from_segments(user, shelf)
+    }
+
+    /// Creates a UsersShelfResourceNameRef from parsed segments and validates it.
+    fn from_segments(user: &'a str, shelf: &'a str) -> Result<Self, ResourceNameError> {
+        let name = Self { user, shelf };
+        name.validate()?;
+        Ok(name)
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        validate_segment("user", self.user)?;
+        validate_segment("shelf", self.shelf)?;
+        Ok(())
+    }
+
+    /// Converts the borrowed view into an owned UsersShelfResourceName.
+    pub fn to_owned(self) -> UsersShelfResourceName {
+        UsersShelfResourceName {
+            user: self.user.to_string(),
+            shelf: self.shelf.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for UsersShelfResourceNameRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "users/{user}/shelves/{shelf}"
+            , user = self.user
+            , shelf = self.shelf
+        )
+    }
+}
+
+/// Parent of a ShelfResourceName.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ShelfParent {
+    Projects(ProjectsShelfParent),
+    Users(UsersShelfParent),
+}
+
+impl ShelfParent {
+    /// Returns true if any field contains a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        match self {
+            ShelfParent::Projects(inner) => inner.contains_wildcard(),
+            ShelfParent::Users(inner) => inner.contains_wildcard(),
+        }
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    /// Names of different patterns are matched segment by segment.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        match (self, concrete) {
+            (ShelfParent::Projects(inner), ShelfParent::Projects(concrete)) => inner.matches(concrete),
+            (ShelfParent::Users(inner), ShelfParent::Users(concrete)) => inner.matches(concrete),
+            _ => aip_resourcename::wildcard_matches(&self.to_string(), &concrete.to_string()),
+        }
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(self, variables: &[&str]) -> Self {
+        match self {
+            ShelfParent::Projects(inner) => ShelfParent::Projects(inner.with_wildcards_in(variables)),
+            ShelfParent::Users(inner) => ShelfParent::Users(inner.with_wildcards_in(variables)),
+        }
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        match self {
+            ShelfParent::Projects(inner) => inner.validate_concrete(),
+            ShelfParent::Users(inner) => inner.validate_concrete(),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        match self {
+            ShelfParent::Projects(inner) => inner.validate(),
+            ShelfParent::Users(inner) => inner.validate(),
+        }
+    }
+
+    /// Borrows the resource name as a ShelfParentRef.
+    pub fn as_borrowed(&self) -> ShelfParentRef<'_> {
+        match self {
+            ShelfParent::Projects(inner) => ShelfParentRef::Projects(inner.as_borrowed()),
+            ShelfParent::Users(inner) => ShelfParentRef::Users(inner.as_borrowed()),
+        }
+    }
+}
+
+impl fmt::Display for ShelfParent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShelfParent::Projects(inner) => write!(f, "{inner}"),
+            ShelfParent::Users(inner) => write!(f, "{inner}"),
+        }
+    }
+}
+
+impl FromStr for ShelfParent {
+    type Err = ResourceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ShelfParentRef::parse_borrowed(s).map(ShelfParentRef::to_owned)
+    }
+}
+
+/// Borrowed view of a ShelfParent that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ShelfParentRef<'a> {
+    Projects(ProjectsShelfParentRef<'a>),
+    Users(UsersShelfParentRef<'a>),
+}
+
+impl<'a> ShelfParentRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut parts = s.split('/');
+        match parts.next() {
+            Some("projects") => {
+                match parts.next() {
+                    Some(part1) => {
+                        match parts.next() {
+                            Some(part) => Err(ResourceNameError::no_matching_pattern("projects/{project}", 2, Some(part))),
+                            None => ProjectsShelfParentRef::from_segments(part1).map(Self::Projects),
+                        }
+                    }
+                    None => Err(ResourceNameError::no_matching_pattern("projects/{project}", 1, None)),
+                }
+            }
+            Some("users") => {
+                match parts.next() {
+                    Some(part1) => {
+                        match parts.next() {
+                            Some(part) => Err(ResourceNameError::no_matching_pattern("users/{user}", 2, Some(part))),
+                            None => UsersShelfParentRef::from_segments(part1).map(Self::Users),
+                        }
+                    }
+                    None => Err(ResourceNameError::no_matching_pattern("users/{user}", 1, None)),
+                }
+            }
+            Some(part) => Err(ResourceNameError::no_matching_pattern("projects/{project}", 0, Some(part))),
+            None => Err(ResourceNameError::no_matching_pattern("projects/{project}", 0, None)),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        match self {
+            ShelfParentRef::Projects(inner) => inner.validate(),
+            ShelfParentRef::Users(inner) => inner.validate(),
+        }
+    }
+
+    /// Converts the borrowed view into an owned ShelfParent.
+    pub fn to_owned(self) -> ShelfParent {
+        match self {
+            ShelfParentRef::Projects(inner) => ShelfParent::Projects(inner.to_owned()),
+            ShelfParentRef::Users(inner) => ShelfParent::Users(inner.to_owned()),
+        }
+    }
+}
+
+impl fmt::Display for ShelfParentRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShelfParentRef::Projects(inner) => write!(f, "{inner}"),
+            ShelfParentRef::Users(inner) => write!(f, "{inner}"),
+        }
+    }
+}
+
+/// Parent of a ShelfResourceName: `projects/{project}`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ProjectsShelfParent {
+    pub project: String,
+}
+
+impl ProjectsShelfParent {
+    /// Creates a new ProjectsShelfParent.
+    pub fn new(
+        project: impl Into<String>
+    ) -> Self {
+        Self {
+            project: project.into(),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        self.as_borrowed().validate()
+    }
+
+    /// Borrows the resource name as a ProjectsShelfParentRef.
+    pub fn as_borrowed(&self) -> ProjectsShelfParentRef<'_> {
+        ProjectsShelfParentRef {
+            project: &self.project,
+        }
+    }
+
+    /// Returns true if any field contains a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        self.project == "-"
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        self.project == "-" || self.project == concrete.project
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(mut self, variables: &[&str]) -> Self {
+        if variables.contains(&"project") {
+            self.project = "-".to_string();
+        }
+        self
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        self.validate()?;
+        reject_wildcard("project", &self.project)?;
+        Ok(())
+    }
+
+}
+
+impl fmt::Display for ProjectsShelfParent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_borrowed(), f)
+    }
+}
+
+impl FromStr for ProjectsShelfParent {
+    type Err = ResourceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ProjectsShelfParentRef::parse_borrowed(s).map(ProjectsShelfParentRef::to_owned)
+    }
+}
+
+/// Borrowed view of a ProjectsShelfParent that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ProjectsShelfParentRef<'a> {
+    pub project: &'a str,
+}
+
+impl<'a> ProjectsShelfParentRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut scanner = Scanner::new(s, 2);
+        scanner.literal("projects")?;
+        let project = scanner.variable()?;
+        scanner.finish()?;
+        Self::from_segments(project)
+    }
+
+    /// Creates a ProjectsShelfParentRef from parsed segments and validates it.
+    fn from_segments(project: &'a str) -> Result<Self, ResourceNameError> {
+        let name = Self { project };
+        name.validate()?;
+        Ok(name)
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        validate_segment("project", self.project)?;
+        Ok(())
+    }
+
+    /// Converts the borrowed view into an owned ProjectsShelfParent.
+    pub fn to_owned(self) -> ProjectsShelfParent {
+        ProjectsShelfParent {
+            project: self.project.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ProjectsShelfParentRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "projects/{project}"
+            , project = self.project
+        )
+    }
+}
+
+/// Parent of a ShelfResourceName: `users/{user}`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UsersShelfParent {
+    pub user: String,
+}
+
+impl UsersShelfParent {
+    /// Creates a new UsersShelfParent.
+    pub fn new(
+        user: impl Into<String>
+    ) -> Self {
+        Self {
+            user: user.into(),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        self.as_borrowed().validate()
+    }
+
+    /// Borrows the resource name as a UsersShelfParentRef.
+    pub fn as_borrowed(&self) -> UsersShelfParentRef<'_> {
+        UsersShelfParentRef {
+            user: &self.user,
+        }
+    }
+
+    /// Returns true if any field contains a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        self.user == "-"
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        self.user == "-" || self.user == concrete.user
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(mut self, variables: &[&str]) -> Self {
+        if variables.contains(&"user") {
+            self.user = "-".to_string();
+        }
+        self
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        self.validate()?;
+        reject_wildcard("user", &self.user)?;
+        Ok(())
+    }
+
+}
+
+impl fmt::Display for UsersShelfParent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_borrowed(), f)
+    }
+}
+
+impl FromStr for UsersShelfParent {
+    type Err = ResourceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        UsersShelfParentRef::parse_borrowed(s).map(UsersShelfParentRef::to_owned)
+    }
+}
+
+/// Borrowed view of a UsersShelfParent that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UsersShelfParentRef<'a> {
+    pub user: &'a str,
+}
+
+impl<'a> UsersShelfParentRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut scanner = Scanner::new(s, 2);
+        scanner.literal("users")?;
+        let user = scanner.variable()?;
+        scanner.finish()?;
+        Self::from_segments(user)
+    }
+
+    /// Creates a UsersShelfParentRef from parsed segments and validates it.
+    fn from_segments(user: &'a str) -> Result<Self, ResourceNameError> {
+        let name = Self { user };
+        name.validate()?;
+        Ok(name)
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        validate_segment("user", self.user)?;
+        Ok(())
+    }
+
+    /// Converts the borrowed view into an owned UsersShelfParent.
+    pub fn to_owned(self) -> UsersShelfParent {
+        UsersShelfParent {
+            user: self.user.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for UsersShelfParentRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "users/{user}"
+            , user = self.user
+        )
+    }
+}
+
+impl ProjectsShelfResourceName {
+    /// Returns the name of the parent of this resource.
+    pub fn parent(&self) -> ProjectsShelfParent {
+        ProjectsShelfParent {
+            project: self.project.clone(),
+        }
+    }
+}
+
+impl UsersShelfResourceName {
+    /// Returns the name of the parent of this resource.
+    pub fn parent(&self) -> UsersShelfParent {
+        UsersShelfParent {
+            user: self.user.clone(),
+        }
+    }
+}
+
+impl ShelfResourceName {
+    /// Returns the name of the parent of this resource.
+    pub fn parent(&self) -> ShelfParent {
+        match self {
+            ShelfResourceName::Projects(inner) => ShelfParent::Projects(inner.parent()),
+            ShelfResourceName::Users(inner) => ShelfParent::Users(inner.parent()),
+        }
+    }
+}
+
+impl ProjectsShelfParent {
+    /// Creates the name of a ShelfResourceName nested under this one.
+    pub fn shelf(&self, shelf: impl Into<String>) -> ShelfResourceName {
+        ShelfResourceName::Projects(ProjectsShelfResourceName { project: self.project.clone(), shelf: shelf.into() })
+    }
+}
+
+impl UsersShelfParent {
+    /// Creates the name of a ShelfResourceName nested under this one.
+    pub fn shelf(&self, shelf: impl Into<String>) -> ShelfResourceName {
+        ShelfResourceName::Users(UsersShelfResourceName { user: self.user.clone(), shelf: shelf.into() })
+    }
+}
+
+impl ShelfParent {
+    /// Creates the name of a ShelfResourceName nested under this one.
+    pub fn shelf(&self, shelf: impl Into<String>) -> ShelfResourceName {
+        match self {
+            ShelfParent::Projects(inner) => inner.shelf(shelf),
+            ShelfParent::Users(inner) => inner.shelf(shelf),
+        }
+    }
+}
+
+impl example::library::v1::Shelf {
+    /// Parses the `name` field as a ShelfResourceName.
+    pub fn resource_name(&self) -> Result<ShelfResourceName, ResourceNameError> {
+        self.name.parse()
+    }
+
+    /// Sets the `name` field from a ShelfResourceName.
+    pub fn set_resource_name(&mut self, name: &ShelfResourceName) {
+        self.name = name.to_string();
+    }
+}
+
+/// Multi-pattern resource name for library.googleapis.com/Author
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AuthorResourceName {
+    Authors(AuthorsAuthorResourceName),
+    /// A well-formed name that matches none of the known patterns. It has no parent
+    /// and matches no glob.
+    Unknown(UntypedResourceName),
+}
+
+impl AuthorResourceName {
+    /// Returns the resource type.
+    pub fn resource_type(&self) -> &'static str {
+        "library.googleapis.com/Author"
+    }
+
+    /// Returns true if the name matches none of the known patterns.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, AuthorResourceName::Unknown(_))
+    }
+
+    /// Returns true if any field contains a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        match self {
+            AuthorResourceName::Authors(inner) => inner.contains_wildcard(),
+            AuthorResourceName::Unknown(name) => name.contains_wildcard(),
+        }
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    /// Names of different patterns are matched segment by segment.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        match (self, concrete) {
+            (AuthorResourceName::Authors(inner), AuthorResourceName::Authors(concrete)) => inner.matches(concrete),
+            (AuthorResourceName::Unknown(name), AuthorResourceName::Unknown(concrete)) => name.matches(concrete),
+            _ => aip_resourcename::wildcard_matches(&self.to_string(), &concrete.to_string()),
+        }
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(self, variables: &[&str]) -> Self {
+        match self {
+            AuthorResourceName::Authors(inner) => AuthorResourceName::Authors(inner.with_wildcards_in(variables)),
+            AuthorResourceName::Unknown(name) => AuthorResourceName::Unknown(name),
+        }
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        match self {
+            AuthorResourceName::Authors(inner) => inner.validate_concrete(),
+            AuthorResourceName::Unknown(name) => name.validate_concrete(),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        match self {
+            AuthorResourceName::Authors(inner) => inner.validate(),
+            AuthorResourceName::Unknown(_) => Ok(()),
+        }
+    }
+
+    /// Borrows the resource name as a AuthorResourceNameRef.
+    pub fn as_borrowed(&self) -> AuthorResourceNameRef<'_> {
+        match self {
+            AuthorResourceName::Authors(inner) => AuthorResourceNameRef::Authors(inner.as_borrowed()),
+            AuthorResourceName::Unknown(name) => AuthorResourceNameRef::Unknown(name.as_borrowed()),
+        }
+    }
+
+    /// Returns true if `other` is the name of a resource nested under this one.
+    pub fn is_ancestor_of(&self, other: &str) -> bool {
+        ResourceName::is_ancestor_of(self, other)
+    }
+
+    /// Returns true if the name matches a glob such as `projects/acme/books/*`, where `*`
+    /// matches one segment and a final `**` any suffix. Globs that cannot match names of
+    /// this type match nothing.
+    ///
+    /// Globs only match names of the known patterns, so an Unknown name
+    /// matches no glob, not even `**`.
+    pub fn matches_glob(&self, glob: &str) -> bool {
+        ResourceName::matches_glob(self, glob)
+    }
+
+    /// Returns the name of the service the resource belongs to.
+    pub fn service_name(&self) -> &'static str {
+        match self {
+            AuthorResourceName::Authors(inner) => inner.service_name(),
+            AuthorResourceName::Unknown(_) => "library.googleapis.com",
+        }
+    }
+
+    /// Returns the full resource name, prefixed with the service name.
+    pub fn to_full_name(&self) -> String {
+        match self {
+            AuthorResourceName::Authors(inner) => inner.to_full_name(),
+            AuthorResourceName::Unknown(name) => format!("//{}/{}", self.service_name(), name),
+        }
+    }
+
+    /// Parses a full resource name, which must belong to the service of the resource.
+    pub fn from_full_name(full_name: &str) -> Result<Self, ResourceNameError> {
+        strip_service(full_name, "library.googleapis.com")?.parse()
+    }
+
+    /// Returns the API version of the resource, from its proto package.
+    pub fn api_version(&self) -> &'static str {
+        match self {
+            AuthorResourceName::Authors(inner) => inner.api_version(),
+            AuthorResourceName::Unknown(_) => "v1",
+        }
+    }
+
+    /// Returns the resource URI in the given API version, or else in the one of the resource.
+    pub fn to_uri(&self, version: Option<&str>) -> String {
+        match self {
+            AuthorResourceName::Authors(inner) => inner.to_uri(version),
+            AuthorResourceName::Unknown(name) => {
+                let version = version.unwrap_or(self.api_version());
+                format!("https://{}/{}/{}", self.service_name(), version, name)
+            }
+        }
+    }
+
+    /// Parses a resource URI of the service of the resource, and returns the name with the
+    /// API version of the URI.
+    pub fn from_uri(uri: &str) -> Result<(Self, &str), ResourceNameError> {
+        let (version, name) = aip_resourcename::split_uri(uri, "library.googleapis.com")?;
+        Ok((name.parse()?, version))
+    }
+
+}
+
+impl fmt::Display for AuthorResourceName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AuthorResourceName::Authors(inner) => write!(f, "{inner}"),
+            AuthorResourceName::Unknown(name) => write!(f, "{name}"),
+        }
+    }
+}
+
+impl FromStr for AuthorResourceName {
+    type Err = ResourceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        AuthorResourceNameRef::parse_borrowed(s).map(AuthorResourceNameRef::to_owned)
+    }
+}
+
+impl ResourceName for AuthorResourceName {
+    const TYPE: &'static str = "library.googleapis.com/Author";
+    const PATTERNS: &'static [&'static str] = &["authors/{author}"];
+    const SINGULAR: &'static str = "author";
+    const PLURAL: &'static str = "authors";
+
+    fn validate(&self) -> Result<(), ResourceNameError> {
+        self.validate()
+    }
+
+    fn contains_wildcard(&self) -> bool {
+        self.contains_wildcard()
+    }
+}
+
+impl Serialize for AuthorResourceName {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            AuthorResourceName::Authors(name) => name.serialize(serializer),
+            AuthorResourceName::Unknown(name) => aip_resourcename::serde::serialize_str(name, serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for AuthorResourceName {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut variables = match aip_resourcename::serde::VariablesOrName::deserialize(deserializer)? {
+            aip_resourcename::serde::VariablesOrName::Variables(variables) => variables,
+            aip_resourcename::serde::VariablesOrName::Name(name) => return Ok(name),
+        };
+        if let Some([author]) = variables.take(["author"]) {
+            return validated(AuthorResourceName::Authors(AuthorsAuthorResourceName { author }));
+        }
+        Err(variables.no_match(&[&["author"]]))
+    }
+}
+
+/// Borrowed view of a AuthorResourceName that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AuthorResourceNameRef<'a> {
+    Authors(AuthorsAuthorResourceNameRef<'a>),
+    /// A well-formed name that matches none of the known patterns.
+    Unknown(UntypedResourceNameRef<'a>),
+}
+
+impl<'a> AuthorResourceNameRef<'a> {
+    /// Parses a resource name without allocating. Well-formed names that match none of
+    /// the known patterns are parsed as Unknown.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        Self::parse_known(s).or_else(|err| {
+            if err.kind != ResourceNameErrorKind::NoMatchingPattern {
+                return Err(err);
+            }
+            UntypedResourceNameRef::parse_borrowed(s)
+                .map(Self::Unknown)
+                .map_err(|_| err)
+        })
+    }
+
+    /// Parses a resource name of one of the known patterns.
+    fn parse_known(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut parts = s.split('/');
+        match parts.next() {
+            Some("authors") => {
+                match parts.next() {
+                    Some(part1) => {
+                        match parts.next() {
+                            Some(part) => Err(ResourceNameError::no_matching_pattern("authors/{author}", 2, Some(part))),
+                            None => AuthorsAuthorResourceNameRef::from_segments(part1).map(Self::Authors),
+                        }
+                    }
+                    None => Err(ResourceNameError::no_matching_pattern("authors/{author}", 1, None)),
+                }
+            }
+            Some(part) => Err(ResourceNameError::no_matching_pattern("authors/{author}", 0, Some(part))),
+            None => Err(ResourceNameError::no_matching_pattern("authors/{author}", 0, None)),
+        }
+    }
+
+    /// Returns true if the name matches none of the known patterns.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, AuthorResourceNameRef::Unknown(_))
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        match self {
+            AuthorResourceNameRef::Authors(inner) => inner.validate(),
+            AuthorResourceNameRef::Unknown(_) => Ok(()),
+        }
+    }
+
+    /// Converts the borrowed view into an owned AuthorResourceName.
+    pub fn to_owned(self) -> AuthorResourceName {
+        match self {
+            AuthorResourceNameRef::Authors(inner) => AuthorResourceName::Authors(inner.to_owned()),
+            AuthorResourceNameRef::Unknown(name) => AuthorResourceName::Unknown(name.to_owned()),
+        }
+    }
+}
+
+impl fmt::Display for AuthorResourceNameRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AuthorResourceNameRef::Authors(inner) => write!(f, "{inner}"),
+            AuthorResourceNameRef::Unknown(name) => write!(f, "{name}"),
+        }
+    }
+}
+
+/// Parses a resource name string and returns the appropriate type.
+pub fn parse_author_resource_name(name: &str) -> Result<AuthorResourceName, ResourceNameError> {
+    AuthorResourceName::from_str(name)
+}
+
+/// Resource name for library.googleapis.com/Author
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AuthorsAuthorResourceName {
+    pub author: String,
+}
+
+impl AuthorsAuthorResourceName {
+    /// Creates a new AuthorsAuthorResourceName.
+    pub fn new(
+        author: impl Into<String>
+    ) -> Self {
+        Self {
+            author: author.into(),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        self.as_borrowed().validate()
+    }
+
+    /// Borrows the resource name as a AuthorsAuthorResourceNameRef.
+    pub fn as_borrowed(&self) -> AuthorsAuthorResourceNameRef<'_> {
+        AuthorsAuthorResourceNameRef {
+            author: &self.author,
+        }
+    }
+
+    /// Returns the resource type.
+    pub fn resource_type(&self) -> &'static str {
+        "library.googleapis.com/Author"
+    }
+
+    /// Returns the name of the service the resource belongs to.
+    pub fn service_name(&self) -> &'static str {
+        "library.googleapis.com"
+    }
+
+    /// Returns the full resource name, prefixed with the service name.
+    pub fn to_full_name(&self) -> String {
+        format!("//{}/{}", self.service_name(), self)
+    }
+
+    /// Parses a full resource name, which must belong to the service of the resource.
+    pub fn from_full_name(full_name: &str) -> Result<Self, ResourceNameError> {
+        strip_service(full_name, "library.googleapis.com")?.parse()
+    }
+
+    /// Returns the API version of the resource, from its proto package.
+    pub fn api_version(&self) -> &'static str {
+        "v1"
+    }
+
+    /// Returns the resource URI in the given API version, or else in the one of the resource.
+    pub fn to_uri(&self, version: Option<&str>) -> String {
+        let version = version.unwrap_or(self.api_version());
+        format!("https://{}/{}/{}", self.service_name(), version, self)
+    }
+
+    /// Parses a resource URI of the service of the resource, and returns the name with the
+    /// API version of the URI.
+    pub fn from_uri(uri: &str) -> Result<(Self, &str), ResourceNameError> {
+        let (version, name) = aip_resourcename::split_uri(uri, "library.googleapis.com")?;
+        Ok((name.parse()?, version))
+    }
+
+    /// Returns true if any field contains a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        self.author == "-"
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        self.author == "-" || self.author == concrete.author
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(mut self, variables: &[&str]) -> Self {
+        if variables.contains(&"author") {
+            self.author = "-".to_string();
+        }
+        self
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        self.validate()?;
+        reject_wildcard("author", &self.author)?;
+        Ok(())
+    }
+
+    /// Returns true if `other` is the name of a resource nested under this one.
+    pub fn is_ancestor_of(&self, other: &str) -> bool {
+        ResourceName::is_ancestor_of(self, other)
+    }
+
+    /// Returns true if the name matches a glob such as `projects/acme/books/*`, where `*`
+    /// matches one segment and a final `**` any suffix. Globs that cannot match names of
+    /// this type match nothing.
+    pub fn matches_glob(&self, glob: &str) -> bool {
+        ResourceName::matches_glob(self, glob)
+    }
+
+}
+
+impl fmt::Display for AuthorsAuthorResourceName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_borrowed(), f)
+    }
+}
+
+impl FromStr for AuthorsAuthorResourceName {
+    type Err = ResourceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        AuthorsAuthorResourceNameRef::parse_borrowed(s).map(AuthorsAuthorResourceNameRef::to_owned)
+    }
+}
+
+impl ResourceName for AuthorsAuthorResourceName {
+    const TYPE: &'static str = "library.googleapis.com/Author";
+    const PATTERNS: &'static [&'static str] = &["authors/{author}"];
+    const SINGULAR: &'static str = "author";
+    const PLURAL: &'static str = "authors";
+
+    fn validate(&self) -> Result<(), ResourceNameError> {
+        self.validate()
+    }
+
+    fn contains_wildcard(&self) -> bool {
+        self.contains_wildcard()
+    }
+}
+
+impl Serialize for AuthorsAuthorResourceName {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_variables(serializer, [("author", &self.author)])
+    }
+}
+
+impl<'de> Deserialize<'de> for AuthorsAuthorResourceName {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let [author] = deserialize_variables(deserializer, ["author"])?;
+        validated(Self { author })
+    }
+}
+
+/// Borrowed view of a AuthorsAuthorResourceName that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AuthorsAuthorResourceNameRef<'a> {
+    pub author: &'a str,
+}
+
+impl<'a> AuthorsAuthorResourceNameRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut scanner = Scanner::new(s, 2);
+        scanner.literal("authors")?;
+        let author = scanner.variable()?;
+        scanner.finish()?;
+        Self::from_segments(author)
+    }
+
+    /// Creates a AuthorsAuthorResourceNameRef from parsed segments and validates it.
+    fn from_segments(author: &'a str) -> Result<Self, ResourceNameError> {
+        let name = Self { author };
+        name.validate()?;
+        Ok(name)
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        validate_segment("author", self.author)?;
+        Ok(())
+    }
+
+    /// Converts the borrowed view into an owned AuthorsAuthorResourceName.
+    pub fn to_owned(self) -> AuthorsAuthorResourceName {
+        AuthorsAuthorResourceName {
+            author: self.author.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for AuthorsAuthorResourceNameRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "authors/{author}"
+            , author = self.author
+        )
+    }
+}
+
+impl example::library::v1::Author {
+    /// Parses the `name` field as a AuthorResourceName.
+    pub fn resource_name(&self) -> Result<AuthorResourceName, ResourceNameError> {
+        self.name.parse()
+    }
+
+    /// Sets the `name` field from a AuthorResourceName.
+    pub fn set_resource_name(&mut self, name: &AuthorResourceName) {
+        self.name = name.to_string();
+    }
+}
+
+/// Resource name for library.googleapis.com/Review
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ReviewResourceName {
+    pub project: String,
+    pub book: String,
+    pub review: String,
+}
+
+impl ReviewResourceName {
+    /// Creates a new ReviewResourceName.
+    pub fn new(
+        project: impl Into<String>,
+        book: impl Into<String>,
+        review: impl Into<String>
+    ) -> Self {
+        Self {
+            project: project.into(),
+            book: book.into(),
+            review: review.into(),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        self.as_borrowed().validate()
+    }
+
+    /// Borrows the resource name as a ReviewResourceNameRef.
+    pub fn as_borrowed(&self) -> ReviewResourceNameRef<'_> {
+        ReviewResourceNameRef {
+            project: &self.project,
+            book: &self.book,
+            review: &self.review,
+        }
+    }
+
+    /// Returns the resource type.
+    pub fn resource_type(&self) -> &'static str {
+        "library.googleapis.com/Review"
+    }
+
+    /// Returns the name of the service the resource belongs to.
+    pub fn service_name(&self) -> &'static str {
+        "library.googleapis.com"
+    }
+
+    /// Returns the full resource name, prefixed with the service name.
+    pub fn to_full_name(&self) -> String {
+        format!("//{}/{}", self.service_name(), self)
+    }
+
+    /// Parses a full resource name, which must belong to the service of the resource.
+    pub fn from_full_name(full_name: &str) -> Result<Self, ResourceNameError> {
+        strip_service(full_name, "library.googleapis.com")?.parse()
+    }
+
+    /// Returns the API version of the resource, from its proto package.
+    pub fn api_version(&self) -> &'static str {
+        "v1"
+    }
+
+    /// Returns the resource URI in the given API version, or else in the one of the resource.
+    pub fn to_uri(&self, version: Option<&str>) -> String {
+        let version = version.unwrap_or(self.api_version());
+        format!("https://{}/{}/{}", self.service_name(), version, self)
+    }
+
+    /// Parses a resource URI of the service of the resource, and returns the name with the
+    /// API version of the URI.
+    pub fn from_uri(uri: &str) -> Result<(Self, &str), ResourceNameError> {
+        let (version, name) = aip_resourcename::split_uri(uri, "library.googleapis.com")?;
+        Ok((name.parse()?, version))
+    }
+
+    /// Returns true if any field contains a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        self.project == "-" || self.book == "-" || self.review == "-"
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        (self.project == "-" || self.project == concrete.project)
+            && (self.book == "-" || self.book == concrete.book)
+            && (self.review == "-" || self.review == concrete.review)
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(mut self, variables: &[&str]) -> Self {
+        if variables.contains(&"project") {
+            self.project = "-".to_string();
+        }
+        if variables.contains(&"book") {
+            self.book = "-".to_string();
+        }
+        if variables.contains(&"review") {
+            self.review = "-".to_string();
+        }
+        self
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        self.validate()?;
+        reject_wildcard("project", &self.project)?;
+        reject_wildcard("book", &self.book)?;
+        reject_wildcard("review", &self.review)?;
+        Ok(())
+    }
+
+    /// Returns true if `other` is the name of a resource nested under this one.
+    pub fn is_ancestor_of(&self, other: &str) -> bool {
+        ResourceName::is_ancestor_of(self, other)
+    }
+
+    /// Returns true if the name matches a glob such as `projects/acme/books/*`, where `*`
+    /// matches one segment and a final `**` any suffix. Globs that cannot match names of
+    /// this type match nothing.
+    pub fn matches_glob(&self, glob: &str) -> bool {
+        ResourceName::matches_glob(self, glob)
+    }
+
+}
+
+impl fmt::Display for ReviewResourceName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_borrowed(), f)
+    }
+}
+
+impl FromStr for ReviewResourceName {
+    type Err = ResourceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ReviewResourceNameRef::parse_borrowed(s).map(ReviewResourceNameRef::to_owned)
+    }
+}
+
+impl ResourceName for ReviewResourceName {
+    const TYPE: &'static str = "library.googleapis.com/Review";
+    const PATTERNS: &'static [&'static str] = &["projects/{project}/books/{book}/reviews/{review}"];
+    const SINGULAR: &'static str = "review";
+    const PLURAL: &'static str = "reviews";
+
+    fn validate(&self) -> Result<(), ResourceNameError> {
+        self.validate()
+    }
+
+    fn contains_wildcard(&self) -> bool {
+        self.contains_wildcard()
+    }
+}
+
+impl Serialize for ReviewResourceName {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_variables(serializer, [("project", &self.project), ("book", &self.book), ("review", &self.review)])
+    }
+}
+
+impl<'de> Deserialize<'de> for ReviewResourceName {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let [project, book, review] = deserialize_variables(deserializer, ["project", "book", "review"])?;
+        validated(Self { project, book, review })
+    }
+}
+
+/// Borrowed view of a ReviewResourceName that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ReviewResourceNameRef<'a> {
+    pub project: &'a str,
+    pub book: &'a str,
+    pub review: &'a str,
+}
+
+impl<'a> ReviewResourceNameRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut scanner = Scanner::new(s, 6);
+        scanner.literal("projects")?;
+        let project = scanner.variable()?;
+        scanner.literal("books")?;
+        let book = scanner.variable()?;
+        scanner.literal("reviews")?;
+        let review = scanner.variable()?;
+        scanner.finish()?;
+        Self::from_segments(project, book, review)
+    }
+
+    /// Creates a ReviewResourceNameRef from parsed segments and validates it.
+    fn from_segments(project: &'a str, book: &'a str, review: &'a str) -> Result<Self, ResourceNameError> {
+        let name = Self { project, book, review };
+        name.validate()?;
+        Ok(name)
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        validate_segment("project", self.project)?;
+        validate_segment("book", self.book)?;
+        validate_segment("review", self.review)?;
+        Ok(())
+    }
+
+    /// Converts the borrowed view into an owned ReviewResourceName.
+    pub fn to_owned(self) -> ReviewResourceName {
+        ReviewResourceName {
+            project: self.project.to_string(),
+            book: self.book.to_string(),
+            review: self.review.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ReviewResourceNameRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "projects/{project}/books/{book}/reviews/{review}"
+            , project = self.project
+            , book = self.book
+            , review = self.review
+        )
+    }
+}
+
+/// Parent of a ReviewResourceName.
+pub type ReviewParent = BookResourceName;
+
+impl ReviewResourceName {
+    /// Returns the name of the parent of this resource.
+    pub fn parent(&self) -> BookResourceName {
+        BookResourceName {
+            project: self.project.clone(),
+            book: self.book.clone(),
+        }
+    }
+}
+
+impl ReviewResourceName {
+    /// Returns the name of the BookResourceName this resource is nested under.
+    pub fn book(&self) -> BookResourceName {
+        BookResourceName {
+            project: self.project.clone(),
+            book: self.book.clone(),
+        }
+    }
+}
+
+impl BookResourceName {
+    /// Creates the name of a ReviewResourceName nested under this one.
+    pub fn review(&self, review: impl Into<String>) -> ReviewResourceName {
+        ReviewResourceName { project: self.project.clone(), book: self.book.clone(), review: review.into() }
+    }
+}
+
+impl example::library::v1::Review {
+    /// Parses the `name` field as a ReviewResourceName.
+    pub fn resource_name(&self) -> Result<ReviewResourceName, ResourceNameError> {
+        self.name.parse()
+    }
+
+    /// Sets the `name` field from a ReviewResourceName.
+    pub fn set_resource_name(&mut self, name: &ReviewResourceName) {
+        self.name = name.to_string();
+    }
+}
+
+/// Multi-pattern resource name for library.googleapis.com/Publisher
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PublisherResourceName {
+    Publishers(PublishersPublisherResourceName),
+    Organizations(OrganizationsPublisherResourceName),
+    Projects(ProjectsPublisherResourceName),
+}
+
+impl PublisherResourceName {
+    /// Returns the resource type.
+    pub fn resource_type(&self) -> &'static str {
+        "library.googleapis.com/Publisher"
+    }
+
+    /// Returns true if any field contains a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        match self {
+            PublisherResourceName::Publishers(inner) => inner.contains_wildcard(),
+            PublisherResourceName::Organizations(inner) => inner.contains_wildcard(),
+            PublisherResourceName::Projects(inner) => inner.contains_wildcard(),
+        }
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    /// Names of different patterns are matched segment by segment.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        match (self, concrete) {
+            (PublisherResourceName::Publishers(inner), PublisherResourceName::Publishers(concrete)) => inner.matches(concrete),
+            (PublisherResourceName::Organizations(inner), PublisherResourceName::Organizations(concrete)) => inner.matches(concrete),
+            (PublisherResourceName::Projects(inner), PublisherResourceName::Projects(concrete)) => inner.matches(concrete),
+            _ => aip_resourcename::wildcard_matches(&self.to_string(), &concrete.to_string()),
+        }
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(self, variables: &[&str]) -> Self {
+        match self {
+            PublisherResourceName::Publishers(inner) => PublisherResourceName::Publishers(inner.with_wildcards_in(variables)),
+            PublisherResourceName::Organizations(inner) => PublisherResourceName::Organizations(inner.with_wildcards_in(variables)),
+            PublisherResourceName::Projects(inner) => PublisherResourceName::Projects(inner.with_wildcards_in(variables)),
+        }
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        match self {
+            PublisherResourceName::Publishers(inner) => inner.validate_concrete(),
+            PublisherResourceName::Organizations(inner) => inner.validate_concrete(),
+            PublisherResourceName::Projects(inner) => inner.validate_concrete(),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        match self {
+            PublisherResourceName::Publishers(inner) => inner.validate(),
+            PublisherResourceName::Organizations(inner) => inner.validate(),
+            PublisherResourceName::Projects(inner) => inner.validate(),
+        }
+    }
+
+    /// Borrows the resource name as a PublisherResourceNameRef.
+    pub fn as_borrowed(&self) -> PublisherResourceNameRef<'_> {
+        match self {
+            PublisherResourceName::Publishers(inner) => PublisherResourceNameRef::Publishers(inner.as_borrowed()),
+            PublisherResourceName::Organizations(inner) => PublisherResourceNameRef::Organizations(inner.as_borrowed()),
+            PublisherResourceName::Projects(inner) => PublisherResourceNameRef::Projects(inner.as_borrowed()),
+        }
+    }
+
+    /// Returns true if `other` is the name of a resource nested under this one.
+    pub fn is_ancestor_of(&self, other: &str) -> bool {
+        ResourceName::is_ancestor_of(self, other)
+    }
+
+    /// Returns true if the name matches a glob such as `projects/acme/books/*`, where `*`
+    /// matches one segment and a final `**` any suffix. Globs that cannot match names of
+    /// this type match nothing.
+    pub fn matches_glob(&self, glob: &str) -> bool {
+        ResourceName::matches_glob(self, glob)
+    }
+
+    /// Returns the name of the service the resource belongs to.
+    pub fn service_name(&self) -> &'static str {
+        match self {
+            PublisherResourceName::Publishers(inner) => inner.service_name(),
+            PublisherResourceName::Organizations(inner) => inner.service_name(),
+            PublisherResourceName::Projects(inner) => inner.service_name(),
+        }
+    }
+
+    /// Returns the full resource name, prefixed with the service name.
+    pub fn to_full_name(&self) -> String {
+        match self {
+            PublisherResourceName::Publishers(inner) => inner.to_full_name(),
+            PublisherResourceName::Organizations(inner) => inner.to_full_name(),
+            PublisherResourceName::Projects(inner) => inner.to_full_name(),
+        }
+    }
+
+    /// Parses a full resource name, which must belong to the service of the resource.
+    pub fn from_full_name(full_name: &str) -> Result<Self, ResourceNameError> {
+        strip_service(full_name, "library.googleapis.com")?.parse()
+    }
+
+    /// Returns the API version of the resource, from its proto package.
+    pub fn api_version(&self) -> &'static str {
+        match self {
+            PublisherResourceName::Publishers(inner) => inner.api_version(),
+            PublisherResourceName::Organizations(inner) => inner.api_version(),
+            PublisherResourceName::Projects(inner) => inner.api_version(),
+        }
+    }
+
+    /// Returns the resource URI in the given API version, or else in the one of the resource.
+    pub fn to_uri(&self, version: Option<&str>) -> String {
+        match self {
+            PublisherResourceName::Publishers(inner) => inner.to_uri(version),
+            PublisherResourceName::Organizations(inner) => inner.to_uri(version),
+            PublisherResourceName::Projects(inner) => inner.to_uri(version),
+        }
+    }
+
+    /// Parses a resource URI of the service of the resource, and returns the name with the
+    /// API version of the URI.
+    pub fn from_uri(uri: &str) -> Result<(Self, &str), ResourceNameError> {
+        let (version, name) = aip_resourcename::split_uri(uri, "library.googleapis.com")?;
+        Ok((name.parse()?, version))
+    }
+
+}
+
+impl fmt::Display for PublisherResourceName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PublisherResourceName::Publishers(inner) => write!(f, "{inner}"),
+            PublisherResourceName::Organizations(inner) => write!(f, "{inner}"),
+            PublisherResourceName::Projects(inner) => write!(f, "{inner}"),
+        }
+    }
+}
+
+impl FromStr for PublisherResourceName {
+    type Err = ResourceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        PublisherResourceNameRef::parse_borrowed(s).map(PublisherResourceNameRef::to_owned)
+    }
+}
+
+impl ResourceName for PublisherResourceName {
+    const TYPE: &'static str = "library.googleapis.com/Publisher";
+    const PATTERNS: &'static [&'static str] = &["publishers/{publisher}", "organizations/{organization}/publishers/{publisher}", "projects/{project}/publishers/{publisher}"];
+    const SINGULAR: &'static str = "publisher";
+    const PLURAL: &'static str = "publishers";
+
+    fn validate(&self) -> Result<(), ResourceNameError> {
+        self.validate()
+    }
+
+    fn contains_wildcard(&self) -> bool {
+        self.contains_wildcard()
+    }
+}
+
+impl Serialize for PublisherResourceName {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            PublisherResourceName::Publishers(name) => name.serialize(serializer),
+            PublisherResourceName::Organizations(name) => name.serialize(serializer),
+            PublisherResourceName::Projects(name) => name.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for PublisherResourceName {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut variables = aip_resourcename::serde::Variables::deserialize(deserializer)?;
+        if let Some([publisher]) = variables.take(["publisher"]) {
+            return validated(PublisherResourceName::Publishers(PublishersPublisherResourceName { publisher }));
+        }
+        if let Some([organization, publisher]) = variables.take(["organization", "publisher"]) {
+            return validated(PublisherResourceName::Organizations(OrganizationsPublisherResourceName { organization, publisher }));
+        }
+        if let Some([project, publisher]) = variables.take(["project", "publisher"]) {
+            return validated(PublisherResourceName::Projects(ProjectsPublisherResourceName { project, publisher }));
+        }
+        Err(variables.no_match(&[&["publisher"], &["organization", "publisher"], &["project", "publisher"]]))
+    }
+}
+
+/// Borrowed view of a PublisherResourceName that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PublisherResourceNameRef<'a> {
+    Publishers(PublishersPublisherResourceNameRef<'a>),
+    Organizations(OrganizationsPublisherResourceNameRef<'a>),
+    Projects(ProjectsPublisherResourceNameRef<'a>),
+}
+
+impl<'a> PublisherResourceNameRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut parts = s.split('/');
+        match parts.next() {
+            Some("publishers") => {
+                match parts.next() {
+                    Some(part1) => {
+                        match parts.next() {
+                            Some(part) => Err(ResourceNameError::no_matching_pattern("publishers/{publisher}", 2, Some(part))),
+                            None => PublishersPublisherResourceNameRef::from_segments(part1).map(Self::Publishers),
+                        }
+                    }
+                    None => Err(ResourceNameError::no_matching_pattern("publishers/{publisher}", 1, None)),
+                }
+            }
+            Some("organizations") => {
+                match parts.next() {
+                    Some(part1) => {
+                        match parts.next() {
+                            Some("publishers") => {
+                                match parts.next() {
+                                    Some(part3) => {
+                                        match parts.next() {
+                                            Some(part) => Err(ResourceNameError::no_matching_pattern("organizations/{organization}/publishers/{publisher}", 4, Some(part))),
+                                            None => OrganizationsPublisherResourceNameRef::from_segments(part1, part3).map(Self::Organizations),
+                                        }
+                                    }
+                                    None => Err(ResourceNameError::no_matching_pattern("organizations/{organization}/publishers/{publisher}", 3, None)),
+                                }
+                            }
+                            Some(part) => Err(ResourceNameError::no_matching_pattern("organizations/{organization}/publishers/{publisher}", 2, Some(part))),
+                            None => Err(ResourceNameError::no_matching_pattern("organizations/{organization}/publishers/{publisher}", 2, None)),
+                        }
+                    }
+                    None => Err(ResourceNameError::no_matching_pattern("organizations/{organization}/publishers/{publisher}", 1, None)),
+                }
+            }
+            Some("projects") => {
+                match parts.next() {
+                    Some(part1) => {
+                        match parts.next() {
+                            Some("publishers") => {
+                                match parts.next() {
+                                    Some(part3) => {
+                                        match parts.next() {
+                                            Some(part) => Err(ResourceNameError::no_matching_pattern("projects/{project}/publishers/{publisher}", 4, Some(part))),
+                                            None => ProjectsPublisherResourceNameRef::from_segments(part1, part3).map(Self::Projects),
+                                        }
+                                    }
+                                    None => Err(ResourceNameError::no_matching_pattern("projects/{project}/publishers/{publisher}", 3, None)),
+                                }
+                            }
+                            Some(part) => Err(ResourceNameError::no_matching_pattern("projects/{project}/publishers/{publisher}", 2, Some(part))),
+                            None => Err(ResourceNameError::no_matching_pattern("projects/{project}/publishers/{publisher}", 2, None)),
+                        }
+                    }
+                    None => Err(ResourceNameError::no_matching_pattern("projects/{project}/publishers/{publisher}", 1, None)),
+                }
+            }
+            Some(part) => Err(ResourceNameError::no_matching_pattern("publishers/{publisher}", 0, Some(part))),
+            None => Err(ResourceNameError::no_matching_pattern("publishers/{publisher}", 0, None)),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        match self {
+            PublisherResourceNameRef::Publishers(inner) => inner.validate(),
+            PublisherResourceNameRef::Organizations(inner) => inner.validate(),
+            PublisherResourceNameRef::Projects(inner) => inner.validate(),
+        }
+    }
+
+    /// Converts the borrowed view into an owned PublisherResourceName.
+    pub fn to_owned(self) -> PublisherResourceName {
+        match self {
+            PublisherResourceNameRef::Publishers(inner) => PublisherResourceName::Publishers(inner.to_owned()),
+            PublisherResourceNameRef::Organizations(inner) => PublisherResourceName::Organizations(inner.to_owned()),
+            PublisherResourceNameRef::Projects(inner) => PublisherResourceName::Projects(inner.to_owned()),
+        }
+    }
+}
+
+impl fmt::Display for PublisherResourceNameRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PublisherResourceNameRef::Publishers(inner) => write!(f, "{inner}"),
+            PublisherResourceNameRef::Organizations(inner) => write!(f, "{inner}"),
+            PublisherResourceNameRef::Projects(inner) => write!(f, "{inner}"),
+        }
+    }
+}
+
+/// Parses a resource name string and returns the appropriate type.
+pub fn parse_publisher_resource_name(name: &str) -> Result<PublisherResourceName, ResourceNameError> {
+    PublisherResourceName::from_str(name)
+}
+
+/// Resource name for library.googleapis.com/Publisher
+///
+/// The original pattern of the resource, from before it had several patterns.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PublishersPublisherResourceName {
+    pub publisher: String,
+}
+
+impl PublishersPublisherResourceName {
+    /// Creates a new PublishersPublisherResourceName.
+    pub fn new(
+        publisher: impl Into<String>
+    ) -> Self {
+        Self {
+            publisher: publisher.into(),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        self.as_borrowed().validate()
+    }
+
+    /// Borrows the resource name as a PublishersPublisherResourceNameRef.
+    pub fn as_borrowed(&self) -> PublishersPublisherResourceNameRef<'_> {
+        PublishersPublisherResourceNameRef {
+            publisher: &self.publisher,
+        }
+    }
+
+    /// Returns the resource type.
+    pub fn resource_type(&self) -> &'static str {
+        "library.googleapis.com/Publisher"
+    }
+
+    /// Returns the name of the service the resource belongs to.
+    pub fn service_name(&self) -> &'static str {
+        "library.googleapis.com"
+    }
+
+    /// Returns the full resource name, prefixed with the service name.
+    pub fn to_full_name(&self) -> String {
+        format!("//{}/{}", self.service_name(), self)
+    }
+
+    /// Parses a full resource name, which must belong to the service of the resource.
+    pub fn from_full_name(full_name: &str) -> Result<Self, ResourceNameError> {
+        strip_service(full_name, "library.googleapis.com")?.parse()
+    }
+
+    /// Returns the API version of the resource, from its proto package.
+    pub fn api_version(&self) -> &'static str {
+        "v1"
+    }
+
+    /// Returns the resource URI in the given API version, or else in the one of the resource.
+    pub fn to_uri(&self, version: Option<&str>) -> String {
+        let version = version.unwrap_or(self.api_version());
+        format!("https://{}/{}/{}", self.service_name(), version, self)
+    }
+
+    /// Parses a resource URI of the service of the resource, and returns the name with the
+    /// API version of the URI.
+    pub fn from_uri(uri: &str) -> Result<(Self, &str), ResourceNameError> {
+        let (version, name) = aip_resourcename::split_uri(uri, "library.googleapis.com")?;
+        Ok((name.parse()?, version))
+    }
+
+    /// Returns true if any field contains a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        self.publisher == "-"
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        self.publisher == "-" || self.publisher == concrete.publisher
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(mut self, variables: &[&str]) -> Self {
+        if variables.contains(&"publisher") {
+            self.publisher = "-".to_string();
+        }
+        self
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        self.validate()?;
+        reject_wildcard("publisher", &self.publisher)?;
+        Ok(())
+    }
+
+    /// Returns true if `other` is the name of a resource nested under this one.
+    pub fn is_ancestor_of(&self, other: &str) -> bool {
+        ResourceName::is_ancestor_of(self, other)
+    }
+
+    /// Returns true if the name matches a glob such as `projects/acme/books/*`, where `*`
+    /// matches one segment and a final `**` any suffix. Globs that cannot match names of
+    /// this type match nothing.
+    pub fn matches_glob(&self, glob: &str) -> bool {
+        ResourceName::matches_glob(self, glob)
+    }
+
+}
+
+impl fmt::Display for PublishersPublisherResourceName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_borrowed(), f)
+    }
+}
+
+impl FromStr for PublishersPublisherResourceName {
+    type Err = ResourceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        PublishersPublisherResourceNameRef::parse_borrowed(s).map(PublishersPublisherResourceNameRef::to_owned)
+    }
+}
+
+impl ResourceName for PublishersPublisherResourceName {
+    const TYPE: &'static str = "library.googleapis.com/Publisher";
+    const PATTERNS: &'static [&'static str] = &["publishers/{publisher}"];
+    const SINGULAR: &'static str = "publisher";
+    const PLURAL: &'static str = "publishers";
+
+    fn validate(&self) -> Result<(), ResourceNameError> {
+        self.validate()
+    }
+
+    fn contains_wildcard(&self) -> bool {
+        self.contains_wildcard()
+    }
+}
+
+impl Serialize for PublishersPublisherResourceName {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_variables(serializer, [("publisher", &self.publisher)])
+    }
+}
+
+impl<'de> Deserialize<'de> for PublishersPublisherResourceName {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let [publisher] = deserialize_variables(deserializer, ["publisher"])?;
+        validated(Self { publisher })
+    }
+}
+
+/// Borrowed view of a PublishersPublisherResourceName that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PublishersPublisherResourceNameRef<'a> {
+    pub publisher: &'a str,
+}
+
+impl<'a> PublishersPublisherResourceNameRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut scanner = Scanner::new(s, 2);
+        scanner.literal("publishers")?;
+        let publisher = scanner.variable()?;
+        scanner.finish()?;
+        Self::from_segments(publisher)
+    }
+
+    /// Creates a PublishersPublisherResourceNameRef from parsed segments and validates it.
+    fn from_segments(publisher: &'a str) -> Result<Self, ResourceNameError> {
+        let name = Self { publisher };
+        name.validate()?;
+        Ok(name)
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        validate_segment("publisher", self.publisher)?;
+        Ok(())
+    }
+
+    /// Converts the borrowed view into an owned PublishersPublisherResourceName.
+    pub fn to_owned(self) -> PublishersPublisherResourceName {
+        PublishersPublisherResourceName {
+            publisher: self.publisher.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for PublishersPublisherResourceNameRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "publishers/{publisher}"
+            , publisher = self.publisher
+        )
+    }
+}
+
+/// Resource name for library.googleapis.com/Publisher
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OrganizationsPublisherResourceName {
+    pub organization: String,
+    pub publisher: String,
+}
+
+impl OrganizationsPublisherResourceName {
+    /// Creates a new OrganizationsPublisherResourceName.
+    pub fn new(
+        organization: impl Into<String>,
+        publisher: impl Into<String>
+    ) -> Self {
+        Self {
+            organization: organization.into(),
+            publisher: publisher.into(),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        self.as_borrowed().validate()
+    }
+
+    /// Borrows the resource name as a OrganizationsPublisherResourceNameRef.
+    pub fn as_borrowed(&self) -> OrganizationsPublisherResourceNameRef<'_> {
+        OrganizationsPublisherResourceNameRef {
+            organization: &self.organization,
+            publisher: &self.publisher,
+        }
+    }
+
+    /// Returns the resource type.
+    pub fn resource_type(&self) -> &'static str {
+        "library.googleapis.com/Publisher"
+    }
+
+    /// Returns the name of the service the resource belongs to.
+    pub fn service_name(&self) -> &'static str {
+        "library.googleapis.com"
+    }
+
+    /// Returns the full resource name, prefixed with the service name.
+    pub fn to_full_name(&self) -> String {
+        format!("//{}/{}", self.service_name(), self)
+    }
+
+    /// Parses a full resource name, which must belong to the service of the resource.
+    pub fn from_full_name(full_name: &str) -> Result<Self, ResourceNameError> {
+        strip_service(full_name, "library.googleapis.com")?.parse()
+    }
+
+    /// Returns the API version of the resource, from its proto package.
+    pub fn api_version(&self) -> &'static str {
+        "v1"
+    }
+
+    /// Returns the resource URI in the given API version, or else in the one of the resource.
+    pub fn to_uri(&self, version: Option<&str>) -> String {
+        let version = version.unwrap_or(self.api_version());
+        format!("https://{}/{}/{}", self.service_name(), version, self)
+    }
+
+    /// Parses a resource URI of the service of the resource, and returns the name with the
+    /// API version of the URI.
+    pub fn from_uri(uri: &str) -> Result<(Self, &str), ResourceNameError> {
+        let (version, name) = aip_resourcename::split_uri(uri, "library.googleapis.com")?;
+        Ok((name.parse()?, version))
+    }
+
+    /// Returns true if any field contains a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        self.organization == "-" || self.publisher == "-"
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        (self.organization == "-" || self.organization == concrete.organization)
+            && (self.publisher == "-" || self.publisher == concrete.publisher)
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(mut self, variables: &[&str]) -> Self {
+        if variables.contains(&"organization") {
+            self.organization = "-".to_string();
+        }
+        if variables.contains(&"publisher") {
+            self.publisher = "-".to_string();
+        }
+        self
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        self.validate()?;
+        reject_wildcard("organization", &self.organization)?;
+        reject_wildcard("publisher", &self.publisher)?;
+        Ok(())
+    }
+
+    /// Returns true if `other` is the name of a resource nested under this one.
+    pub fn is_ancestor_of(&self, other: &str) -> bool {
+        ResourceName::is_ancestor_of(self, other)
+    }
+
+    /// Returns true if the name matches a glob such as `projects/acme/books/*`, where `*`
+    /// matches one segment and a final `**` any suffix. Globs that cannot match names of
+    /// this type match nothing.
+    pub fn matches_glob(&self, glob: &str) -> bool {
+        ResourceName::matches_glob(self, glob)
+    }
+
+}
+
+impl fmt::Display for OrganizationsPublisherResourceName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_borrowed(), f)
+    }
+}
+
+impl FromStr for OrganizationsPublisherResourceName {
+    type Err = ResourceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        OrganizationsPublisherResourceNameRef::parse_borrowed(s).map(OrganizationsPublisherResourceNameRef::to_owned)
+    }
+}
+
+impl ResourceName for OrganizationsPublisherResourceName {
+    const TYPE: &'static str = "library.googleapis.com/Publisher";
+    const PATTERNS: &'static [&'static str] = &["organizations/{organization}/publishers/{publisher}"];
+    const SINGULAR: &'static str = "publisher";
+    const PLURAL: &'static str = "publishers";
+
+    fn validate(&self) -> Result<(), ResourceNameError> {
+        self.validate()
+    }
+
+    fn contains_wildcard(&self) -> bool {
+        self.contains_wildcard()
+    }
+}
+
+impl Serialize for OrganizationsPublisherResourceName {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_variables(serializer, [("organization", &self.organization), ("publisher", &self.publisher)])
+    }
+}
+
+impl<'de> Deserialize<'de> for OrganizationsPublisherResourceName {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let [organization, publisher] = deserialize_variables(deserializer, ["organization", "publisher"])?;
+        validated(Self { organization, publisher })
+    }
+}
+
+/// Borrowed view of a OrganizationsPublisherResourceName that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OrganizationsPublisherResourceNameRef<'a> {
+    pub organization: &'a str,
+    pub publisher: &'a str,
+}
+
+impl<'a> OrganizationsPublisherResourceNameRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut scanner = Scanner::new(s, 4);
+        scanner.literal("organizations")?;
+        let organization = scanner.variable()?;
+        scanner.literal("publishers")?;
+        let publisher = scanner.variable()?;
+        scanner.finish()?;
+        Self::from_segments(organization, publisher)
+    }
+
+    /// Creates a OrganizationsPublisherResourceNameRef from parsed segments and validates it.
+    fn from_segments(organization: &'a str, publisher: &'a str) -> Result<Self, ResourceNameError> {
+        let name = Self { organization, publisher };
+        name.validate()?;
+        Ok(name)
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        validate_segment("organization", self.organization)?;
+        validate_segment("publisher", self.publisher)?;
+        Ok(())
+    }
+
+    /// Converts the borrowed view into an owned OrganizationsPublisherResourceName.
+    pub fn to_owned(self) -> OrganizationsPublisherResourceName {
+        OrganizationsPublisherResourceName {
+            organization: self.organization.to_string(),
+            publisher: self.publisher.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for OrganizationsPublisherResourceNameRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "organizations/{organization}/publishers/{publisher}"
+            , organization = self.organization
+            , publisher = self.publisher
+        )
+    }
+}
+
+/// Resource name for library.googleapis.com/Publisher
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ProjectsPublisherResourceName {
+    pub project: String,
+    pub publisher: String,
+}
+
+impl ProjectsPublisherResourceName {
+    /// Creates a new ProjectsPublisherResourceName.
+    pub fn new(
+        project: impl Into<String>,
+        publisher: impl Into<String>
+    ) -> Self {
+        Self {
+            project: project.into(),
+            publisher: publisher.into(),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        self.as_borrowed().validate()
+    }
+
+    /// Borrows the resource name as a ProjectsPublisherResourceNameRef.
+    pub fn as_borrowed(&self) -> ProjectsPublisherResourceNameRef<'_> {
+        ProjectsPublisherResourceNameRef {
+            project: &self.project,
+            publisher: &self.publisher,
+        }
+    }
+
+    /// Returns the resource type.
+    pub fn resource_type(&self) -> &'static str {
+        "library.googleapis.com/Publisher"
+    }
+
+    /// Returns the name of the service the resource belongs to.
+    pub fn service_name(&self) -> &'static str {
+        "library.googleapis.com"
+    }
+
+    /// Returns the full resource name, prefixed with the service name.
+    pub fn to_full_name(&self) -> String {
+        format!("//{}/{}", self.service_name(), self)
+    }
+
+    /// Parses a full resource name, which must belong to the service of the resource.
+    pub fn from_full_name(full_name: &str) -> Result<Self, ResourceNameError> {
+        strip_service(full_name, "library.googleapis.com")?.parse()
+    }
+
+    /// Returns the API version of the resource, from its proto package.
+    pub fn api_version(&self) -> &'static str {
+        "v1"
+    }
+
+    /// Returns the resource URI in the given API version, or else in the one of the resource.
+    pub fn to_uri(&self, version: Option<&str>) -> String {
+        let version = version.unwrap_or(self.api_version());
+        format!("https://{}/{}/{}", self.service_name(), version, self)
+    }
+
+    /// Parses a resource URI of the service of the resource, and returns the name with the
+    /// API version of the URI.
+    pub fn from_uri(uri: &str) -> Result<(Self, &str), ResourceNameError> {
+        let (version, name) = aip_resourcename::split_uri(uri, "library.googleapis.com")?;
+        Ok((name.parse()?, version))
+    }
+
+    /// Returns true if any field contains a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        self.project == "-" || self.publisher == "-"
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        (self.project == "-" || self.project == concrete.project)
+            && (self.publisher == "-" || self.publisher == concrete.publisher)
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(mut self, variables: &[&str]) -> Self {
+        if variables.contains(&"project") {
+            self.project = "-".to_string();
+        }
+        if variables.contains(&"publisher") {
+            self.publisher = "-".to_string();
+        }
+        self
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        self.validate()?;
+        reject_wildcard("project", &self.project)?;
+        reject_wildcard("publisher", &self.publisher)?;
+        Ok(())
+    }
+
+    /// Returns true if `other` is the name of a resource nested under this one.
+    pub fn is_ancestor_of(&self, other: &str) -> bool {
+        ResourceName::is_ancestor_of(self, other)
+    }
+
+    /// Returns true if the name matches a glob such as `projects/acme/books/*`, where `*`
+    /// matches one segment and a final `**` any suffix. Globs that cannot match names of
+    /// this type match nothing.
+    pub fn matches_glob(&self, glob: &str) -> bool {
+        ResourceName::matches_glob(self, glob)
+    }
+
+}
+
+impl fmt::Display for ProjectsPublisherResourceName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_borrowed(), f)
+    }
+}
+
+impl FromStr for ProjectsPublisherResourceName {
+    type Err = ResourceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ProjectsPublisherResourceNameRef::parse_borrowed(s).map(ProjectsPublisherResourceNameRef::to_owned)
+    }
+}
+
+impl ResourceName for ProjectsPublisherResourceName {
+    const TYPE: &'static str = "library.googleapis.com/Publisher";
+    const PATTERNS: &'static [&'static str] = &["projects/{project}/publishers/{publisher}"];
+    const SINGULAR: &'static str = "publisher";
+    const PLURAL: &'static str = "publishers";
+
+    fn validate(&self) -> Result<(), ResourceNameError> {
+        self.validate()
+    }
+
+    fn contains_wildcard(&self) -> bool {
+        self.contains_wildcard()
+    }
+}
+
+impl Serialize for ProjectsPublisherResourceName {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_variables(serializer, [("project", &self.project), ("publisher", &self.publisher)])
+    }
+}
+
+impl<'de> Deserialize<'de> for ProjectsPublisherResourceName {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let [project, publisher] = deserialize_variables(deserializer, ["project", "publisher"])?;
+        validated(Self { project, publisher })
+    }
+}
+
+/// Borrowed view of a ProjectsPublisherResourceName that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ProjectsPublisherResourceNameRef<'a> {
+    pub project: &'a str,
+    pub publisher: &'a str,
+}
+
+impl<'a> ProjectsPublisherResourceNameRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut scanner = Scanner::new(s, 4);
+        scanner.literal("projects")?;
+        let project = scanner.variable()?;
+        scanner.literal("publishers")?;
+        let publisher = scanner.variable()?;
+        scanner.finish()?;
+        Self::from_segments(project, publisher)
     }
 
-    /// Returns the resource type.
-    pub fn resource_type(&self) -> &'static str {
-        "library.googleapis.com/Review"
+    /// Creates a ProjectsPublisherResourceNameRef from parsed segments and validates it.
+    fn from_segments(project: &'a str, publisher: &'a str) -> Result<Self, ResourceNameError> {
+        let name = Self { project, publisher };
+        name.validate()?;
+        Ok(name)
     }
 
-    /// Returns true if any field contains a wildcard.
-    pub fn contains_wildcard(&self) -> bool {
-        self.project == "-" || self.book == "-" || self.review == "-"
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        validate_segment("project", self.project)?;
+        validate_segment("publisher", self.publisher)?;
+        Ok(())
     }
 
+    /// Converts the borrowed view into an owned ProjectsPublisherResourceName.
+    pub fn to_owned(self) -> ProjectsPublisherResourceName {
+        ProjectsPublisherResourceName {
+            project: self.project.to_string(),
+            publisher: self.publisher.to_string(),
+        }
+    }
 }
 
-impl fmt::Display for ReviewResourceName {
+impl fmt::Display for ProjectsPublisherResourceNameRef<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "projects/{project}/books/{book}/reviews/{review}"
+        write!(f, "projects/{project}/publishers/{publisher}"
             , project = self.project
-            , book = self.book
-            , review = self.review
+            , publisher = self.publisher
         )
     }
 }
 
-impl FromStr for ReviewResourceName {
-    type Err = String;
+/// Resource name of the original pattern `publishers/{publisher}` of library.googleapis.com/Publisher,
+/// from before the resource had several patterns.
+#[deprecated(note = "library.googleapis.com/Publisher has several patterns, use PublisherResourceName")]
+pub type SinglePatternPublisherResourceName = PublishersPublisherResourceName;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts: Vec<&str> = s.split('/').collect();
-        if parts.len() != 6 {
-            return Err(format!("expected {expected_parts} parts, got {}", parts.len(), expected_parts = 6));
-        }
-        if parts[0] != "projects" {
-            return Err(format!("expected 'projects' at position 0, got '{part}'", part = parts[0]));
-        }
-        let project = parts[1].to_string();
-        if parts[2] != "books" {
-            return Err(format!("expected 'books' at position 2, got '{part}'", part = parts[2]));
-        }
-        let book = parts[3].to_string();
-        if parts[4] != "reviews" {
-            return Err(format!("expected 'reviews' at position 4, got '{part}'", part = parts[4]));
-        }
-        let review = parts[5].to_string();
-        let result = Self {
-            project,
-            book,
-            review,
-        };
-        result.validate()?;
-        Ok(result)
+impl From<PublishersPublisherResourceName> for PublisherResourceName {
+    fn from(name: PublishersPublisherResourceName) -> Self {
+        PublisherResourceName::Publishers(name)
     }
 }
 
-/// Multi-pattern resource name for library.googleapis.com/Publisher
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum PublisherResourceName {
-    Publishers(PublishersPublisherResourceName),
-    Organizations(OrganizationsPublisherResourceName),
-    Projects(ProjectsPublisherResourceName),
+impl TryFrom<PublisherResourceName> for PublishersPublisherResourceName {
+    type Error = ResourceNameError;
+
+    /// Converts names of the original pattern `publishers/{publisher}`, and fails for the others.
+    fn try_from(name: PublisherResourceName) -> Result<Self, Self::Error> {
+        match name {
+            PublisherResourceName::Publishers(name) => Ok(name),
+            name => name.to_string().parse(),
+        }
+    }
 }
 
 impl PublisherResourceName {
-    /// Returns the resource type.
-    pub fn resource_type(&self) -> &'static str {
-        "library.googleapis.com/Publisher"
+    /// Creates a new PublisherResourceName with the original pattern `publishers/{publisher}`.
+    #[deprecated(note = "library.googleapis.com/Publisher has several patterns, use PublisherResourceName::Publishers")]
+    pub fn new(publisher: impl Into<String>) -> Self {
+        PublisherResourceName::Publishers(PublishersPublisherResourceName::new(publisher))
     }
+}
+
+/// Parent of a PublisherResourceName.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PublisherParent {
+    Organizations(OrganizationsPublisherParent),
+    Projects(ProjectsPublisherParent),
+}
 
+impl PublisherParent {
     /// Returns true if any field contains a wildcard.
     pub fn contains_wildcard(&self) -> bool {
         match self {
-            PublisherResourceName::Publishers(inner) => inner.contains_wildcard(),
-            PublisherResourceName::Organizations(inner) => inner.contains_wildcard(),
-            PublisherResourceName::Projects(inner) => inner.contains_wildcard(),
+            PublisherParent::Organizations(inner) => inner.contains_wildcard(),
+            PublisherParent::Projects(inner) => inner.contains_wildcard(),
+        }
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    /// Names of different patterns are matched segment by segment.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        match (self, concrete) {
+            (PublisherParent::Organizations(inner), PublisherParent::Organizations(concrete)) => inner.matches(concrete),
+            (PublisherParent::Projects(inner), PublisherParent::Projects(concrete)) => inner.matches(concrete),
+            _ => aip_resourcename::wildcard_matches(&self.to_string(), &concrete.to_string()),
+        }
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(self, variables: &[&str]) -> Self {
+        match self {
+            PublisherParent::Organizations(inner) => PublisherParent::Organizations(inner.with_wildcards_in(variables)),
+            PublisherParent::Projects(inner) => PublisherParent::Projects(inner.with_wildcards_in(variables)),
+        }
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        match self {
+            PublisherParent::Organizations(inner) => inner.validate_concrete(),
+            PublisherParent::Projects(inner) => inner.validate_concrete(),
         }
     }
 
     /// Validates the resource name.
-    pub fn validate(&self) -> Result<(), String> {
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
         match self {
-            PublisherResourceName::Publishers(inner) => inner.validate(),
-            PublisherResourceName::Organizations(inner) => inner.validate(),
-            PublisherResourceName::Projects(inner) => inner.validate(),
+            PublisherParent::Organizations(inner) => inner.validate(),
+            PublisherParent::Projects(inner) => inner.validate(),
+        }
+    }
+
+    /// Borrows the resource name as a PublisherParentRef.
+    pub fn as_borrowed(&self) -> PublisherParentRef<'_> {
+        match self {
+            PublisherParent::Organizations(inner) => PublisherParentRef::Organizations(inner.as_borrowed()),
+            PublisherParent::Projects(inner) => PublisherParentRef::Projects(inner.as_borrowed()),
         }
     }
 }
 
-impl fmt::Display for PublisherResourceName {
+impl fmt::Display for PublisherParent {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            PublisherResourceName::Publishers(inner) => write!(f, "{inner}"),
-            PublisherResourceName::Organizations(inner) => write!(f, "{inner}"),
-            PublisherResourceName::Projects(inner) => write!(f, "{inner}"),
+            PublisherParent::Organizations(inner) => write!(f, "{inner}"),
+            PublisherParent::Projects(inner) => write!(f, "{inner}"),
         }
     }
 }
 
-impl FromStr for PublisherResourceName {
-    type Err = String;
+impl FromStr for PublisherParent {
+    type Err = ResourceNameError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if let Ok(parsed) = PublishersPublisherResourceName::from_str(s) {
-            return Ok(PublisherResourceName::Publishers(parsed));
+        PublisherParentRef::parse_borrowed(s).map(PublisherParentRef::to_owned)
+    }
+}
+
+/// Borrowed view of a PublisherParent that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PublisherParentRef<'a> {
+    Organizations(OrganizationsPublisherParentRef<'a>),
+    Projects(ProjectsPublisherParentRef<'a>),
+}
+
+impl<'a> PublisherParentRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut parts = s.split('/');
+        match parts.next() {
+            Some("organizations") => {
+                match parts.next() {
+                    Some(part1) => {
+                        match parts.next() {
+                            Some(part) => Err(ResourceNameError::no_matching_pattern("organizations/{organization}", 2, Some(part))),
+                            None => OrganizationsPublisherParentRef::from_segments(part1).map(Self::Organizations),
+                        }
+                    }
+                    None => Err(ResourceNameError::no_matching_pattern("organizations/{organization}", 1, None)),
+                }
+            }
+            Some("projects") => {
+                match parts.next() {
+                    Some(part1) => {
+                        match parts.next() {
+                            Some(part) => Err(ResourceNameError::no_matching_pattern("projects/{project}", 2, Some(part))),
+                            None => ProjectsPublisherParentRef::from_segments(part1).map(Self::Projects),
+                        }
+                    }
+                    None => Err(ResourceNameError::no_matching_pattern("projects/{project}", 1, None)),
+                }
+            }
+            Some(part) => Err(ResourceNameError::no_matching_pattern("organizations/{organization}", 0, Some(part))),
+            None => Err(ResourceNameError::no_matching_pattern("organizations/{organization}", 0, None)),
         }
-        if let Ok(parsed) = OrganizationsPublisherResourceName::from_str(s) {
-            return Ok(PublisherResourceName::Organizations(parsed));
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        match self {
+            PublisherParentRef::Organizations(inner) => inner.validate(),
+            PublisherParentRef::Projects(inner) => inner.validate(),
         }
-        if let Ok(parsed) = ProjectsPublisherResourceName::from_str(s) {
-            return Ok(PublisherResourceName::Projects(parsed));
+    }
+
+    /// Converts the borrowed view into an owned PublisherParent.
+    pub fn to_owned(self) -> PublisherParent {
+        match self {
+            PublisherParentRef::Organizations(inner) => PublisherParent::Organizations(inner.to_owned()),
+            PublisherParentRef::Projects(inner) => PublisherParent::Projects(inner.to_owned()),
         }
-        Err("no matching pattern".to_string())
     }
 }
 
-/// Parses a resource name string and returns the appropriate type.
-pub fn parse_publisher_resource_name(name: &str) -> Result<PublisherResourceName, String> {
-    PublisherResourceName::from_str(name)
+impl fmt::Display for PublisherParentRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PublisherParentRef::Organizations(inner) => write!(f, "{inner}"),
+            PublisherParentRef::Projects(inner) => write!(f, "{inner}"),
+        }
+    }
 }
 
-/// Resource name for library.googleapis.com/Publisher
+/// Parent of a PublisherResourceName: `organizations/{organization}`.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PublishersPublisherResourceName {
-    pub publisher: String,
+pub struct OrganizationsPublisherParent {
+    pub organization: String,
 }
 
-impl PublishersPublisherResourceName {
-    /// Creates a new PublishersPublisherResourceName.
+impl OrganizationsPublisherParent {
+    /// Creates a new OrganizationsPublisherParent.
     pub fn new(
-        publisher: impl Into<String>
+        organization: impl Into<String>
     ) -> Self {
         Self {
-            publisher: publisher.into(),
+            organization: organization.into(),
         }
     }
 
     /// Validates the resource name.
-    pub fn validate(&self) -> Result<(), String> {
-        if self.publisher.is_empty() {
-            return Err("publisher: empty".to_string());
-        }
-        if self.publisher.contains('/') {
-            return Err("publisher: contains illegal character '/'".to_string());
-        }
-        Ok(())
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        self.as_borrowed().validate()
     }
 
-    /// Returns the resource type.
-    pub fn resource_type(&self) -> &'static str {
-        "library.googleapis.com/Publisher"
+    /// Borrows the resource name as a OrganizationsPublisherParentRef.
+    pub fn as_borrowed(&self) -> OrganizationsPublisherParentRef<'_> {
+        OrganizationsPublisherParentRef {
+            organization: &self.organization,
+        }
     }
 
     /// Returns true if any field contains a wildcard.
     pub fn contains_wildcard(&self) -> bool {
-        self.publisher == "-"
+        self.organization == "-"
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        self.organization == "-" || self.organization == concrete.organization
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(mut self, variables: &[&str]) -> Self {
+        if variables.contains(&"organization") {
+            self.organization = "-".to_string();
+        }
+        self
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        self.validate()?;
+        reject_wildcard("organization", &self.organization)?;
+        Ok(())
     }
 
 }
 
-impl fmt::Display for PublishersPublisherResourceName {
+impl fmt::Display for OrganizationsPublisherParent {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "publishers/{publisher}"
-            , publisher = self.publisher
-        )
+        fmt::Display::fmt(&self.as_borrowed(), f)
     }
 }
 
-impl FromStr for PublishersPublisherResourceName {
-    type Err = String;
+impl FromStr for OrganizationsPublisherParent {
+    type Err = ResourceNameError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts: Vec<&str> = s.split('/').collect();
-        if parts.len() != 2 {
-            return Err(format!("expected {expected_parts} parts, got {}", parts.len(), expected_parts = 2));
-        }
-        if parts[0] != "publishers" {
-            return Err(format!("expected 'publishers' at position 0, got '{part}'", part = parts[0]));
+        OrganizationsPublisherParentRef::parse_borrowed(s).map(OrganizationsPublisherParentRef::to_owned)
+    }
+}
+
+/// Borrowed view of a OrganizationsPublisherParent that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OrganizationsPublisherParentRef<'a> {
+    pub organization: &'a str,
+}
+
+impl<'a> OrganizationsPublisherParentRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut scanner = Scanner::new(s, 2);
+        scanner.literal("organizations")?;
+        let organization = scanner.variable()?;
+        scanner.finish()?;
+        Self::from_segments(organization)
+    }
+
+    /// Creates a OrganizationsPublisherParentRef from parsed segments and validates it.
+    fn from_segments(organization: &'a str) -> Result<Self, ResourceNameError> {
+        let name = Self { organization };
+        name.validate()?;
+        Ok(name)
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        validate_segment("organization", self.organization)?;
+        Ok(())
+    }
+
+    /// Converts the borrowed view into an owned OrganizationsPublisherParent.
+    pub fn to_owned(self) -> OrganizationsPublisherParent {
+        OrganizationsPublisherParent {
+            organization: self.organization.to_string(),
         }
-        let publisher = parts[1].to_string();
-        let result = Self {
-            publisher,
-        };
-        result.validate()?;
-        Ok(result)
     }
 }
 
-/// Resource name for library.googleapis.com/Publisher
+impl fmt::Display for OrganizationsPublisherParentRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "organizations/{organization}"
+            , organization = self.organization
+        )
+    }
+}
+
+/// Parent of a PublisherResourceName: `projects/{project}`.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct OrganizationsPublisherResourceName {
-    pub organization: String,
-    pub publisher: String,
+pub struct ProjectsPublisherParent {
+    pub project: String,
 }
 
-impl OrganizationsPublisherResourceName {
-    /// Creates a new OrganizationsPublisherResourceName.
+impl ProjectsPublisherParent {
+    /// Creates a new ProjectsPublisherParent.
     pub fn new(
-        organization: impl Into<String>,
-        publisher: impl Into<String>
+        project: impl Into<String>
     ) -> Self {
         Self {
-            organization: organization.into(),
-            publisher: publisher.into(),
+            project: project.into(),
         }
     }
 
     /// Validates the resource name.
-    pub fn validate(&self) -> Result<(), String> {
-        if self.organization.is_empty() {
-            return Err("organization: empty".to_string());
-        }
-        if self.organization.contains('/') {
-            return Err("organization: contains illegal character '/'".to_string());
-        }
-        if self.publisher.is_empty() {
-            return Err("publisher: empty".to_string());
-        }
-        if self.publisher.contains('/') {
-            return Err("publisher: contains illegal character '/'".to_string());
-        }
-        Ok(())
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        self.as_borrowed().validate()
     }
 
-    /// Returns the resource type.
-    pub fn resource_type(&self) -> &'static str {
-        "library.googleapis.com/Publisher"
+    /// Borrows the resource name as a ProjectsPublisherParentRef.
+    pub fn as_borrowed(&self) -> ProjectsPublisherParentRef<'_> {
+        ProjectsPublisherParentRef {
+            project: &self.project,
+        }
     }
 
     /// Returns true if any field contains a wildcard.
     pub fn contains_wildcard(&self) -> bool {
-        self.organization == "-" || self.publisher == "-"
+        self.project == "-"
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        self.project == "-" || self.project == concrete.project
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(mut self, variables: &[&str]) -> Self {
+        if variables.contains(&"project") {
+            self.project = "-".to_string();
+        }
+        self
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        self.validate()?;
+        reject_wildcard("project", &self.project)?;
+        Ok(())
     }
 
 }
 
-impl fmt::Display for OrganizationsPublisherResourceName {
+impl fmt::Display for ProjectsPublisherParent {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "organizations/{organization}/publishers/{publisher}"
-            , organization = self.organization
-            , publisher = self.publisher
-        )
+        fmt::Display::fmt(&self.as_borrowed(), f)
     }
 }
 
-impl FromStr for OrganizationsPublisherResourceName {
-    type Err = String;
+impl FromStr for ProjectsPublisherParent {
+    type Err = ResourceNameError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts: Vec<&str> = s.split('/').collect();
-        if parts.len() != 4 {
-            return Err(format!("expected {expected_parts} parts, got {}", parts.len(), expected_parts = 4));
-        }
-        if parts[0] != "organizations" {
-            return Err(format!("expected 'organizations' at position 0, got '{part}'", part = parts[0]));
-        }
-        let organization = parts[1].to_string();
-        if parts[2] != "publishers" {
-            return Err(format!("expected 'publishers' at position 2, got '{part}'", part = parts[2]));
-        }
-        let publisher = parts[3].to_string();
-        let result = Self {
-            organization,
-            publisher,
-        };
-        result.validate()?;
-        Ok(result)
+        ProjectsPublisherParentRef::parse_borrowed(s).map(ProjectsPublisherParentRef::to_owned)
     }
 }
 
-/// Resource name for library.googleapis.com/Publisher
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ProjectsPublisherResourceName {
-    pub project: String,
-    pub publisher: String,
+/// Borrowed view of a ProjectsPublisherParent that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ProjectsPublisherParentRef<'a> {
+    pub project: &'a str,
 }
 
-impl ProjectsPublisherResourceName {
-    /// Creates a new ProjectsPublisherResourceName.
-    pub fn new(
-        project: impl Into<String>,
-        publisher: impl Into<String>
-    ) -> Self {
-        Self {
-            project: project.into(),
-            publisher: publisher.into(),
-        }
+impl<'a> ProjectsPublisherParentRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut scanner = Scanner::new(s, 2);
+        scanner.literal("projects")?;
+        let project = scanner.variable()?;
+        scanner.finish()?;
+        Self::from_segments(project)
     }
 
-    /// Validates the resource name.
-    pub fn validate(&self) -> Result<(), String> {
-        if self.project.is_empty() {
-            return Err("project: empty".to_string());
-        }
-        if self.project.contains('/') {
-            return Err("project: contains illegal character '/'".to_string());
-        }
-        if self.publisher.is_empty() {
-            return Err("publisher: empty".to_string());
-        }
-        if self.publisher.contains('/') {
-            return Err("publisher: contains illegal character '/'".to_string());
-        }
-        Ok(())
+    /// Creates a ProjectsPublisherParentRef from parsed segments and validates it.
+    fn from_segments(project: &'a str) -> Result<Self, ResourceNameError> {
+        let name = Self { project };
+        name.validate()?;
+        Ok(name)
     }
 
-    /// Returns the resource type.
-    pub fn resource_type(&self) -> &'static str {
-        "library.googleapis.com/Publisher"
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        validate_segment("project", self.project)?;
+        Ok(())
     }
 
-    /// Returns true if any field contains a wildcard.
-    pub fn contains_wildcard(&self) -> bool {
-        self.project == "-" || self.publisher == "-"
+    /// Converts the borrowed view into an owned ProjectsPublisherParent.
+    pub fn to_owned(self) -> ProjectsPublisherParent {
+        ProjectsPublisherParent {
+            project: self.project.to_string(),
+        }
     }
-
 }
 
-impl fmt::Display for ProjectsPublisherResourceName {
+impl fmt::Display for ProjectsPublisherParentRef<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "projects/{project}/publishers/{publisher}"
+        write!(f, "projects/{project}"
             , project = self.project
-            , publisher = self.publisher
         )
     }
 }
 
-impl FromStr for ProjectsPublisherResourceName {
-    type Err = String;
+impl OrganizationsPublisherResourceName {
+    /// Returns the name of the parent of this resource.
+    pub fn parent(&self) -> OrganizationsPublisherParent {
+        OrganizationsPublisherParent {
+            organization: self.organization.clone(),
+        }
+    }
+}
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts: Vec<&str> = s.split('/').collect();
-        if parts.len() != 4 {
-            return Err(format!("expected {expected_parts} parts, got {}", parts.len(), expected_parts = 4));
+impl ProjectsPublisherResourceName {
+    /// Returns the name of the parent of this resource.
+    pub fn parent(&self) -> ProjectsPublisherParent {
+        ProjectsPublisherParent {
+            project: self.project.clone(),
         }
-        if parts[0] != "projects" {
-            return Err(format!("expected 'projects' at position 0, got '{part}'", part = parts[0]));
+    }
+}
+
+impl PublisherResourceName {
+    /// Returns the name of the parent of this resource, or None for top-level patterns.
+    pub fn parent(&self) -> Option<PublisherParent> {
+        match self {
+            PublisherResourceName::Publishers(_) => None,
+            PublisherResourceName::Organizations(inner) => Some(PublisherParent::Organizations(inner.parent())),
+            PublisherResourceName::Projects(inner) => Some(PublisherParent::Projects(inner.parent())),
         }
-        let project = parts[1].to_string();
-        if parts[2] != "publishers" {
-            return Err(format!("expected 'publishers' at position 2, got '{part}'", part = parts[2]));
+    }
+}
+
+impl OrganizationsPublisherParent {
+    /// Creates the name of a PublisherResourceName nested under this one.
+    pub fn publisher(&self, publisher: impl Into<String>) -> PublisherResourceName {
+        PublisherResourceName::Organizations(OrganizationsPublisherResourceName { organization: self.organization.clone(), publisher: publisher.into() })
+    }
+}
+
+impl ProjectsPublisherParent {
+    /// Creates the name of a PublisherResourceName nested under this one.
+    pub fn publisher(&self, publisher: impl Into<String>) -> PublisherResourceName {
+        PublisherResourceName::Projects(ProjectsPublisherResourceName { project: self.project.clone(), publisher: publisher.into() })
+    }
+}
+
+impl PublisherParent {
+    /// Creates the name of a PublisherResourceName nested under this one.
+    pub fn publisher(&self, publisher: impl Into<String>) -> PublisherResourceName {
+        match self {
+            PublisherParent::Organizations(inner) => inner.publisher(publisher),
+            PublisherParent::Projects(inner) => inner.publisher(publisher),
         }
-        let publisher = parts[3].to_string();
-        let result = Self {
-            project,
-            publisher,
-        };
-        result.validate()?;
-        Ok(result)
+    }
+}
+
+impl example::library::v1::Publisher {
+    /// Parses the `name` field as a PublisherResourceName.
+    pub fn resource_name(&self) -> Result<PublisherResourceName, ResourceNameError> {
+        self.name.parse()
+    }
+
+    /// Sets the `name` field from a PublisherResourceName.
+    pub fn set_resource_name(&mut self, name: &PublisherResourceName) {
+        self.name = name.to_string();
+    }
+}
+
+impl example::library::v1::ListBooksRequest {
+    /// Parses the `parent` field as a BookParent.
+    pub fn parent_resource(&self) -> Result<BookParent, ResourceNameError> {
+        self.parent.parse()
+    }
+}
+
+impl example::library::v1::GetBookRequest {
+    /// Parses the `name` field as a BookResourceName.
+    pub fn name_resource(&self) -> Result<BookResourceName, ResourceNameError> {
+        self.name.parse()
+    }
+}
+
+impl example::library::v1::GetResourceRequest {
+    /// Parses the `name` field as a UntypedResourceName.
+    pub fn name_resource(&self) -> Result<UntypedResourceName, ResourceNameError> {
+        self.name.parse()
     }
 }
 
@@ -25,20 +25,16 @@ impl TestResourceResourceName {
     }
 
     /// Validates the resource name.
-    pub fn validate(&self) -> Result<(), String> {
-        if self.project.is_empty() {
-            return Err("project: empty".to_string());
-        }
-        if self.project.contains('/') {
-            return Err("project: contains illegal character '/'".to_string());
-        }
-        if self.test.is_empty() {
-            return Err("test: empty".to_string());
-        }
-        if self.test.contains('/') {
-            return Err("test: contains illegal character '/'".to_string());
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        self.as_borrowed().validate()
+    }
+
+    /// Borrows the resource name as a TestResourceResourceNameRef.
+    pub fn as_borrowed(&self) -> TestResourceResourceNameRef<'_> {
+        TestResourceResourceNameRef {
+            project: &self.project,
+            test: &self.test,
         }
-        Ok(())
     }
 
     /// Returns the resource type.
@@ -46,14 +42,169 @@ impl TestResourceResourceName {
         "test.googleapis.com/TestResource"
     }
 
+    /// Returns the name of the service the resource belongs to.
+    pub fn service_name(&self) -> &'static str {
+        "test.googleapis.com"
+    }
+
+    /// Returns the full resource name, prefixed with the service name.
+    pub fn to_full_name(&self) -> String {
+        format!("//{}/{}", self.service_name(), self)
+    }
+
+    /// Parses a full resource name, which must belong to the service of the resource.
+    pub fn from_full_name(full_name: &str) -> Result<Self, ResourceNameError> {
+        strip_service(full_name, "test.googleapis.com")?.parse()
+    }
+
+    /// Returns the API version of the resource, from its proto package.
+    pub fn api_version(&self) -> &'static str {
+        "v1"
+    }
+
+    /// Returns the resource URI in the given API version, or else in the one of the resource.
+    pub fn to_uri(&self, version: Option<&str>) -> String {
+        let version = version.unwrap_or(self.api_version());
+        format!("https://{}/{}/{}", self.service_name(), version, self)
+    }
+
+    /// Parses a resource URI of the service of the resource, and returns the name with the
+    /// API version of the URI.
+    pub fn from_uri(uri: &str) -> Result<(Self, &str), ResourceNameError> {
+        let (version, name) = aip_resourcename::split_uri(uri, "test.googleapis.com")?;
+        Ok((name.parse()?, version))
+    }
+
     /// Returns true if any field contains a wildcard.
     pub fn contains_wildcard(&self) -> bool {
         self.project == "-" || self.test == "-"
     }
 
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        (self.project == "-" || self.project == concrete.project)
+            && (self.test == "-" || self.test == concrete.test)
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(mut self, variables: &[&str]) -> Self {
+        if variables.contains(&"project") {
+            self.project = "-".to_string();
+        }
+        if variables.contains(&"test") {
+            self.test = "-".to_string();
+        }
+        self
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        self.validate()?;
+        reject_wildcard("project", &self.project)?;
+        reject_wildcard("test", &self.test)?;
+        Ok(())
+    }
+
+    /// Returns true if `other` is the name of a resource nested under this one.
+    pub fn is_ancestor_of(&self, other: &str) -> bool {
+        ResourceName::is_ancestor_of(self, other)
+    }
+
+    /// Returns true if the name matches a glob such as `projects/acme/books/*`, where `*`
+    /// matches one segment and a final `**` any suffix. Globs that cannot match names of
+    /// this type match nothing.
+    pub fn matches_glob(&self, glob: &str) -> bool {
+        ResourceName::matches_glob(self, glob)
+    }
+
 }
 
 impl fmt::Display for TestResourceResourceName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_borrowed(), f)
+    }
+}
+
+impl FromStr for TestResourceResourceName {
+    type Err = ResourceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        TestResourceResourceNameRef::parse_borrowed(s).map(TestResourceResourceNameRef::to_owned)
+    }
+}
+
+impl ResourceName for TestResourceResourceName {
+    const TYPE: &'static str = "test.googleapis.com/TestResource";
+    const PATTERNS: &'static [&'static str] = &["projects/{project}/tests/{test}"];
+    const SINGULAR: &'static str = "test";
+    const PLURAL: &'static str = "tests";
+
+    fn validate(&self) -> Result<(), ResourceNameError> {
+        self.validate()
+    }
+
+    fn contains_wildcard(&self) -> bool {
+        self.contains_wildcard()
+    }
+}
+
+impl Serialize for TestResourceResourceName {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_variables(serializer, [("project", &self.project), ("test", &self.test)])
+    }
+}
+
+impl<'de> Deserialize<'de> for TestResourceResourceName {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let [project, test] = deserialize_variables(deserializer, ["project", "test"])?;
+        validated(Self { project, test })
+    }
+}
+
+/// Borrowed view of a TestResourceResourceName that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TestResourceResourceNameRef<'a> {
+    pub project: &'a str,
+    pub test: &'a str,
+}
+
+impl<'a> TestResourceResourceNameRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut scanner = Scanner::new(s, 4);
+        scanner.literal("projects")?;
+        let project = scanner.variable()?;
+        scanner.literal("tests")?;
+        let test = scanner.variable()?;
+        scanner.finish()?;
+        Self::from_segments(project, test)
+    }
+
+    /// Creates a TestResourceResourceNameRef from parsed segments and validates it.
+    fn from_segments(project: &'a str, test: &'a str) -> Result<Self, ResourceNameError> {
+        let name = Self { project, test };
+        name.validate()?;
+        Ok(name)
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        validate_segment("project", self.project)?;
+        validate_segment("test", self.test)?;
+        Ok(())
+    }
+
+    /// Converts the borrowed view into an owned TestResourceResourceName.
+    pub fn to_owned(self) -> TestResourceResourceName {
+        TestResourceResourceName {
+            project: self.project.to_string(),
+            test: self.test.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for TestResourceResourceNameRef<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "projects/{project}/tests/{test}"
             , project = self.project
@@ -62,28 +213,497 @@ impl fmt::Display for TestResourceResourceName {
     }
 }
 
-impl FromStr for TestResourceResourceName {
-    type Err = String;
+/// Parent of a TestResourceResourceName: `projects/{project}`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TestResourceParent {
+    pub project: String,
+}
+
+impl TestResourceParent {
+    /// Creates a new TestResourceParent.
+    pub fn new(
+        project: impl Into<String>
+    ) -> Self {
+        Self {
+            project: project.into(),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        self.as_borrowed().validate()
+    }
+
+    /// Borrows the resource name as a TestResourceParentRef.
+    pub fn as_borrowed(&self) -> TestResourceParentRef<'_> {
+        TestResourceParentRef {
+            project: &self.project,
+        }
+    }
+
+    /// Returns true if any field contains a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        self.project == "-"
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        self.project == "-" || self.project == concrete.project
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(mut self, variables: &[&str]) -> Self {
+        if variables.contains(&"project") {
+            self.project = "-".to_string();
+        }
+        self
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        self.validate()?;
+        reject_wildcard("project", &self.project)?;
+        Ok(())
+    }
+
+}
+
+impl fmt::Display for TestResourceParent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_borrowed(), f)
+    }
+}
+
+impl FromStr for TestResourceParent {
+    type Err = ResourceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        TestResourceParentRef::parse_borrowed(s).map(TestResourceParentRef::to_owned)
+    }
+}
+
+/// Borrowed view of a TestResourceParent that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TestResourceParentRef<'a> {
+    pub project: &'a str,
+}
+
+impl<'a> TestResourceParentRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut scanner = Scanner::new(s, 2);
+        scanner.literal("projects")?;
+        let project = scanner.variable()?;
+        scanner.finish()?;
+        Self::from_segments(project)
+    }
+
+    /// Creates a TestResourceParentRef from parsed segments and validates it.
+    fn from_segments(project: &'a str) -> Result<Self, ResourceNameError> {
+        let name = Self { project };
+        name.validate()?;
+        Ok(name)
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        validate_segment("project", self.project)?;
+        Ok(())
+    }
+
+    /// Converts the borrowed view into an owned TestResourceParent.
+    pub fn to_owned(self) -> TestResourceParent {
+        TestResourceParent {
+            project: self.project.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for TestResourceParentRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "projects/{project}"
+            , project = self.project
+        )
+    }
+}
+
+impl TestResourceResourceName {
+    /// Returns the name of the parent of this resource.
+    pub fn parent(&self) -> TestResourceParent {
+        TestResourceParent {
+            project: self.project.clone(),
+        }
+    }
+}
+
+impl TestResourceParent {
+    /// Creates the name of a TestResourceResourceName nested under this one.
+    pub fn test(&self, test: impl Into<String>) -> TestResourceResourceName {
+        TestResourceResourceName { project: self.project.clone(), test: test.into() }
+    }
+}
+
+impl example::optional::v1::TestResource {
+    /// Parses the `name` field as a TestResourceResourceName.
+    pub fn resource_name(&self) -> Result<TestResourceResourceName, ResourceNameError> {
+        self.name.parse()
+    }
+
+    /// Sets the `name` field from a TestResourceResourceName.
+    pub fn set_resource_name(&mut self, name: &TestResourceResourceName) {
+        self.name = name.to_string();
+    }
+}
+
+/// Resource name for test.googleapis.com/Draft
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DraftResourceName {
+    pub project: String,
+    pub draft: String,
+}
+
+impl DraftResourceName {
+    /// Creates a new DraftResourceName.
+    pub fn new(
+        project: impl Into<String>,
+        draft: impl Into<String>
+    ) -> Self {
+        Self {
+            project: project.into(),
+            draft: draft.into(),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        self.as_borrowed().validate()
+    }
+
+    /// Borrows the resource name as a DraftResourceNameRef.
+    pub fn as_borrowed(&self) -> DraftResourceNameRef<'_> {
+        DraftResourceNameRef {
+            project: &self.project,
+            draft: &self.draft,
+        }
+    }
+
+    /// Returns the resource type.
+    pub fn resource_type(&self) -> &'static str {
+        "test.googleapis.com/Draft"
+    }
+
+    /// Returns the name of the service the resource belongs to.
+    pub fn service_name(&self) -> &'static str {
+        "test.googleapis.com"
+    }
+
+    /// Returns the full resource name, prefixed with the service name.
+    pub fn to_full_name(&self) -> String {
+        format!("//{}/{}", self.service_name(), self)
+    }
+
+    /// Parses a full resource name, which must belong to the service of the resource.
+    pub fn from_full_name(full_name: &str) -> Result<Self, ResourceNameError> {
+        strip_service(full_name, "test.googleapis.com")?.parse()
+    }
+
+    /// Returns the API version of the resource, from its proto package.
+    pub fn api_version(&self) -> &'static str {
+        "v1"
+    }
+
+    /// Returns the resource URI in the given API version, or else in the one of the resource.
+    pub fn to_uri(&self, version: Option<&str>) -> String {
+        let version = version.unwrap_or(self.api_version());
+        format!("https://{}/{}/{}", self.service_name(), version, self)
+    }
+
+    /// Parses a resource URI of the service of the resource, and returns the name with the
+    /// API version of the URI.
+    pub fn from_uri(uri: &str) -> Result<(Self, &str), ResourceNameError> {
+        let (version, name) = aip_resourcename::split_uri(uri, "test.googleapis.com")?;
+        Ok((name.parse()?, version))
+    }
+
+    /// Returns true if any field contains a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        self.project == "-" || self.draft == "-"
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        (self.project == "-" || self.project == concrete.project)
+            && (self.draft == "-" || self.draft == concrete.draft)
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(mut self, variables: &[&str]) -> Self {
+        if variables.contains(&"project") {
+            self.project = "-".to_string();
+        }
+        if variables.contains(&"draft") {
+            self.draft = "-".to_string();
+        }
+        self
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        self.validate()?;
+        reject_wildcard("project", &self.project)?;
+        reject_wildcard("draft", &self.draft)?;
+        Ok(())
+    }
+
+    /// Returns true if `other` is the name of a resource nested under this one.
+    pub fn is_ancestor_of(&self, other: &str) -> bool {
+        ResourceName::is_ancestor_of(self, other)
+    }
+
+    /// Returns true if the name matches a glob such as `projects/acme/books/*`, where `*`
+    /// matches one segment and a final `**` any suffix. Globs that cannot match names of
+    /// this type match nothing.
+    pub fn matches_glob(&self, glob: &str) -> bool {
+        ResourceName::matches_glob(self, glob)
+    }
+
+}
+
+impl fmt::Display for DraftResourceName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_borrowed(), f)
+    }
+}
+
+impl FromStr for DraftResourceName {
+    type Err = ResourceNameError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts: Vec<&str> = s.split('/').collect();
-        if parts.len() != 4 {
-            return Err(format!("expected {expected_parts} parts, got {}", parts.len(), expected_parts = 4));
-        }
-        if parts[0] != "projects" {
-            return Err(format!("expected 'projects' at position 0, got '{part}'", part = parts[0]));
-        }
-        let project = parts[1].to_string();
-        if parts[2] != "tests" {
-            return Err(format!("expected 'tests' at position 2, got '{part}'", part = parts[2]));
-        }
-        let test = parts[3].to_string();
-        let result = Self {
-            project,
-            test,
-        };
-        result.validate()?;
-        Ok(result)
+        DraftResourceNameRef::parse_borrowed(s).map(DraftResourceNameRef::to_owned)
+    }
+}
+
+impl ResourceName for DraftResourceName {
+    const TYPE: &'static str = "test.googleapis.com/Draft";
+    const PATTERNS: &'static [&'static str] = &["projects/{project}/drafts/{draft}"];
+    const SINGULAR: &'static str = "draft";
+    const PLURAL: &'static str = "drafts";
+
+    fn validate(&self) -> Result<(), ResourceNameError> {
+        self.validate()
+    }
+
+    fn contains_wildcard(&self) -> bool {
+        self.contains_wildcard()
+    }
+}
+
+impl Serialize for DraftResourceName {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_variables(serializer, [("project", &self.project), ("draft", &self.draft)])
+    }
+}
+
+impl<'de> Deserialize<'de> for DraftResourceName {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let [project, draft] = deserialize_variables(deserializer, ["project", "draft"])?;
+        validated(Self { project, draft })
+    }
+}
+
+/// Borrowed view of a DraftResourceName that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DraftResourceNameRef<'a> {
+    pub project: &'a str,
+    pub draft: &'a str,
+}
+
+impl<'a> DraftResourceNameRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut scanner = Scanner::new(s, 4);
+        scanner.literal("projects")?;
+        let project = scanner.variable()?;
+        scanner.literal("drafts")?;
+        let draft = scanner.variable()?;
+        scanner.finish()?;
+        Self::from_segments(project, draft)
+    }
+
+    /// Creates a DraftResourceNameRef from parsed segments and validates it.
+    fn from_segments(project: &'a str, draft: &'a str) -> Result<Self, ResourceNameError> {
+        let name = Self { project, draft };
+        name.validate()?;
+        Ok(name)
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        validate_segment("project", self.project)?;
+        validate_segment("draft", self.draft)?;
+        Ok(())
+    }
+
+    /// Converts the borrowed view into an owned DraftResourceName.
+    pub fn to_owned(self) -> DraftResourceName {
+        DraftResourceName {
+            project: self.project.to_string(),
+            draft: self.draft.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for DraftResourceNameRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "projects/{project}/drafts/{draft}"
+            , project = self.project
+            , draft = self.draft
+        )
+    }
+}
+
+/// Parent of a DraftResourceName: `projects/{project}`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DraftParent {
+    pub project: String,
+}
+
+impl DraftParent {
+    /// Creates a new DraftParent.
+    pub fn new(
+        project: impl Into<String>
+    ) -> Self {
+        Self {
+            project: project.into(),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        self.as_borrowed().validate()
+    }
+
+    /// Borrows the resource name as a DraftParentRef.
+    pub fn as_borrowed(&self) -> DraftParentRef<'_> {
+        DraftParentRef {
+            project: &self.project,
+        }
+    }
+
+    /// Returns true if any field contains a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        self.project == "-"
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        self.project == "-" || self.project == concrete.project
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(mut self, variables: &[&str]) -> Self {
+        if variables.contains(&"project") {
+            self.project = "-".to_string();
+        }
+        self
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        self.validate()?;
+        reject_wildcard("project", &self.project)?;
+        Ok(())
+    }
+
+}
+
+impl fmt::Display for DraftParent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_borrowed(), f)
+    }
+}
+
+impl FromStr for DraftParent {
+    type Err = ResourceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        DraftParentRef::parse_borrowed(s).map(DraftParentRef::to_owned)
+    }
+}
+
+/// Borrowed view of a DraftParent that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DraftParentRef<'a> {
+    pub project: &'a str,
+}
+
+impl<'a> DraftParentRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut scanner = Scanner::new(s, 2);
+        scanner.literal("projects")?;
+        let project = scanner.variable()?;
+        scanner.finish()?;
+        Self::from_segments(project)
+    }
+
+    /// Creates a DraftParentRef from parsed segments and validates it.
+    fn from_segments(project: &'a str) -> Result<Self, ResourceNameError> {
+        let name = Self { project };
+        name.validate()?;
+        Ok(name)
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        validate_segment("project", self.project)?;
+        Ok(())
+    }
+
+    /// Converts the borrowed view into an owned DraftParent.
+    pub fn to_owned(self) -> DraftParent {
+        DraftParent {
+            project: self.project.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for DraftParentRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "projects/{project}"
+            , project = self.project
+        )
+    }
+}
+
+impl DraftResourceName {
+    /// Returns the name of the parent of this resource.
+    pub fn parent(&self) -> DraftParent {
+        DraftParent {
+            project: self.project.clone(),
+        }
+    }
+}
+
+impl DraftParent {
+    /// Creates the name of a DraftResourceName nested under this one.
+    pub fn draft(&self, draft: impl Into<String>) -> DraftResourceName {
+        DraftResourceName { project: self.project.clone(), draft: draft.into() }
+    }
+}
+
+impl example::optional::v1::Draft {
+    /// Parses the `name` field, if set, as a DraftResourceName.
+    pub fn resource_name(&self) -> Result<Option<DraftResourceName>, ResourceNameError> {
+        self.name.as_deref().map(str::parse).transpose()
+    }
+
+    /// Sets the `name` field from a DraftResourceName.
+    pub fn set_resource_name(&mut self, name: &DraftResourceName) {
+        self.name = Some(name.to_string());
     }
 }
 
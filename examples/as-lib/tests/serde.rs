@@ -0,0 +1,85 @@
+//! The structured serde form, generated with `serde=structured` in buf.gen.yaml.
+
+use build_with_buf::*;
+use serde_json::{from_str, json, to_value};
+
+#[test]
+fn struct_round_trip() {
+    let book = BookResourceName::new("my-project", "rust-book");
+    let value = to_value(&book).unwrap();
+    assert_eq!(value, json!({"project": "my-project", "book": "rust-book"}));
+    assert_eq!(
+        from_str::<BookResourceName>(&value.to_string()).unwrap(),
+        book
+    );
+
+    let attachment = ProjectsBooksAttachmentResourceName::new("p", "b", "docs/a.txt");
+    let value = to_value(&attachment).unwrap();
+    assert_eq!(
+        value,
+        json!({"project": "p", "book": "b", "path": "docs/a.txt"})
+    );
+    assert_eq!(
+        from_str::<ProjectsBooksAttachmentResourceName>(&value.to_string()).unwrap(),
+        attachment
+    );
+}
+
+#[test]
+fn variables_are_validated_in_place() {
+    // Joined into a name, these would parse back as year "20" and format "20~x"
+    let json = r#"{"project":"p","book":"b","year":"20~20","format":"x"}"#;
+    assert!(from_str::<EditionResourceName>(json).is_err());
+    let json = r#"{"project":"p/books/b","book":"c"}"#;
+    assert!(from_str::<BookResourceName>(json).is_err());
+    // A capture must match its template
+    let json = r#"{"parent":"users/u/shelves/s/attachments/x","path":"a"}"#;
+    assert!(from_str::<UsersShelvesAttachmentResourceName>(json).is_err());
+    let json = r#"{"project":"p"}"#;
+    assert!(from_str::<BookResourceName>(json).is_err());
+}
+
+#[test]
+fn enum_variant_by_variables() {
+    for name in [
+        "publishers/p",
+        "organizations/o/publishers/p",
+        "projects/x/publishers/p",
+    ] {
+        let publisher: PublisherResourceName = name.parse().unwrap();
+        let json = to_value(&publisher).unwrap().to_string();
+        assert!(json.starts_with('{'), "{json}");
+        assert_eq!(from_str::<PublisherResourceName>(&json).unwrap(), publisher);
+    }
+    let json = r#"{"user":"u","publisher":"p"}"#;
+    assert!(from_str::<PublisherResourceName>(json).is_err());
+}
+
+#[test]
+fn ambiguous_variants_as_strings() {
+    // Both patterns of a loan have the variables project and loan
+    for name in ["projects/p/loans/l", "projects/p/archivedLoans/l"] {
+        let loan: LoanResourceName = name.parse().unwrap();
+        assert_eq!(to_value(&loan).unwrap(), json!(name));
+        assert_eq!(
+            from_str::<LoanResourceName>(&format!("{name:?}")).unwrap(),
+            loan
+        );
+    }
+    let json = r#"{"project":"p","loan":"l"}"#;
+    assert!(from_str::<LoanResourceName>(json).is_err());
+}
+
+#[test]
+fn unknown_variant_as_string() {
+    let author: AuthorResourceName = "authors/a".parse().unwrap();
+    let json = to_value(&author).unwrap().to_string();
+    assert_eq!(json, r#"{"author":"a"}"#);
+    assert_eq!(from_str::<AuthorResourceName>(&json).unwrap(), author);
+
+    let author: AuthorResourceName = "publishers/p/authors/a".parse().unwrap();
+    assert!(author.is_unknown());
+    let json = to_value(&author).unwrap().to_string();
+    assert_eq!(json, r#""publishers/p/authors/a""#);
+    assert_eq!(from_str::<AuthorResourceName>(&json).unwrap(), author);
+}
@@ -0,0 +1,224 @@
+package genaip
+
+import (
+	"strings"
+
+	"github.com/AnteWall/protoc-gen-rust-aip/pkg/resourcename"
+	"google.golang.org/protobuf/compiler/protogen"
+)
+
+// ancestryLink pairs a pattern of a resource with the pattern of an ancestor it is nested under.
+type ancestryLink struct {
+	ancestor   patternCandidate
+	descendant patternCandidate
+}
+
+// ancestryLinks returns, for each descendant pattern nested under one of the ancestor patterns,
+// the link to the closest of them.
+func ancestryLinks(ancestors, descendants []patternCandidate) []ancestryLink {
+	var links []ancestryLink
+	for _, descendant := range descendants {
+		var closest *patternCandidate
+		for i, ancestor := range ancestors {
+			if isPatternPrefix(ancestor.segments, descendant.segments) &&
+				(closest == nil || len(ancestor.segments) > len(closest.segments)) {
+				closest = &ancestors[i]
+			}
+		}
+		if closest != nil {
+			links = append(links, ancestryLink{ancestor: *closest, descendant: descendant})
+		}
+	}
+	return links
+}
+
+// isPatternPrefix reports whether a pattern starts with all segments of a shorter pattern.
+func isPatternPrefix(prefix, segments []resourcename.Segment) bool {
+	if len(prefix) >= len(segments) {
+		return false
+	}
+	for i, segment := range prefix {
+		if segments[i] != segment {
+			return false
+		}
+	}
+	return true
+}
+
+// generateAncestry generates the navigation between the resource and every other resource
+// generated in this run that it is nested under: an accessor on the resource named after
+// the ancestor, and a constructor on the ancestor named after the resource.
+func (r resourceNameCodeGenerator) generateAncestry(g *protogen.GeneratedFile) error {
+	candidates, err := r.resourceCandidates()
+	if err != nil {
+		return err
+	}
+	for _, resourceType := range r.index.sortedTypes() {
+		if resourceType == r.resource.GetType() {
+			continue
+		}
+		ancestor := &resourceNameCodeGenerator{resource: r.index[resourceType]}
+		ancestorCandidates, err := ancestor.resourceCandidates()
+		if err != nil {
+			return err
+		}
+		links := ancestryLinks(ancestorCandidates, candidates)
+		if len(links) == 0 {
+			continue
+		}
+		r.generateAncestorAccessors(g, ancestor, links, candidates)
+		// Unknown names cannot construct the names nested under them, so an enum with an
+		// Unknown variant gets no constructor that dispatches to its variants.
+		ownerIsEnum := ancestor.isMultiPattern() && !ancestor.hasUnknownVariant()
+		r.generateNestedConstructors(g, ancestor.resourceTypeName(), ownerIsEnum, ancestorCandidates, links)
+	}
+	return nil
+}
+
+// generateAncestorAccessors generates the accessor of an ancestor on every struct of the
+// resource nested under it, and on the enum of a multi-pattern resource.
+func (r resourceNameCodeGenerator) generateAncestorAccessors(
+	g *protogen.GeneratedFile,
+	ancestor *resourceNameCodeGenerator,
+	links []ancestryLink,
+	candidates []patternCandidate,
+) {
+	ancestorType := ancestor.resourceTypeName()
+	methodName := rustIdent(ancestor.singularName())
+	for _, link := range links {
+		g.P("impl ", link.descendant.structName, " {")
+		g.P("    /// Returns the name of the ", ancestorType, " this resource is nested under.")
+		g.P("    pub fn ", methodName, "(&self) -> ", ancestorType, " {")
+		indent := "        "
+		if ancestor.isMultiPattern() {
+			g.P(indent, ancestorType, "::", link.ancestor.variantName, "(", link.ancestor.structName, " {")
+		} else {
+			g.P(indent, link.ancestor.structName, " {")
+		}
+		for _, variable := range variableNames(link.ancestor.segments) {
+			g.P(indent, "    ", variable, ": self.", variable, ".clone(),")
+		}
+		if ancestor.isMultiPattern() {
+			g.P(indent, "})")
+		} else {
+			g.P(indent, "}")
+		}
+		g.P("    }")
+		g.P("}")
+		g.P()
+	}
+
+	if !r.isMultiPattern() {
+		return
+	}
+	linked := map[string]bool{}
+	for _, link := range links {
+		linked[link.descendant.pattern] = true
+	}
+	allLinked := len(links) == len(candidates) && !r.hasUnknownVariant()
+	enumName := r.resourceTypeName()
+	returnType := ancestorType
+	if !allLinked {
+		returnType = "Option<" + ancestorType + ">"
+	}
+	g.P("impl ", enumName, " {")
+	if allLinked {
+		g.P("    /// Returns the name of the ", ancestorType, " this resource is nested under.")
+	} else {
+		g.P("    /// Returns the name of the ", ancestorType, " this resource is nested under, if any.")
+	}
+	g.P("    pub fn ", methodName, "(&self) -> ", returnType, " {")
+	g.P("        match self {")
+	for _, candidate := range candidates {
+		switch {
+		case !linked[candidate.pattern]:
+			g.P("            ", enumName, "::", candidate.variantName, "(_) => None,")
+		case allLinked:
+			g.P("            ", enumName, "::", candidate.variantName, "(inner) => inner.", methodName, "(),")
+		default:
+			g.P("            ", enumName, "::", candidate.variantName, "(inner) => Some(inner.", methodName, "()),")
+		}
+	}
+	if r.hasUnknownVariant() {
+		g.P("            ", enumName, "::", unknownVariantName, "(_) => None,")
+	}
+	g.P("        }")
+	g.P("    }")
+	g.P("}")
+	g.P()
+}
+
+// generateNestedConstructors generates, on the structs of a type the resource is nested under,
+// a constructor for the name of the resource, named after the resource and taking the
+// variables that follow the ones of the struct. An enum dispatches to its variants when they
+// all take the same variables.
+func (r resourceNameCodeGenerator) generateNestedConstructors(
+	g *protogen.GeneratedFile,
+	ownerType string,
+	ownerIsEnum bool,
+	ownerCandidates []patternCandidate,
+	links []ancestryLink,
+) {
+	resourceType := r.resourceTypeName()
+	methodName := rustIdent(r.singularName())
+	variablesByOwner := map[string][]string{}
+	for _, link := range links {
+		if _, ok := variablesByOwner[link.ancestor.pattern]; ok {
+			continue
+		}
+		ownerVariables := variableNames(link.ancestor.segments)
+		variables := variableNames(link.descendant.segments)[len(ownerVariables):]
+		variablesByOwner[link.ancestor.pattern] = variables
+
+		var fields []string
+		for _, variable := range ownerVariables {
+			fields = append(fields, variable+": self."+variable+".clone()")
+		}
+		for _, variable := range variables {
+			fields = append(fields, variable+": "+variable+".into()")
+		}
+		construct := link.descendant.structName + " { " + strings.Join(fields, ", ") + " }"
+		if r.isMultiPattern() {
+			construct = resourceType + "::" + link.descendant.variantName + "(" + construct + ")"
+		}
+		g.P("impl ", link.ancestor.structName, " {")
+		g.P("    /// Creates the name of a ", resourceType, " nested under this one.")
+		g.P("    pub fn ", methodName, "(", constructorParams(variables), ") -> ", resourceType, " {")
+		g.P("        ", construct)
+		g.P("    }")
+		g.P("}")
+		g.P()
+	}
+
+	if !ownerIsEnum {
+		return
+	}
+	var variables []string
+	for i, candidate := range ownerCandidates {
+		candidateVariables, ok := variablesByOwner[candidate.pattern]
+		if !ok || (i > 0 && strings.Join(candidateVariables, ",") != strings.Join(variables, ",")) {
+			return
+		}
+		variables = candidateVariables
+	}
+	g.P("impl ", ownerType, " {")
+	g.P("    /// Creates the name of a ", resourceType, " nested under this one.")
+	g.P("    pub fn ", methodName, "(", constructorParams(variables), ") -> ", resourceType, " {")
+	g.P("        match self {")
+	for _, candidate := range ownerCandidates {
+		g.P("            ", ownerType, "::", candidate.variantName, "(inner) => inner.", methodName, "(", strings.Join(variables, ", "), "),")
+	}
+	g.P("        }")
+	g.P("    }")
+	g.P("}")
+	g.P()
+}
+
+// constructorParams returns the parameters of a constructor taking the given variables.
+func constructorParams(variables []string) string {
+	params := []string{"&self"}
+	for _, variable := range variables {
+		params = append(params, variable+": impl Into<String>")
+	}
+	return strings.Join(params, ", ")
+}
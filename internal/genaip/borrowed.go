@@ -0,0 +1,259 @@
+package genaip
+
+import (
+	"strconv"
+	"strings"
+
+	"github.com/AnteWall/protoc-gen-rust-aip/pkg/resourcename"
+	"google.golang.org/protobuf/compiler/protogen"
+)
+
+// borrowedTypeName returns the name of the borrowed view of a generated resource name type.
+func borrowedTypeName(typeName string) string {
+	return typeName + "Ref"
+}
+
+// generateBorrowedStruct generates the borrowed view of a single-pattern struct.
+//
+// The view owns the parser, the validation rules and the format string. The owned struct
+// delegates to it, so both forms always agree and parsing only allocates in to_owned.
+func (r resourceNameCodeGenerator) generateBorrowedStruct(
+	g *protogen.GeneratedFile,
+	typeName string,
+	segments []resourcename.Segment,
+	revisionType string,
+) {
+	borrowedName := borrowedTypeName(typeName)
+	g.P("/// Borrowed view of a ", typeName, " that parses without allocating.")
+	g.P("#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]")
+	g.P("pub struct ", borrowedName, "<'a> {")
+	for _, segment := range segments {
+		for _, variable := range segment.Variables() {
+			g.P("    pub ", toRustFieldName(variable), ": &'a str,")
+		}
+	}
+	g.P("}")
+	g.P()
+
+	g.P("impl<'a> ", borrowedName, "<'a> {")
+	r.generateParseBorrowedMethod(g, segments)
+	r.generateFromSegmentsMethod(g, borrowedName, segments)
+
+	g.P("    /// Validates the resource name.")
+	g.P("    pub fn validate(&self) -> Result<(), ", resourceNameErrorType, "> {")
+	for _, segment := range segments {
+		for _, variable := range segment.Variables() {
+			fieldName := toRustFieldName(variable)
+			if segment.IsCapture() {
+				g.P("        for segment in aip_resourcename::capture_segments(", strconv.Quote(variable), ", self.", fieldName,
+					", ", strconv.Quote(segment.Template()), ")? {")
+				g.P("            ", r.config.Validation.segmentValidator(), "(", strconv.Quote(variable), ", segment)?;")
+				g.P("        }")
+			} else {
+				g.P("        ", r.config.Validation.segmentValidator(), "(", strconv.Quote(variable), ", self.", fieldName, ")?;")
+			}
+			r.generateVariableRuleChecks(g, variable)
+		}
+		generateSeparatorChecks(g, segment)
+	}
+	generateRevisionSeparatorCheck(g, segments, revisionType)
+	g.P("        Ok(())")
+	g.P("    }")
+	g.P()
+
+	g.P("    /// Converts the borrowed view into an owned ", typeName, ".")
+	g.P("    pub fn to_owned(self) -> ", typeName, " {")
+	g.P("        ", typeName, " {")
+	for _, segment := range segments {
+		for _, variable := range segment.Variables() {
+			fieldName := toRustFieldName(variable)
+			g.P("            ", fieldName, ": self.", fieldName, ".to_string(),")
+		}
+	}
+	g.P("        }")
+	g.P("    }")
+	g.P("}")
+	g.P()
+
+	g.P("impl fmt::Display for ", borrowedName, "<'_> {")
+	g.P("    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {")
+
+	// Build the format string with inlined variables
+	var formatStr strings.Builder
+	for i, segment := range segments {
+		if i > 0 {
+			formatStr.WriteString("/")
+		}
+		for _, part := range segment.Parts() {
+			if part.IsVariable() {
+				formatStr.WriteString("{" + toRustFieldName(part.Literal()) + "}")
+			} else {
+				formatStr.WriteString(part.Literal())
+			}
+		}
+	}
+
+	// Use inlined format args for clippy compliance
+	g.P("        write!(f, \"", formatStr.String(), "\"")
+	for _, segment := range segments {
+		for _, variable := range segment.Variables() {
+			fieldName := toRustFieldName(variable)
+			g.P("            , ", fieldName, " = self.", fieldName)
+		}
+	}
+	g.P("        )")
+	g.P("    }")
+	g.P("}")
+	g.P()
+}
+
+// generateParseBorrowedMethod generates a single-pass parser over the segments of a name.
+func (r resourceNameCodeGenerator) generateParseBorrowedMethod(
+	g *protogen.GeneratedFile,
+	segments []resourcename.Segment,
+) {
+	g.P("    /// Parses a resource name without allocating.")
+	g.P("    pub fn parse_borrowed(s: &'a str) -> Result<Self, ", resourceNameErrorType, "> {")
+
+	// Walk the segments with the runtime scanner so that parsing never collects an
+	// intermediate Vec and fails on the first literal that does not match.
+	g.P("        let mut scanner = ", scannerType, "::new(s, ", len(expandedSegments(segments)), ");")
+
+	var args []string
+	for _, segment := range segments {
+		switch {
+		case segment.IsCapture():
+			fieldName := toRustFieldName(segment.Literal())
+			args = append(args, fieldName)
+			g.P("        let ", fieldName, " = scanner.capture(", strconv.Quote(segment.Template()), ")?;")
+		case segment.IsComplex():
+			args = append(args, variableNames([]resourcename.Segment{segment})...)
+			generateSplitSegment(g, "        ", segment, "scanner.variable()?")
+		case segment.IsVariable():
+			fieldName := toRustFieldName(segment.Literal())
+			args = append(args, fieldName)
+			g.P("        let ", fieldName, " = scanner.variable()?;")
+		default:
+			g.P("        scanner.literal(", strconv.Quote(segment.Literal()), ")?;")
+		}
+	}
+	g.P("        scanner.finish()?;")
+	g.P("        Self::from_segments(", strings.Join(args, ", "), ")")
+	g.P("    }")
+	g.P()
+}
+
+// generateFromSegmentsMethod generates the private constructor used by the parsers,
+// which validates the segments before handing out the view.
+func (r resourceNameCodeGenerator) generateFromSegmentsMethod(
+	g *protogen.GeneratedFile,
+	borrowedName string,
+	segments []resourcename.Segment,
+) {
+	var params, fields []string
+	for _, segment := range segments {
+		for _, variable := range segment.Variables() {
+			fieldName := toRustFieldName(variable)
+			params = append(params, fieldName+": &'a str")
+			fields = append(fields, fieldName)
+		}
+	}
+	g.P("    /// Creates a ", borrowedName, " from parsed segments and validates it.")
+	g.P("    fn from_segments(", strings.Join(params, ", "), ") -> Result<Self, ", resourceNameErrorType, "> {")
+	g.P("        let name = Self { ", strings.Join(fields, ", "), " };")
+	g.P("        name.validate()?;")
+	g.P("        Ok(name)")
+	g.P("    }")
+	g.P()
+}
+
+// generateBorrowedEnum generates the borrowed view of a multi-pattern enum,
+// whose parser picks the variant with a decision tree over all patterns. With an Unknown
+// variant, well-formed names that match no pattern are parsed into it.
+func generateBorrowedEnum(g *protogen.GeneratedFile, enumName string, candidates []patternCandidate, unknown bool) {
+	borrowedName := borrowedTypeName(enumName)
+	untypedName := borrowedTypeName(untypedResourceNameType)
+	g.P("/// Borrowed view of a ", enumName, " that parses without allocating.")
+	g.P("#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]")
+	if unknown {
+		g.P("#[non_exhaustive]")
+	}
+	g.P("pub enum ", borrowedName, "<'a> {")
+	for _, candidate := range candidates {
+		g.P("    ", candidate.variantName, "(", borrowedTypeName(candidate.structName), "<'a>),")
+	}
+	if unknown {
+		g.P("    /// A well-formed name that matches none of the known patterns.")
+		g.P("    ", unknownVariantName, "(", untypedName, "<'a>),")
+	}
+	g.P("}")
+	g.P()
+
+	g.P("impl<'a> ", borrowedName, "<'a> {")
+	if unknown {
+		g.P("    /// Parses a resource name without allocating. Well-formed names that match none of")
+		g.P("    /// the known patterns are parsed as ", unknownVariantName, ".")
+		g.P("    pub fn parse_borrowed(s: &'a str) -> Result<Self, ", resourceNameErrorType, "> {")
+		g.P("        Self::parse_known(s).or_else(|err| {")
+		g.P("            if err.kind != ", resourceNameErrorType, "Kind::NoMatchingPattern {")
+		g.P("                return Err(err);")
+		g.P("            }")
+		g.P("            ", untypedName, "::parse_borrowed(s)")
+		g.P("                .map(Self::", unknownVariantName, ")")
+		g.P("                .map_err(|_| err)")
+		g.P("        })")
+		g.P("    }")
+		g.P()
+		g.P("    /// Parses a resource name of one of the known patterns.")
+		g.P("    fn parse_known(s: &'a str) -> Result<Self, ", resourceNameErrorType, "> {")
+	} else {
+		g.P("    /// Parses a resource name without allocating.")
+		g.P("    pub fn parse_borrowed(s: &'a str) -> Result<Self, ", resourceNameErrorType, "> {")
+	}
+	g.P("        let mut parts = s.split('/');")
+	generatePatternDecisionTree(g, candidates, 0, "        ")
+	g.P("    }")
+	g.P()
+	if unknown {
+		generateIsUnknownMethod(g, borrowedName)
+	}
+	g.P("    /// Validates the resource name.")
+	g.P("    pub fn validate(&self) -> Result<(), ", resourceNameErrorType, "> {")
+	g.P("        match self {")
+	for _, candidate := range candidates {
+		g.P("            ", borrowedName, "::", candidate.variantName, "(inner) => inner.validate(),")
+	}
+	if unknown {
+		g.P("            ", borrowedName, "::", unknownVariantName, "(_) => Ok(()),")
+	}
+	g.P("        }")
+	g.P("    }")
+	g.P()
+	g.P("    /// Converts the borrowed view into an owned ", enumName, ".")
+	g.P("    pub fn to_owned(self) -> ", enumName, " {")
+	g.P("        match self {")
+	for _, candidate := range candidates {
+		g.P("            ", borrowedName, "::", candidate.variantName, "(inner) => ", enumName, "::", candidate.variantName, "(inner.to_owned()),")
+	}
+	if unknown {
+		g.P("            ", borrowedName, "::", unknownVariantName, "(name) => ", enumName, "::", unknownVariantName, "(name.to_owned()),")
+	}
+	g.P("        }")
+	g.P("    }")
+	g.P("}")
+	g.P()
+
+	g.P("impl fmt::Display for ", borrowedName, "<'_> {")
+	g.P("    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {")
+	g.P("        match self {")
+	for _, candidate := range candidates {
+		g.P("            ", borrowedName, "::", candidate.variantName, "(inner) => write!(f, \"{inner}\"),")
+	}
+	if unknown {
+		g.P("            ", borrowedName, "::", unknownVariantName, "(name) => write!(f, \"{name}\"),")
+	}
+	g.P("        }")
+	g.P("    }")
+	g.P("}")
+	g.P()
+}
@@ -0,0 +1,64 @@
+package genaip
+
+import (
+	"fmt"
+	"strconv"
+
+	"github.com/AnteWall/protoc-gen-rust-aip/pkg/resourcename"
+)
+
+// checkPatterns rejects the patterns whose names could not be formatted back from the fields
+// of a generated type: anonymous wildcards are only allowed in the template of a variable.
+func (r resourceNameCodeGenerator) checkPatterns() error {
+	for _, pattern := range r.resource.GetPattern() {
+		var segments []resourcename.Segment
+		if err := resourcename.ParsePattern(pattern, &segments); err != nil {
+			return fmt.Errorf("%s: %w", r.resource.GetType(), err)
+		}
+		for _, segment := range segments {
+			if segment.IsWildcard() {
+				return fmt.Errorf("%s: pattern %q has an anonymous %s, capture it with a variable such as {name=%s}",
+					r.resource.GetType(), pattern, segment.Literal(), segment.Literal())
+			}
+		}
+	}
+	return nil
+}
+
+// expandedSegments returns the segments of a pattern with the templates of captures expanded,
+// so that each matches a single segment of a name, apart from a trailing **.
+func expandedSegments(segments []resourcename.Segment) []resourcename.Segment {
+	var expanded []resourcename.Segment
+	for _, segment := range segments {
+		expanded = append(expanded, segment.TemplateSegments()...)
+	}
+	return expanded
+}
+
+// endsWithMultiWildcard reports whether a capture takes the rest of the name, e.g. {path=**}.
+func endsWithMultiWildcard(segment resourcename.Segment) bool {
+	templateSegments := segment.TemplateSegments()
+	return templateSegments[len(templateSegments)-1].IsMultiWildcard()
+}
+
+// captureSpan returns the expression slicing the value of a capture starting at the given
+// position out of the name parsed by a decision tree.
+func captureSpan(segment resourcename.Segment, position int) string {
+	first := "part" + strconv.Itoa(position)
+	if endsWithMultiWildcard(segment) {
+		return "aip_resourcename::span_from(s, " + first + ")"
+	}
+	last := "part" + strconv.Itoa(position+len(segment.TemplateSegments())-1)
+	return "aip_resourcename::span(s, " + first + ", " + last + ")"
+}
+
+// captureTemplates returns the templates of the captures of a pattern, by variable name.
+func captureTemplates(segments []resourcename.Segment) map[string]string {
+	templates := map[string]string{}
+	for _, segment := range segments {
+		if segment.IsCapture() {
+			templates[segment.Literal()] = segment.Template()
+		}
+	}
+	return templates
+}
@@ -0,0 +1,34 @@
+package genaip
+
+import (
+	"strconv"
+	"strings"
+
+	"github.com/AnteWall/protoc-gen-rust-aip/pkg/resourcename"
+	"google.golang.org/protobuf/compiler/protogen"
+)
+
+// generateSplitSegment generates the bindings of the variables of a complex segment, such as
+// {key_a}~{key_b}, split from the value of the segment with the runtime.
+func generateSplitSegment(g *protogen.GeneratedFile, indent string, segment resourcename.Segment, value string) {
+	var separators []string
+	for _, part := range segment.Parts() {
+		if !part.IsVariable() {
+			separators = append(separators, "'"+part.Literal()+"'")
+		}
+	}
+	g.P(indent, "let [", strings.Join(variableNames([]resourcename.Segment{segment}), ", "),
+		"] = aip_resourcename::split_segment(", strconv.Quote(segment.Literal()), ", ", value,
+		", &[", strings.Join(separators, ", "), "])?;")
+}
+
+// generateSeparatorChecks generates the checks that the variables of a complex segment do not
+// contain the separator that follows them, which would make the formatted name parse differently.
+func generateSeparatorChecks(g *protogen.GeneratedFile, segment resourcename.Segment) {
+	parts := segment.Parts()
+	for i := 0; i+1 < len(parts); i += 2 {
+		fieldName := toRustFieldName(parts[i].Literal())
+		g.P("        aip_resourcename::reject_separator(", strconv.Quote(parts[i].Literal()), ", self.", fieldName,
+			", '", parts[i+1].Literal(), "')?;")
+	}
+}
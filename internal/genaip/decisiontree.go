@@ -0,0 +1,225 @@
+package genaip
+
+import (
+	"fmt"
+	"strconv"
+	"strings"
+
+	"github.com/AnteWall/protoc-gen-rust-aip/pkg/resourcename"
+	"google.golang.org/protobuf/compiler/protogen"
+)
+
+// patternCandidate is one pattern of a generated enum, with the struct and variant holding it.
+type patternCandidate struct {
+	pattern     string
+	segments    []resourcename.Segment
+	structName  string
+	variantName string
+}
+
+// patternCandidates returns the patterns of the resource in declaration order.
+func (r *resourceNameCodeGenerator) patternCandidates() ([]patternCandidate, error) {
+	candidates := make([]patternCandidate, 0, len(r.resource.GetPattern()))
+	for _, pattern := range r.resource.GetPattern() {
+		var segments []resourcename.Segment
+		if err := resourcename.ParsePattern(pattern, &segments); err != nil {
+			return nil, fmt.Errorf("failed to parse pattern %q: %w", pattern, err)
+		}
+		candidates = append(candidates, patternCandidate{
+			pattern:     pattern,
+			segments:    segments,
+			structName:  r.MultiPatternStructName(pattern),
+			variantName: r.getPatternVariantName(pattern),
+		})
+	}
+	return candidates, nil
+}
+
+// generatePatternDecisionTree generates a parser that picks the matching pattern in a single pass.
+//
+// At generation time the candidates are split on the segment at the given depth: each distinct
+// literal gets its own match arm, and candidates with a variable at that depth follow every arm.
+// Captures are expanded into the segments of their templates, and patterns ending with ** follow
+// every arm once they reached it. The generated code reads each segment exactly once and never
+// allocates on success.
+// When no pattern matches, the error names the first candidate that was still alive and the
+// position where the name diverged from it.
+func generatePatternDecisionTree(
+	g *protogen.GeneratedFile,
+	candidates []patternCandidate,
+	depth int,
+	indent string,
+) {
+	var terminal, tails, remaining, variables []patternCandidate
+	var literals []string
+	seenLiterals := map[string]bool{}
+	for _, candidate := range candidates {
+		segments := expandedSegments(candidate.segments)
+		if segments[len(segments)-1].IsMultiWildcard() && len(segments) <= depth {
+			tails = append(tails, candidate)
+			continue
+		}
+		if len(segments) == depth {
+			terminal = append(terminal, candidate)
+			continue
+		}
+		remaining = append(remaining, candidate)
+		segment := segments[depth]
+		if segment.HasVariables() || segment.IsWildcard() {
+			variables = append(variables, candidate)
+		} else if !seenLiterals[segment.Literal()] {
+			seenLiterals[segment.Literal()] = true
+			literals = append(literals, segment.Literal())
+		}
+	}
+
+	if len(remaining) == 0 && len(terminal) == 0 {
+		// Only patterns ending with ** are left, and the first one takes the rest of the name.
+		generateCandidateConstruction(g, indent, "", tails[0])
+		return
+	}
+
+	// Candidates that can match after the segment at this depth, in declaration order.
+	following := func(matches func(segment resourcename.Segment) bool) []patternCandidate {
+		var next []patternCandidate
+		for _, candidate := range candidates {
+			segments := expandedSegments(candidate.segments)
+			if containsCandidate(tails, candidate) || (len(segments) > depth && matches(segments[depth])) {
+				next = append(next, candidate)
+			}
+		}
+		return next
+	}
+
+	partName := "part" + strconv.Itoa(depth)
+	g.P(indent, "match parts.next() {")
+	for _, literal := range literals {
+		next := following(func(segment resourcename.Segment) bool {
+			return segment.HasVariables() || segment.IsWildcard() || segment.Literal() == literal
+		})
+		armPattern := strconv.Quote(literal)
+		if anyNeedsPart(next, depth) {
+			armPattern = partName + " @ " + armPattern
+		}
+		g.P(indent, "    Some(", armPattern, ") => {")
+		generatePatternDecisionTree(g, next, depth+1, indent+"        ")
+		g.P(indent, "    }")
+	}
+	if len(variables) > 0 {
+		next := following(func(segment resourcename.Segment) bool {
+			return segment.HasVariables() || segment.IsWildcard()
+		})
+		armPattern := "_"
+		if anyNeedsPart(next, depth) {
+			armPattern = partName
+		}
+		g.P(indent, "    Some(", armPattern, ") => {")
+		generatePatternDecisionTree(g, next, depth+1, indent+"        ")
+		g.P(indent, "    }")
+	} else if len(tails) > 0 {
+		// No other pattern goes on, so the first pattern ending with ** takes the rest.
+		generateCandidateConstruction(g, indent+"    ", "Some(_)", tails[0])
+	} else {
+		// Either a literal did not match, or the name is longer than every remaining pattern.
+		closest := terminal
+		if len(remaining) > 0 {
+			closest = remaining
+		}
+		g.P(indent, "    Some(part) => Err(", resourceNameErrorType, "::no_matching_pattern(",
+			strconv.Quote(closest[0].pattern), ", ", depth, ", Some(part))),")
+	}
+	// Patterns are tried in declaration order, so the first complete one wins.
+	var complete []patternCandidate
+	for _, candidate := range candidates {
+		if containsCandidate(terminal, candidate) || containsCandidate(tails, candidate) {
+			complete = append(complete, candidate)
+		}
+	}
+	if len(complete) > 0 {
+		generateCandidateConstruction(g, indent+"    ", "None", complete[0])
+	} else {
+		g.P(indent, "    None => Err(", resourceNameErrorType, "::no_matching_pattern(",
+			strconv.Quote(remaining[0].pattern), ", ", depth, ", None)),")
+	}
+	g.P(indent, "}")
+}
+
+// generateCandidateConstruction generates the construction of the borrowed view of a candidate
+// from the parts read so far, as a match arm, or as the tail of a block without one.
+func generateCandidateConstruction(
+	g *protogen.GeneratedFile,
+	indent string,
+	arm string,
+	candidate patternCandidate,
+) {
+	var args []string
+	var complexSegments []resourcename.Segment
+	var complexParts []string
+	position := 0
+	for _, segment := range candidate.segments {
+		partName := "part" + strconv.Itoa(position)
+		switch {
+		case segment.IsCapture():
+			args = append(args, captureSpan(segment, position))
+		case segment.IsComplex():
+			args = append(args, variableNames([]resourcename.Segment{segment})...)
+			complexSegments = append(complexSegments, segment)
+			complexParts = append(complexParts, partName)
+		case segment.IsVariable():
+			args = append(args, partName)
+		}
+		position += len(segment.TemplateSegments())
+	}
+	construct := borrowedTypeName(candidate.structName) + "::from_segments(" +
+		strings.Join(args, ", ") + ").map(Self::" + candidate.variantName + ")"
+	switch {
+	case arm == "":
+		for i, segment := range complexSegments {
+			generateSplitSegment(g, indent, segment, complexParts[i])
+		}
+		g.P(indent, construct)
+	case len(complexSegments) == 0:
+		g.P(indent, arm, " => ", construct, ",")
+	default:
+		// Complex segments are split into their variables once the pattern is known.
+		g.P(indent, arm, " => {")
+		for i, segment := range complexSegments {
+			generateSplitSegment(g, indent+"    ", segment, complexParts[i])
+		}
+		g.P(indent, "    ", construct)
+		g.P(indent, "}")
+	}
+}
+
+// anyNeedsPart reports whether the construction of any of the candidates reads the part at
+// the given depth.
+func anyNeedsPart(candidates []patternCandidate, depth int) bool {
+	for _, candidate := range candidates {
+		position := 0
+		for _, segment := range candidate.segments {
+			n := len(segment.TemplateSegments())
+			switch {
+			case segment.IsCapture():
+				if depth == position || (depth == position+n-1 && !endsWithMultiWildcard(segment)) {
+					return true
+				}
+			case segment.HasVariables():
+				if depth == position {
+					return true
+				}
+			}
+			position += n
+		}
+	}
+	return false
+}
+
+// containsCandidate reports whether a candidate is one of the given candidates.
+func containsCandidate(candidates []patternCandidate, candidate patternCandidate) bool {
+	for _, other := range candidates {
+		if other.pattern == candidate.pattern {
+			return true
+		}
+	}
+	return false
+}
@@ -0,0 +1,103 @@
+package genaip
+
+import (
+	"fmt"
+	"sort"
+	"strconv"
+	"strings"
+
+	"google.golang.org/protobuf/compiler/protogen"
+)
+
+// ServiceNames maps proto packages to the service name used in the full resource names of
+// the resources they declare, overriding the host in the resource type. It implements
+// flag.Value, and each value is a single package=service pair.
+type ServiceNames map[string]string
+
+// String implements flag.Value.
+func (s ServiceNames) String() string {
+	pairs := make([]string, 0, len(s))
+	for pkg, service := range s {
+		pairs = append(pairs, pkg+"="+service)
+	}
+	sort.Strings(pairs)
+	return strings.Join(pairs, ",")
+}
+
+// Set implements flag.Value.
+func (s ServiceNames) Set(value string) error {
+	pkg, service, ok := strings.Cut(value, "=")
+	if !ok || pkg == "" || service == "" || strings.Contains(service, "/") {
+		return fmt.Errorf("invalid service name %q, expected <proto package>=<service>", value)
+	}
+	s[pkg] = service
+	return nil
+}
+
+// serviceName returns the service the resource belongs to: the one configured for the
+// package of its file, or else the host in its type, e.g. library.googleapis.com.
+func (r *resourceNameCodeGenerator) serviceName() string {
+	if service, ok := r.config.ServiceNames[string(r.file.Desc.Package())]; ok {
+		return service
+	}
+	service, _, _ := strings.Cut(r.resource.GetType(), "/")
+	return service
+}
+
+// generateFullNameMethods generates the methods converting a struct from and to a full
+// resource name.
+func (r resourceNameCodeGenerator) generateFullNameMethods(g *protogen.GeneratedFile) {
+	g.P("    /// Returns the name of the service the resource belongs to.")
+	g.P("    pub fn service_name(&self) -> &'static str {")
+	g.P("        ", strconv.Quote(r.serviceName()))
+	g.P("    }")
+	g.P()
+	g.P("    /// Returns the full resource name, prefixed with the service name.")
+	g.P("    pub fn to_full_name(&self) -> String {")
+	g.P("        format!(\"//{}/{}\", self.service_name(), self)")
+	g.P("    }")
+	g.P()
+	r.generateFromFullNameMethod(g)
+}
+
+// generateEnumFullNameMethods generates the methods converting an enum from and to a full
+// resource name, which delegate to the struct of each variant.
+func (r resourceNameCodeGenerator) generateEnumFullNameMethods(
+	g *protogen.GeneratedFile,
+	enumName string,
+	candidates []patternCandidate,
+) {
+	g.P("    /// Returns the name of the service the resource belongs to.")
+	g.P("    pub fn service_name(&self) -> &'static str {")
+	g.P("        match self {")
+	for _, candidate := range candidates {
+		g.P("            ", enumName, "::", candidate.variantName, "(inner) => inner.service_name(),")
+	}
+	if r.hasUnknownVariant() {
+		g.P("            ", enumName, "::", unknownVariantName, "(_) => ", strconv.Quote(r.serviceName()), ",")
+	}
+	g.P("        }")
+	g.P("    }")
+	g.P()
+	g.P("    /// Returns the full resource name, prefixed with the service name.")
+	g.P("    pub fn to_full_name(&self) -> String {")
+	g.P("        match self {")
+	for _, candidate := range candidates {
+		g.P("            ", enumName, "::", candidate.variantName, "(inner) => inner.to_full_name(),")
+	}
+	if r.hasUnknownVariant() {
+		g.P("            ", enumName, "::", unknownVariantName, "(name) => format!(\"//{}/{}\", self.service_name(), name),")
+	}
+	g.P("        }")
+	g.P("    }")
+	g.P()
+	r.generateFromFullNameMethod(g)
+}
+
+func (r resourceNameCodeGenerator) generateFromFullNameMethod(g *protogen.GeneratedFile) {
+	g.P("    /// Parses a full resource name, which must belong to the service of the resource.")
+	g.P("    pub fn from_full_name(full_name: &str) -> Result<Self, ", resourceNameErrorType, "> {")
+	g.P("        strip_service(full_name, ", strconv.Quote(r.serviceName()), ")?.parse()")
+	g.P("    }")
+	g.P()
+}
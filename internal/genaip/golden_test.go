@@ -0,0 +1,174 @@
+package genaip
+
+import (
+	"flag"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+
+	"google.golang.org/genproto/googleapis/api/annotations"
+	"google.golang.org/protobuf/compiler/protogen"
+	"google.golang.org/protobuf/proto"
+	"google.golang.org/protobuf/types/descriptorpb"
+	"google.golang.org/protobuf/types/pluginpb"
+)
+
+//nolint:gochecknoglobals
+var update = flag.Bool("update", false, "update the golden files in testdata/golden")
+
+// goldenResource returns a resource of the golden.example.com service.
+func goldenResource(
+	kind, singular, plural string,
+	history annotations.ResourceDescriptor_History,
+	patterns ...string,
+) *annotations.ResourceDescriptor {
+	return &annotations.ResourceDescriptor{
+		Type:     "golden.example.com/" + kind,
+		Pattern:  patterns,
+		History:  history,
+		Singular: singular,
+		Plural:   plural,
+	}
+}
+
+// goldenMessage returns a message named after the kind of the resource, with its name field.
+func goldenMessage(resource *annotations.ResourceDescriptor) *descriptorpb.DescriptorProto {
+	options := &descriptorpb.MessageOptions{}
+	proto.SetExtension(options, annotations.E_Resource, resource)
+	return &descriptorpb.DescriptorProto{
+		Name: proto.String(strings.TrimPrefix(resource.GetType(), "golden.example.com/")),
+		Field: []*descriptorpb.FieldDescriptorProto{{
+			Name:     proto.String("name"),
+			JsonName: proto.String("name"),
+			Number:   proto.Int32(1),
+			Label:    descriptorpb.FieldDescriptorProto_LABEL_OPTIONAL.Enum(),
+			Type:     descriptorpb.FieldDescriptorProto_TYPE_STRING.Enum(),
+		}},
+		Options: options,
+	}
+}
+
+// generateGolden runs the plugin on golden.proto, holding a message for each resource, and
+// returns the generated files by name.
+func generateGolden(t *testing.T, config Config, resources []*annotations.ResourceDescriptor) map[string]string {
+	t.Helper()
+	file := &descriptorpb.FileDescriptorProto{
+		Name:    proto.String("golden.proto"),
+		Package: proto.String("golden.v1"),
+		Syntax:  proto.String("proto3"),
+		Options: &descriptorpb.FileOptions{
+			GoPackage: proto.String("example.com/golden;goldenpb"),
+		},
+	}
+	for _, resource := range resources {
+		file.MessageType = append(file.MessageType, goldenMessage(resource))
+	}
+	gen, err := protogen.Options{}.New(&pluginpb.CodeGeneratorRequest{
+		FileToGenerate: []string{file.GetName()},
+		Parameter:      proto.String("paths=source_relative"),
+		ProtoFile:      []*descriptorpb.FileDescriptorProto{file},
+	})
+	if err != nil {
+		t.Fatal(err)
+	}
+	if err := Run(gen, config); err != nil {
+		t.Fatal(err)
+	}
+	response := gen.Response()
+	if response.Error != nil {
+		t.Fatal(response.GetError())
+	}
+	generated := map[string]string{}
+	for _, file := range response.GetFile() {
+		generated[file.GetName()] = file.GetContent()
+	}
+	return generated
+}
+
+// TestGolden compares the generated code with the files in testdata/golden. After a change
+// to the generated code, run `go test ./internal/genaip -update` and review the diff.
+func TestGolden(t *testing.T) {
+	const none = annotations.ResourceDescriptor_HISTORY_UNSPECIFIED
+	for _, tt := range []struct {
+		name      string
+		config    Config
+		resources []*annotations.ResourceDescriptor
+	}{
+		{
+			name: "singleton",
+			resources: []*annotations.ResourceDescriptor{
+				goldenResource("Project", "project", "projects", none, "projects/{project}"),
+				goldenResource("User", "user", "users", none, "users/{user}"),
+				goldenResource("Settings", "settings", "settings", none, "projects/{project}/settings"),
+				goldenResource("Config", "config", "configs", none, "projects/{project}/config", "users/{user}/config"),
+			},
+		},
+		{
+			name:   "revisions",
+			config: Config{Revisions: ResourceTypes{"golden.example.com/Book": true}},
+			resources: []*annotations.ResourceDescriptor{
+				goldenResource("Book", "book", "books", none, "projects/{project}/books/{book}"),
+			},
+		},
+		{
+			name: "originally_single_pattern",
+			resources: []*annotations.ResourceDescriptor{
+				goldenResource(
+					"Publisher", "publisher", "publishers",
+					annotations.ResourceDescriptor_ORIGINALLY_SINGLE_PATTERN,
+					"publishers/{publisher}", "projects/{project}/publishers/{publisher}",
+				),
+			},
+		},
+		{
+			name:   "future_multi_pattern",
+			config: Config{Serde: SerdeStructured},
+			resources: []*annotations.ResourceDescriptor{
+				goldenResource(
+					"Author", "author", "authors",
+					annotations.ResourceDescriptor_FUTURE_MULTI_PATTERN,
+					"authors/{author}",
+				),
+			},
+		},
+	} {
+		t.Run(tt.name, func(t *testing.T) {
+			dir := filepath.Join("testdata", "golden", tt.name)
+			generated := generateGolden(t, tt.config, tt.resources)
+			if *update {
+				if err := os.RemoveAll(dir); err != nil {
+					t.Fatal(err)
+				}
+				if err := os.MkdirAll(dir, 0o755); err != nil {
+					t.Fatal(err)
+				}
+				for name, content := range generated {
+					if err := os.WriteFile(filepath.Join(dir, name), []byte(content), 0o644); err != nil {
+						t.Fatal(err)
+					}
+				}
+			}
+			entries, err := os.ReadDir(dir)
+			if err != nil {
+				t.Fatal(err)
+			}
+			for _, entry := range entries {
+				if _, ok := generated[entry.Name()]; !ok {
+					t.Errorf("%s was not generated", filepath.Join(dir, entry.Name()))
+				}
+			}
+			for name, content := range generated {
+				path := filepath.Join(dir, name)
+				want, err := os.ReadFile(path)
+				if err != nil {
+					t.Errorf("%s: %v, run go test ./internal/genaip -update", name, err)
+					continue
+				}
+				if content != string(want) {
+					t.Errorf("%s differs from the generated code, run go test ./internal/genaip -update and review the diff", path)
+				}
+			}
+		})
+	}
+}
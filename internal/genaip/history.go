@@ -0,0 +1,86 @@
+package genaip
+
+import (
+	"fmt"
+	"strings"
+
+	"github.com/AnteWall/protoc-gen-rust-aip/pkg/resourcename"
+	"google.golang.org/genproto/googleapis/api/annotations"
+	"google.golang.org/protobuf/compiler/protogen"
+)
+
+// isOriginallySinglePattern reports whether the resource gained patterns after it was
+// generated as a single-pattern struct, see history ORIGINALLY_SINGLE_PATTERN. Its enum keeps
+// the name of the original type, and the struct of its first pattern stays available under a
+// deprecated alias.
+func (r *resourceNameCodeGenerator) isOriginallySinglePattern() bool {
+	return r.resource.GetHistory() == annotations.ResourceDescriptor_ORIGINALLY_SINGLE_PATTERN &&
+		len(r.resource.GetPattern()) > 1
+}
+
+// singlePatternCompatName returns the name of the deprecated alias of the first-pattern
+// struct of a resource that was originally single-pattern.
+func (r *resourceNameCodeGenerator) singlePatternCompatName() string {
+	return "SinglePattern" + r.SinglePatternStructName()
+}
+
+// generateSinglePatternCompat keeps the single-pattern shape of a resource that was
+// originally single-pattern available, deprecated in favour of its enum: an alias of the
+// struct of its first pattern, conversions between the two, and the old constructor.
+func (r resourceNameCodeGenerator) generateSinglePatternCompat(g *protogen.GeneratedFile) error {
+	if !r.isOriginallySinglePattern() {
+		return nil
+	}
+	firstPattern := r.resource.GetPattern()[0]
+	var segments []resourcename.Segment
+	if err := resourcename.ParsePattern(firstPattern, &segments); err != nil {
+		return fmt.Errorf("failed to parse pattern %q: %w", firstPattern, err)
+	}
+	enumName := r.MultiPatternEnumName()
+	structName := r.MultiPatternStructName(firstPattern)
+	variantName := r.getPatternVariantName(firstPattern)
+	note := fmt.Sprintf("%s has several patterns, use %s", r.resource.GetType(), enumName)
+
+	g.P("/// Resource name of the original pattern `", firstPattern, "` of ", r.resource.GetType(), ",")
+	g.P("/// from before the resource had several patterns.")
+	g.P("#[deprecated(note = \"", note, "\")]")
+	g.P("pub type ", r.singlePatternCompatName(), " = ", structName, ";")
+	g.P()
+
+	g.P("impl From<", structName, "> for ", enumName, " {")
+	g.P("    fn from(name: ", structName, ") -> Self {")
+	g.P("        ", enumName, "::", variantName, "(name)")
+	g.P("    }")
+	g.P("}")
+	g.P()
+
+	g.P("impl TryFrom<", enumName, "> for ", structName, " {")
+	g.P("    type Error = ", resourceNameErrorType, ";")
+	g.P()
+	g.P("    /// Converts names of the original pattern `", firstPattern, "`, and fails for the others.")
+	g.P("    fn try_from(name: ", enumName, ") -> Result<Self, Self::Error> {")
+	g.P("        match name {")
+	g.P("            ", enumName, "::", variantName, "(name) => Ok(name),")
+	// Parsing reports where the name diverges from the original pattern
+	g.P("            name => name.to_string().parse(),")
+	g.P("        }")
+	g.P("    }")
+	g.P("}")
+	g.P()
+
+	var params, args []string
+	for _, variable := range segmentVariables(segments) {
+		fieldName := toRustFieldName(variable)
+		params = append(params, fieldName+": impl Into<String>")
+		args = append(args, fieldName)
+	}
+	g.P("impl ", enumName, " {")
+	g.P("    /// Creates a new ", enumName, " with the original pattern `", firstPattern, "`.")
+	g.P("    #[deprecated(note = \"", note, "::", variantName, "\")]")
+	g.P("    pub fn new(", strings.Join(params, ", "), ") -> Self {")
+	g.P("        ", enumName, "::", variantName, "(", structName, "::new(", strings.Join(args, ", "), "))")
+	g.P("    }")
+	g.P("}")
+	g.P()
+	return nil
+}
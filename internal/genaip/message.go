@@ -0,0 +1,136 @@
+package genaip
+
+import (
+	"strings"
+	"unicode"
+
+	"google.golang.org/protobuf/compiler/protogen"
+	"google.golang.org/protobuf/reflect/protoreflect"
+)
+
+// defaultNameField is the field holding the resource name when the descriptor does not set one.
+const defaultNameField = "name"
+
+// generateMessageAccessors generates the resource_name accessors on the prost message
+// annotated with the resource, connecting its name field to the generated type.
+func (r resourceNameCodeGenerator) generateMessageAccessors(g *protogen.GeneratedFile) {
+	nameField := r.resource.GetNameField()
+	if nameField == "" {
+		nameField = defaultNameField
+	}
+	field := r.message.Fields().ByName(protoreflect.Name(nameField))
+	if field == nil || field.Kind() != protoreflect.StringKind || field.Cardinality() == protoreflect.Repeated {
+		// Nothing to connect, the message does not follow AIP-122
+		return
+	}
+	if oneof := field.ContainingOneof(); oneof != nil && !oneof.IsSynthetic() {
+		// prost moves the field into an enum of the oneof
+		return
+	}
+
+	typeName := r.resourceTypeName()
+	fieldName := rustIdent(toRustFieldName(nameField))
+	g.P("impl ", prostTypePath(r.config.ProstModule, r.message), " {")
+	// prost generates an Option<String> for an optional name field
+	value := "name.to_string()"
+	if field.HasOptionalKeyword() {
+		g.P("    /// Parses the `", nameField, "` field, if set, as a ", typeName, ".")
+		g.P("    pub fn resource_name(&self) -> Result<Option<", typeName, ">, ", resourceNameErrorType, "> {")
+		g.P("        self.", fieldName, ".as_deref().map(str::parse).transpose()")
+		value = "Some(" + value + ")"
+	} else {
+		g.P("    /// Parses the `", nameField, "` field as a ", typeName, ".")
+		g.P("    pub fn resource_name(&self) -> Result<", typeName, ", ", resourceNameErrorType, "> {")
+		g.P("        self.", fieldName, ".parse()")
+	}
+	g.P("    }")
+	g.P()
+	g.P("    /// Sets the `", nameField, "` field from a ", typeName, ".")
+	g.P("    pub fn set_resource_name(&mut self, name: &", typeName, ") {")
+	g.P("        self.", fieldName, " = ", value, ";")
+	g.P("    }")
+	g.P("}")
+	g.P()
+}
+
+// prostTypePath returns the path of the Rust type prost generates for a message, under module
+// when the prost modules are not included where include_aip.rs is, e.g. crate::pb.
+func prostTypePath(module string, message protoreflect.MessageDescriptor) string {
+	if module == "" {
+		return prostMessagePath(message)
+	}
+	return strings.TrimSuffix(module, "::") + "::" + prostMessagePath(message)
+}
+
+// prostMessagePath returns the path of the Rust type prost generates for a message,
+// relative to the module that includes the generated prost modules.
+func prostMessagePath(message protoreflect.MessageDescriptor) string {
+	path := []string{rustIdent(toUpperCamelCase(string(message.Name())))}
+	// Nested messages live in a module named after their parent message
+	parent, ok := message.Parent().(protoreflect.MessageDescriptor)
+	for ok {
+		module := rustIdent(toSnakeCase(toUpperCamelCase(string(parent.Name()))))
+		path = append([]string{module}, path...)
+		parent, ok = parent.Parent().(protoreflect.MessageDescriptor)
+	}
+	if pkg := string(message.ParentFile().Package()); pkg != "" {
+		var modules []string
+		for _, part := range strings.Split(pkg, ".") {
+			modules = append(modules, rustIdent(toSnakeCase(part)))
+		}
+		path = append(modules, path...)
+	}
+	return strings.Join(path, "::")
+}
+
+// toUpperCamelCase converts a protobuf message name to the Rust type name prost uses for it,
+// e.g. HTTPRequest becomes HttpRequest.
+func toUpperCamelCase(s string) string {
+	var result strings.Builder
+	runes := []rune(s)
+	wordStart := true
+	for i, c := range runes {
+		if c == '_' || c == '-' {
+			wordStart = true
+			continue
+		}
+		if i > 0 && unicode.IsUpper(c) {
+			prev := runes[i-1]
+			nextIsLower := i+1 < len(runes) && unicode.IsLower(runes[i+1])
+			if unicode.IsLower(prev) || unicode.IsDigit(prev) || (unicode.IsUpper(prev) && nextIsLower) {
+				wordStart = true
+			}
+		}
+		if wordStart {
+			result.WriteRune(unicode.ToUpper(c))
+			wordStart = false
+		} else {
+			result.WriteRune(unicode.ToLower(c))
+		}
+	}
+	return result.String()
+}
+
+// rustKeywords lists the Rust keywords that prost escapes in identifiers.
+var rustKeywords = map[string]bool{
+	"as": true, "break": true, "const": true, "continue": true, "else": true, "enum": true,
+	"false": true, "fn": true, "for": true, "if": true, "impl": true, "in": true, "let": true,
+	"loop": true, "match": true, "mod": true, "move": true, "mut": true, "pub": true, "ref": true,
+	"return": true, "static": true, "struct": true, "trait": true, "true": true, "type": true,
+	"unsafe": true, "use": true, "where": true, "while": true, "abstract": true, "become": true,
+	"box": true, "do": true, "final": true, "macro": true, "override": true, "priv": true,
+	"typeof": true, "unsized": true, "virtual": true, "yield": true, "async": true, "await": true,
+	"dyn": true, "try": true, "gen": true,
+}
+
+// rustIdent escapes an identifier that collides with a Rust keyword the way prost does.
+func rustIdent(s string) string {
+	switch {
+	case s == "self" || s == "super" || s == "crate" || s == "Self":
+		return s + "_"
+	case rustKeywords[s]:
+		return "r#" + s
+	default:
+		return s
+	}
+}
@@ -0,0 +1,323 @@
+package genaip
+
+import (
+	"fmt"
+	"strings"
+
+	"github.com/AnteWall/protoc-gen-rust-aip/pkg/resourcename"
+	"google.golang.org/protobuf/compiler/protogen"
+)
+
+// parentInfo describes the type holding the names of the parents of a resource.
+type parentInfo struct {
+	// typeName is the type holding any parent name, a struct or an enum.
+	typeName string
+
+	// isEnum is true when the parent has several patterns.
+	isEnum bool
+
+	// existing is true when the parent is another resource generated in this run.
+	existing bool
+
+	// candidates holds the struct and variant of each parent pattern, in order.
+	candidates []patternCandidate
+}
+
+// candidate returns the parent candidate for a parent pattern.
+func (p parentInfo) candidate(pattern string) patternCandidate {
+	for _, candidate := range p.candidates {
+		if candidate.pattern == pattern {
+			return candidate
+		}
+	}
+	panic("unknown parent pattern " + pattern)
+}
+
+// wrap returns the expression converting a parent struct into the parent type.
+func (p parentInfo) wrap(candidate patternCandidate, expr string) string {
+	if !p.isEnum {
+		return expr
+	}
+	return p.typeName + "::" + candidate.variantName + "(" + expr + ")"
+}
+
+// parentTypeName returns the name of the type holding the parents of the resource.
+func (r *resourceNameCodeGenerator) parentTypeName() string {
+	return toPascalCase(r.getResourceKind()) + "Parent"
+}
+
+// singularName returns the snake_case singular name of the resource.
+func (r *resourceNameCodeGenerator) singularName() string {
+	if singular := r.resource.GetSingular(); singular != "" {
+		return toSnakeCase(singular)
+	}
+	return toSnakeCase(r.getResourceKind())
+}
+
+// resourceCandidates returns the patterns of the resource with the struct holding each of
+// them. Single-pattern resources have no enum, so their candidate has no variant.
+func (r *resourceNameCodeGenerator) resourceCandidates() ([]patternCandidate, error) {
+	if r.isMultiPattern() {
+		return r.patternCandidates()
+	}
+	pattern := r.resource.GetPattern()[0]
+	var segments []resourcename.Segment
+	if err := resourcename.ParsePattern(pattern, &segments); err != nil {
+		return nil, fmt.Errorf("failed to parse pattern %q: %w", pattern, err)
+	}
+	return []patternCandidate{{
+		pattern:    pattern,
+		segments:   segments,
+		structName: r.SinglePatternStructName(),
+	}}, nil
+}
+
+// parentInfo returns the parent type of the resource. A resource generated in this run whose
+// patterns are exactly the parent patterns is preferred over a synthesized type.
+func (r *resourceNameCodeGenerator) parentInfo(parents []string) (parentInfo, error) {
+	if existing := r.index.resourceWithPatterns(parents); existing != nil {
+		e := &resourceNameCodeGenerator{resource: existing}
+		candidates, err := e.resourceCandidates()
+		if err != nil {
+			return parentInfo{}, err
+		}
+		return parentInfo{
+			typeName:   e.resourceTypeName(),
+			isEnum:     e.isMultiPattern(),
+			existing:   true,
+			candidates: candidates,
+		}, nil
+	}
+
+	info := parentInfo{
+		typeName: r.parentTypeName(),
+		isEnum:   len(parents) > 1,
+	}
+	for _, pattern := range parents {
+		var segments []resourcename.Segment
+		if err := resourcename.ParsePattern(pattern, &segments); err != nil {
+			return parentInfo{}, fmt.Errorf("failed to parse pattern %q: %w", pattern, err)
+		}
+		candidate := patternCandidate{
+			pattern:    pattern,
+			segments:   segments,
+			structName: info.typeName,
+		}
+		if info.isEnum {
+			candidate.variantName = parentVariantName(segments)
+			candidate.structName = candidate.variantName + info.typeName
+		}
+		info.candidates = append(info.candidates, candidate)
+	}
+	return info, nil
+}
+
+// parentVariantName returns the enum variant of a synthesized parent pattern, made of its literals.
+func parentVariantName(segments []resourcename.Segment) string {
+	var result strings.Builder
+	for _, segment := range expandedSegments(segments) {
+		if !segment.HasVariables() && !segment.IsWildcard() {
+			result.WriteString(toPascalCase(segment.Literal()))
+		}
+	}
+	if result.Len() == 0 {
+		return "Simple"
+	}
+	return result.String()
+}
+
+// generateParent generates the parent type of the resource, if it has any nested pattern,
+// the parent() methods of the resource and the constructors of the resource on a
+// synthesized parent.
+func (r resourceNameCodeGenerator) generateParent(g *protogen.GeneratedFile) error {
+	parents := parentPatterns(r.resource.GetPattern())
+	if len(parents) == 0 {
+		return nil
+	}
+	info, err := r.parentInfo(parents)
+	if err != nil {
+		return err
+	}
+	candidates, err := r.resourceCandidates()
+	if err != nil {
+		return err
+	}
+
+	if info.existing {
+		g.P("/// Parent of a ", r.resourceTypeName(), ".")
+		g.P("pub type ", r.parentTypeName(), " = ", info.typeName, ";")
+		g.P()
+	} else {
+		r.generateParentTypes(g, info)
+	}
+	r.generateParentMethods(g, info, candidates)
+	if !info.existing {
+		r.generateChildConstructors(g, info, candidates)
+	}
+	return nil
+}
+
+// generateParentTypes generates a synthesized parent type, and its variant structs for enums.
+func (r resourceNameCodeGenerator) generateParentTypes(g *protogen.GeneratedFile, info parentInfo) {
+	if !info.isEnum {
+		candidate := info.candidates[0]
+		r.generateParentStruct(g, candidate.structName, candidate.pattern, candidate.segments)
+		return
+	}
+
+	g.P("/// Parent of a ", r.resourceTypeName(), ".")
+	g.P("#[derive(Debug, Clone, PartialEq, Eq, Hash)]")
+	g.P("pub enum ", info.typeName, " {")
+	for _, candidate := range info.candidates {
+		g.P("    ", candidate.variantName, "(", candidate.structName, "),")
+	}
+	g.P("}")
+	g.P()
+	g.P("impl ", info.typeName, " {")
+	generateEnumMethods(g, info.typeName, info.candidates, false)
+	g.P("}")
+	g.P()
+	generateEnumTraits(g, info.typeName, info.candidates, false)
+	generateBorrowedEnum(g, info.typeName, info.candidates, false)
+
+	for _, candidate := range info.candidates {
+		r.generateParentStruct(g, candidate.structName, candidate.pattern, candidate.segments)
+	}
+}
+
+// generateParentStruct generates a synthesized parent struct. It has the same methods and
+// borrowed view as a resource name struct, except for those describing the resource.
+func (r resourceNameCodeGenerator) generateParentStruct(
+	g *protogen.GeneratedFile,
+	typeName string,
+	pattern string,
+	segments []resourcename.Segment,
+) {
+	g.P("/// Parent of a ", r.resourceTypeName(), ": `", pattern, "`.")
+	g.P("#[derive(Debug, Clone, PartialEq, Eq, Hash)]")
+	g.P("pub struct ", typeName, " {")
+	for _, segment := range segments {
+		for _, variable := range segment.Variables() {
+			g.P("    pub ", toRustFieldName(variable), ": String,")
+		}
+	}
+	g.P("}")
+	g.P()
+
+	g.P("impl ", typeName, " {")
+	r.generateConstructor(g, typeName, segments)
+	generateValidateMethod(g)
+	generateAsBorrowedMethod(g, typeName, segments)
+	generateContainsWildcardMethod(g, segments)
+	generateWildcardMethods(g, segments)
+	g.P("}")
+	g.P()
+
+	generateDisplayTrait(g, typeName)
+	generateFromStrTrait(g, typeName)
+	r.generateBorrowedStruct(g, typeName, segments, "")
+}
+
+// generateParentMethods generates parent() on every struct of the resource with a nested
+// pattern, and on the enum of a multi-pattern resource.
+func (r resourceNameCodeGenerator) generateParentMethods(
+	g *protogen.GeneratedFile,
+	info parentInfo,
+	candidates []patternCandidate,
+) {
+	allNested := true
+	for _, candidate := range candidates {
+		parent, ok := parentPattern(candidate.pattern)
+		if !ok {
+			allNested = false
+			continue
+		}
+		parentCandidate := info.candidate(parent)
+		g.P("impl ", candidate.structName, " {")
+		g.P("    /// Returns the name of the parent of this resource.")
+		g.P("    pub fn parent(&self) -> ", parentCandidate.structName, " {")
+		g.P("        ", parentCandidate.structName, " {")
+		for _, segment := range parentCandidate.segments {
+			for _, variable := range segment.Variables() {
+				fieldName := toRustFieldName(variable)
+				g.P("            ", fieldName, ": self.", fieldName, ".clone(),")
+			}
+		}
+		g.P("        }")
+		g.P("    }")
+		g.P("}")
+		g.P()
+	}
+
+	if !r.isMultiPattern() {
+		return
+	}
+	// The parents of unknown names are not known either
+	if r.hasUnknownVariant() {
+		allNested = false
+	}
+	enumName := r.resourceTypeName()
+	returnType := info.typeName
+	if !allNested {
+		returnType = "Option<" + info.typeName + ">"
+	}
+	g.P("impl ", enumName, " {")
+	switch {
+	case allNested:
+		g.P("    /// Returns the name of the parent of this resource.")
+	case r.hasUnknownVariant():
+		g.P("    /// Returns the name of the parent of this resource, or None for top-level patterns")
+		g.P("    /// and unknown names.")
+	default:
+		g.P("    /// Returns the name of the parent of this resource, or None for top-level patterns.")
+	}
+	g.P("    pub fn parent(&self) -> ", returnType, " {")
+	g.P("        match self {")
+	for _, candidate := range candidates {
+		parent, ok := parentPattern(candidate.pattern)
+		if !ok {
+			g.P("            ", enumName, "::", candidate.variantName, "(_) => None,")
+			continue
+		}
+		expr := info.wrap(info.candidate(parent), "inner.parent()")
+		if !allNested {
+			expr = "Some(" + expr + ")"
+		}
+		g.P("            ", enumName, "::", candidate.variantName, "(inner) => ", expr, ",")
+	}
+	if r.hasUnknownVariant() {
+		g.P("            ", enumName, "::", unknownVariantName, "(_) => None,")
+	}
+	g.P("        }")
+	g.P("    }")
+	g.P("}")
+	g.P()
+}
+
+// generateChildConstructors generates, on each synthesized parent struct, a constructor for
+// the name of the resource under that parent. Parents that are resources generated in this
+// run get theirs with the other ancestors, see generateAncestry.
+func (r resourceNameCodeGenerator) generateChildConstructors(
+	g *protogen.GeneratedFile,
+	info parentInfo,
+	candidates []patternCandidate,
+) {
+	var links []ancestryLink
+	for _, candidate := range candidates {
+		if parent, ok := parentPattern(candidate.pattern); ok {
+			links = append(links, ancestryLink{ancestor: info.candidate(parent), descendant: candidate})
+		}
+	}
+	r.generateNestedConstructors(g, info.typeName, info.isEnum, info.candidates, links)
+}
+
+// variableNames returns the Rust field names of the variables of a pattern, in order.
+func variableNames(segments []resourcename.Segment) []string {
+	var names []string
+	for _, segment := range segments {
+		for _, variable := range segment.Variables() {
+			names = append(names, toRustFieldName(variable))
+		}
+	}
+	return names
+}
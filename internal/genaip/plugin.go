@@ -17,10 +17,23 @@ const generatedFilenameSuffix = "_aip.rs"
 
 type Config struct {
 	IncludeResourceDefinitions bool
+	MessageAccessors           bool
+	ProstModule                string
+	Serde                      SerdeMode
+	ServiceNames               ServiceNames
+	Revisions                  ResourceTypes
+	Validation                 ValidationProfile
 }
 
 // Run the Rust AIP protobuf compiler plugin.
 func Run(gen *protogen.Plugin, config Config) error {
+	if err := config.Serde.validate(); err != nil {
+		return err
+	}
+	if err := config.Validation.validate(); err != nil {
+		return err
+	}
+
 	var files protoregistry.Files
 	var generatedFiles []string
 
@@ -30,6 +43,8 @@ func Run(gen *protogen.Plugin, config Config) error {
 		}
 	}
 
+	index := newResourceIndex(gen, &files, config)
+
 	for _, file := range gen.Files {
 		if !file.Generate {
 			continue
@@ -44,7 +59,11 @@ func Run(gen *protogen.Plugin, config Config) error {
 		var rangeErr error
 		rangeResourcesInFile(
 			file.Desc,
-			func(resource *annotations.ResourceDescriptor, extension protoreflect.ExtensionType) bool {
+			func(
+				resource *annotations.ResourceDescriptor,
+				message protoreflect.MessageDescriptor,
+				extension protoreflect.ExtensionType,
+			) bool {
 				if !config.IncludeResourceDefinitions && extension == annotations.E_ResourceDefinition {
 					return true
 				}
@@ -55,8 +74,11 @@ func Run(gen *protogen.Plugin, config Config) error {
 
 				if err := (resourceNameCodeGenerator{
 					resource: resource,
+					message:  message,
 					files:    &files,
 					file:     file,
+					index:    index,
+					config:   config,
 				}).GenerateCode(g); err != nil {
 					rangeErr = err
 					return false
@@ -68,6 +90,13 @@ func Run(gen *protogen.Plugin, config Config) error {
 			return rangeErr
 		}
 
+		// Opt-in like the resource message accessors, which share the path of the prost types
+		references := referenceAccessorGenerator{index: index, prostModule: config.ProstModule}
+		if config.MessageAccessors && references.GenerateCode(g, file) {
+			g.Unskip()
+			hasResources = true
+		}
+
 		// Track generated files that contain resources
 		if hasResources {
 			generatedFiles = append(generatedFiles, file.GeneratedFilenamePrefix+generatedFilenameSuffix)
@@ -76,7 +105,7 @@ func Run(gen *protogen.Plugin, config Config) error {
 
 	// Generate the consolidated include_aip.rs file if we have any generated files
 	if len(generatedFiles) > 0 {
-		if err := generateIncludeAipFile(gen, generatedFiles); err != nil {
+		if err := generateIncludeAipFile(gen, generatedFiles, index, config); err != nil {
 			return err
 		}
 	}
@@ -84,7 +113,7 @@ func Run(gen *protogen.Plugin, config Config) error {
 	return nil
 }
 
-func generateIncludeAipFile(gen *protogen.Plugin, generatedFiles []string) error {
+func generateIncludeAipFile(gen *protogen.Plugin, generatedFiles []string, index resourceIndex, config Config) error {
 	g := gen.NewGeneratedFile("include_aip.rs", "")
 	g.P("// Code generated by ", PluginName, ". DO NOT EDIT.")
 	g.P("//")
@@ -102,9 +131,16 @@ func generateIncludeAipFile(gen *protogen.Plugin, generatedFiles []string) error
 	g.P("use std::str::FromStr;")
 	g.P()
 
+	// Shared by every included file, so they are imported once here
+	generateRuntimeImports(g, config)
+
 	for _, file := range generatedFiles {
 		g.P("include!(\"", file, "\");")
 	}
+	g.P()
+
+	// Resolves names of all included resources by type or by pattern
+	generateRegistry(g, index)
 
 	return nil
 }
@@ -130,23 +166,28 @@ func getProtocVersion(gen *protogen.Plugin) string {
 
 func rangeResourcesInFile(
 	file protoreflect.FileDescriptor,
-	fn func(resource *annotations.ResourceDescriptor, extension protoreflect.ExtensionType) bool,
+	fn func(
+		resource *annotations.ResourceDescriptor,
+		message protoreflect.MessageDescriptor,
+		extension protoreflect.ExtensionType,
+	) bool,
 ) {
 	for _, resource := range proto.GetExtension(
 		file.Options(), annotations.E_ResourceDefinition,
 	).([]*annotations.ResourceDescriptor) {
-		if !fn(resource, annotations.E_ResourceDefinition) {
+		if !fn(resource, nil, annotations.E_ResourceDefinition) {
 			return
 		}
 	}
 	for i := 0; i < file.Messages().Len(); i++ {
+		message := file.Messages().Get(i)
 		resource := proto.GetExtension(
-			file.Messages().Get(i).Options(), annotations.E_Resource,
+			message.Options(), annotations.E_Resource,
 		).(*annotations.ResourceDescriptor)
 		if resource == nil {
 			continue
 		}
-		if !fn(resource, annotations.E_Resource) {
+		if !fn(resource, message, annotations.E_Resource) {
 			return
 		}
 	}
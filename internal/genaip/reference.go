@@ -0,0 +1,231 @@
+package genaip
+
+import (
+	"sort"
+	"strings"
+
+	"github.com/AnteWall/protoc-gen-rust-aip/pkg/resourcename"
+	"google.golang.org/genproto/googleapis/api/annotations"
+	"google.golang.org/protobuf/compiler/protogen"
+	"google.golang.org/protobuf/proto"
+	"google.golang.org/protobuf/reflect/protoreflect"
+	"google.golang.org/protobuf/reflect/protoregistry"
+)
+
+// resourceIndex maps resource types to the descriptors of the resources generated in this run.
+type resourceIndex map[string]*annotations.ResourceDescriptor
+
+// newResourceIndex indexes the resources of all files in the registry that are generated
+// in this run, so that references can be resolved across files.
+func newResourceIndex(gen *protogen.Plugin, files *protoregistry.Files, config Config) resourceIndex {
+	index := resourceIndex{}
+	files.RangeFiles(func(file protoreflect.FileDescriptor) bool {
+		if f, ok := gen.FilesByPath[file.Path()]; !ok || !f.Generate {
+			return true
+		}
+		rangeResourcesInFile(
+			file,
+			func(
+				resource *annotations.ResourceDescriptor,
+				_ protoreflect.MessageDescriptor,
+				extension protoreflect.ExtensionType,
+			) bool {
+				if !config.IncludeResourceDefinitions && extension == annotations.E_ResourceDefinition {
+					return true
+				}
+				if _, ok := index[resource.GetType()]; !ok && len(resource.GetPattern()) > 0 {
+					index[resource.GetType()] = resource
+				}
+				return true
+			},
+		)
+		return true
+	})
+	return index
+}
+
+// referenceTypeName returns the name of the generated type that holds the names a
+// resource_reference field refers to, or false if the reference cannot be resolved to a
+// resource generated in this run. References to any type hold an untyped name.
+func (index resourceIndex) referenceTypeName(reference *annotations.ResourceReference) (string, bool) {
+	if reference.GetType() == "*" {
+		return untypedResourceNameType, true
+	}
+	if reference.GetType() != "" {
+		resource, ok := index[reference.GetType()]
+		if !ok {
+			return "", false
+		}
+		return (&resourceNameCodeGenerator{resource: resource}).resourceTypeName(), true
+	}
+	child, ok := index[reference.GetChildType()]
+	if !ok || len(parentPatterns(child.GetPattern())) == 0 {
+		return "", false
+	}
+	// A child_type reference holds the name of a parent of the child
+	return (&resourceNameCodeGenerator{resource: child}).parentTypeName(), true
+}
+
+// resourceWithPatterns returns the resource whose patterns are exactly the given ones, or nil.
+func (index resourceIndex) resourceWithPatterns(patterns []string) *annotations.ResourceDescriptor {
+	for _, resourceType := range index.sortedTypes() {
+		if resource := index[resourceType]; equalPatterns(resource.GetPattern(), patterns) {
+			return resource
+		}
+	}
+	return nil
+}
+
+// sortedTypes returns the indexed resource types in lexical order.
+func (index resourceIndex) sortedTypes() []string {
+	types := make([]string, 0, len(index))
+	for resourceType := range index {
+		types = append(types, resourceType)
+	}
+	sort.Strings(types)
+	return types
+}
+
+// parentPatterns returns the distinct parent patterns of a resource's patterns, in order.
+// Top-level patterns have no parent and are skipped.
+func parentPatterns(patterns []string) []string {
+	var parents []string
+	seen := map[string]bool{}
+	for _, pattern := range patterns {
+		parent, ok := parentPattern(pattern)
+		if ok && !seen[parent] {
+			seen[parent] = true
+			parents = append(parents, parent)
+		}
+	}
+	return parents
+}
+
+// parentPattern returns the pattern of the parent of a pattern, which drops its last
+// collection/id pair, or only the last segment for singletons. It returns false for
+// top-level patterns.
+func parentPattern(pattern string) (string, bool) {
+	parts, err := resourcename.SplitPattern(pattern)
+	if err != nil {
+		return "", false
+	}
+	drop := 1
+	if strings.HasPrefix(parts[len(parts)-1], "{") {
+		drop = 2
+	}
+	if len(parts) <= drop {
+		return "", false
+	}
+	return strings.Join(parts[:len(parts)-drop], "/"), true
+}
+
+// equalPatterns reports whether two lists hold the same patterns, ignoring order.
+func equalPatterns(a, b []string) bool {
+	if len(a) != len(b) {
+		return false
+	}
+	set := map[string]bool{}
+	for _, pattern := range a {
+		set[pattern] = true
+	}
+	for _, pattern := range b {
+		if !set[pattern] {
+			return false
+		}
+	}
+	return true
+}
+
+// referenceAccessorGenerator generates typed accessors for the resource_reference fields
+// of the messages in a file.
+type referenceAccessorGenerator struct {
+	index       resourceIndex
+	prostModule string
+}
+
+// GenerateCode generates the accessors for all messages in the file and reports whether
+// any code was generated.
+func (r referenceAccessorGenerator) GenerateCode(g *protogen.GeneratedFile, file *protogen.File) bool {
+	generated := false
+	for _, message := range file.Messages {
+		if r.generateMessage(g, message) {
+			generated = true
+		}
+	}
+	return generated
+}
+
+func (r referenceAccessorGenerator) generateMessage(g *protogen.GeneratedFile, message *protogen.Message) bool {
+	generated := false
+	if message.Desc.IsMapEntry() {
+		return false
+	}
+
+	var fields []*protogen.Field
+	for _, field := range message.Fields {
+		if _, ok := r.referencedTypeName(field); ok {
+			fields = append(fields, field)
+		}
+	}
+	if len(fields) > 0 {
+		generated = true
+		g.P("impl ", prostTypePath(r.prostModule, message.Desc), " {")
+		for i, field := range fields {
+			if i > 0 {
+				g.P()
+			}
+			r.generateFieldAccessor(g, field)
+		}
+		g.P("}")
+		g.P()
+	}
+
+	for _, nested := range message.Messages {
+		if r.generateMessage(g, nested) {
+			generated = true
+		}
+	}
+	return generated
+}
+
+// referencedTypeName returns the name of the generated type a field refers to, or false if
+// the field has no resource_reference that can be resolved, or a prost type that cannot hold one.
+func (r referenceAccessorGenerator) referencedTypeName(field *protogen.Field) (string, bool) {
+	if field.Desc.Kind() != protoreflect.StringKind || field.Desc.IsMap() {
+		return "", false
+	}
+	if oneof := field.Desc.ContainingOneof(); oneof != nil && !oneof.IsSynthetic() {
+		return "", false
+	}
+	reference := proto.GetExtension(
+		field.Desc.Options(), annotations.E_ResourceReference,
+	).(*annotations.ResourceReference)
+	if reference == nil {
+		return "", false
+	}
+	return r.index.referenceTypeName(reference)
+}
+
+func (r referenceAccessorGenerator) generateFieldAccessor(g *protogen.GeneratedFile, field *protogen.Field) {
+	typeName, _ := r.referencedTypeName(field)
+	fieldName := rustIdent(toRustFieldName(string(field.Desc.Name())))
+	methodName := toRustFieldName(string(field.Desc.Name()))
+
+	switch {
+	case field.Desc.IsList():
+		g.P("    /// Parses each element of the `", field.Desc.Name(), "` field as a ", typeName, ".")
+		g.P("    pub fn ", methodName, "_resources(&self) -> Result<Vec<", typeName, ">, ", resourceNameErrorType, "> {")
+		g.P("        self.", fieldName, ".iter().map(|name| name.parse()).collect()")
+		g.P("    }")
+	case field.Desc.HasOptionalKeyword():
+		g.P("    /// Parses the `", field.Desc.Name(), "` field, if set, as a ", typeName, ".")
+		g.P("    pub fn ", methodName, "_resource(&self) -> Result<Option<", typeName, ">, ", resourceNameErrorType, "> {")
+		g.P("        self.", fieldName, ".as_deref().map(str::parse).transpose()")
+		g.P("    }")
+	default:
+		g.P("    /// Parses the `", field.Desc.Name(), "` field as a ", typeName, ".")
+		g.P("    pub fn ", methodName, "_resource(&self) -> Result<", typeName, ", ", resourceNameErrorType, "> {")
+		g.P("        self.", fieldName, ".parse()")
+		g.P("    }")
+	}
+}
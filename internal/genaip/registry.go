@@ -0,0 +1,112 @@
+package genaip
+
+import (
+	"strconv"
+
+	"google.golang.org/protobuf/compiler/protogen"
+)
+
+// anyResourceNameType is the name of the enum over all resource names generated in a run.
+const anyResourceNameType = "AnyResourceName"
+
+// registryEntry describes a resource of the registry.
+type registryEntry struct {
+	resourceType string
+	typeName     string
+	variantName  string
+
+	// unknown is true when the type has an Unknown variant, which detect does not count.
+	unknown bool
+}
+
+// registryEntries returns the resources of the index, ordered by type.
+func (index resourceIndex) registryEntries() []registryEntry {
+	var entries []registryEntry
+	for _, resourceType := range index.sortedTypes() {
+		r := &resourceNameCodeGenerator{resource: index[resourceType]}
+		entries = append(entries, registryEntry{
+			resourceType: resourceType,
+			typeName:     r.resourceTypeName(),
+			variantName:  toPascalCase(r.getResourceKind()),
+			unknown:      r.hasUnknownVariant(),
+		})
+	}
+	return entries
+}
+
+// generateRegistry generates AnyResourceName, which holds the name of any resource
+// generated in this run, and the functions parsing names by resource type or by pattern.
+func generateRegistry(g *protogen.GeneratedFile, index resourceIndex) {
+	entries := index.registryEntries()
+	if len(entries) == 0 {
+		return
+	}
+
+	g.P("/// Name of any resource generated in this compilation.")
+	g.P("#[derive(Debug, Clone, PartialEq, Eq, Hash)]")
+	g.P("pub enum ", anyResourceNameType, " {")
+	for _, entry := range entries {
+		g.P("    ", entry.variantName, "(", entry.typeName, "),")
+	}
+	g.P("}")
+	g.P()
+
+	g.P("impl ", anyResourceNameType, " {")
+	g.P("    /// Returns the name as a ", dynResourceNameTrait, ".")
+	g.P("    pub fn as_dyn(&self) -> &dyn ", dynResourceNameTrait, " {")
+	g.P("        match self {")
+	for _, entry := range entries {
+		g.P("            ", anyResourceNameType, "::", entry.variantName, "(inner) => inner,")
+	}
+	g.P("        }")
+	g.P("    }")
+	g.P()
+	g.P("    /// Returns the resource type.")
+	g.P("    pub fn resource_type(&self) -> &'static str {")
+	g.P("        self.as_dyn().resource_type()")
+	g.P("    }")
+	g.P("}")
+	g.P()
+
+	g.P("impl fmt::Display for ", anyResourceNameType, " {")
+	g.P("    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {")
+	g.P("        fmt::Display::fmt(self.as_dyn(), f)")
+	g.P("    }")
+	g.P("}")
+	g.P()
+
+	for _, entry := range entries {
+		g.P("impl From<", entry.typeName, "> for ", anyResourceNameType, " {")
+		g.P("    fn from(name: ", entry.typeName, ") -> Self {")
+		g.P("        ", anyResourceNameType, "::", entry.variantName, "(name)")
+		g.P("    }")
+		g.P("}")
+		g.P()
+	}
+
+	g.P("/// Parses the name of a resource of the given type, e.g. `library.googleapis.com/Book`.")
+	g.P("pub fn parse_any(resource_type: &str, name: &str) -> Result<", anyResourceNameType, ", ", resourceNameErrorType, "> {")
+	g.P("    match resource_type {")
+	for _, entry := range entries {
+		g.P("        ", strconv.Quote(entry.resourceType), " => name.parse().map(", anyResourceNameType, "::", entry.variantName, "),")
+	}
+	g.P("        _ => Err(", resourceNameErrorType, "::unknown_type(resource_type)),")
+	g.P("    }")
+	g.P("}")
+	g.P()
+
+	g.P("/// Returns the type of every resource with a pattern that matches the name.")
+	g.P("pub fn detect(name: &str) -> Vec<&'static str> {")
+	g.P("    let mut types = Vec::new();")
+	for _, entry := range entries {
+		condition := borrowedTypeName(entry.typeName) + "::parse_borrowed(name).is_ok()"
+		if entry.unknown {
+			condition = borrowedTypeName(entry.typeName) + "::parse_borrowed(name).is_ok_and(|name| !name.is_unknown())"
+		}
+		g.P("    if ", condition, " {")
+		g.P("        types.push(", strconv.Quote(entry.resourceType), ");")
+		g.P("    }")
+	}
+	g.P("    types")
+	g.P("}")
+}
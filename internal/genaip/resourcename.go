@@ -8,6 +8,7 @@ import (
 	"github.com/AnteWall/protoc-gen-rust-aip/pkg/resourcename"
 	"google.golang.org/genproto/googleapis/api/annotations"
 	"google.golang.org/protobuf/compiler/protogen"
+	"google.golang.org/protobuf/reflect/protoreflect"
 	"google.golang.org/protobuf/reflect/protoregistry"
 )
 
@@ -15,12 +16,30 @@ type resourceNameCodeGenerator struct {
 	resource *annotations.ResourceDescriptor
 	file     *protogen.File
 	files    *protoregistry.Files
+	config   Config
+
+	// message is the message annotated with the resource, or nil for resource definitions.
+	message protoreflect.MessageDescriptor
+
+	// index holds all resources generated in this run, for relations between resources.
+	index resourceIndex
+
+	// rules holds the validation rules of the variables of the resource, by variable.
+	rules map[string]variableRule
 }
 
 func (r resourceNameCodeGenerator) GenerateCode(g *protogen.GeneratedFile) error {
 	if len(r.resource.GetPattern()) == 0 {
 		return nil
 	}
+	if err := r.checkPatterns(); err != nil {
+		return err
+	}
+	rules, err := r.variableRules()
+	if err != nil {
+		return err
+	}
+	r.rules = rules
 
 	hasMultiPattern := len(r.resource.GetPattern()) > 1
 	hasFutureMultiPattern := r.resource.GetHistory() == annotations.ResourceDescriptor_FUTURE_MULTI_PATTERN
@@ -30,9 +49,7 @@ func (r resourceNameCodeGenerator) GenerateCode(g *protogen.GeneratedFile) error
 		if err := r.generateMultiPatternTrait(g); err != nil {
 			return err
 		}
-		if err := r.generateMultiPatternParseFunction(g); err != nil {
-			return err
-		}
+		r.generateMultiPatternParseFunction(g)
 	}
 
 	// Generate the single-pattern struct only if this is truly a single-pattern resource
@@ -55,6 +72,22 @@ func (r resourceNameCodeGenerator) GenerateCode(g *protogen.GeneratedFile) error
 			}
 		}
 	}
+	if err := r.generateSinglePatternCompat(g); err != nil {
+		return err
+	}
+
+	if err := r.generateParent(g); err != nil {
+		return err
+	}
+	if err := r.generateAncestry(g); err != nil {
+		return err
+	}
+	r.generateRevisionName(g)
+
+	// Connect the annotated message to the generated type
+	if r.message != nil && r.config.MessageAccessors {
+		r.generateMessageAccessors(g)
+	}
 	return nil
 }
 
@@ -63,52 +96,62 @@ func (r resourceNameCodeGenerator) generatePatternStruct(
 	pattern string,
 	typeName string,
 ) error {
-	g.P("/// Resource name for ", r.resource.GetType())
-	g.P("#[derive(Debug, Clone, PartialEq, Eq, Hash)]")
-	g.P("pub struct ", typeName, " {")
-
 	var segments []resourcename.Segment
 	if err := resourcename.ParsePattern(pattern, &segments); err != nil {
 		return fmt.Errorf("failed to parse pattern %q: %w", pattern, err)
 	}
 
+	if resourcename.IsSingleton(segments) {
+		g.P("/// Resource name for ", r.resource.GetType(), ", a singleton of its parent (AIP-156).")
+	} else {
+		g.P("/// Resource name for ", r.resource.GetType())
+	}
+	if r.isOriginallySinglePattern() && pattern == r.resource.GetPattern()[0] {
+		g.P("///")
+		g.P("/// The original pattern of the resource, from before it had several patterns.")
+	}
+	g.P("#[derive(Debug, Clone, PartialEq, Eq, Hash)]")
+	g.P("pub struct ", typeName, " {")
+
 	for _, segment := range segments {
-		if segment.IsVariable() {
-			fieldName := toRustFieldName(segment.Literal())
+		for _, variable := range segment.Variables() {
+			fieldName := toRustFieldName(variable)
 			g.P("    pub ", fieldName, ": String,")
 		}
 	}
 	g.P("}")
 	g.P()
 
-	if err := r.generateImplBlock(g, pattern, typeName, segments); err != nil {
-		return err
-	}
+	r.generateImplBlock(g, typeName, segments)
+	generateDisplayTrait(g, typeName)
+	generateFromStrTrait(g, typeName)
 
-	if err := r.generateDisplayTrait(g, pattern, typeName, segments); err != nil {
-		return err
-	}
+	r.generateResourceNameTraitImpl(g, typeName, []string{pattern})
+	r.generateStructSerdeImpls(g, typeName, segments)
 
-	if err := r.generateFromStrTrait(g, pattern, typeName, segments); err != nil {
-		return err
+	var revisionType string
+	if r.config.Revisions[r.resource.GetType()] {
+		revisionType = r.revisionTypeName()
 	}
-
+	r.generateBorrowedStruct(g, typeName, segments, revisionType)
 	return nil
 }
 
 func (r resourceNameCodeGenerator) generateImplBlock(
 	g *protogen.GeneratedFile,
-	pattern string,
 	typeName string,
 	segments []resourcename.Segment,
-) error {
+) {
 	g.P("impl ", typeName, " {")
 
 	// Generate constructor
 	r.generateConstructor(g, typeName, segments)
 
 	// Generate validation method
-	r.generateValidateMethod(g, typeName, segments)
+	generateValidateMethod(g)
+
+	// Generate conversion to the borrowed view
+	generateAsBorrowedMethod(g, typeName, segments)
 
 	// Generate type method
 	g.P("    /// Returns the resource type.")
@@ -117,12 +160,20 @@ func (r resourceNameCodeGenerator) generateImplBlock(
 	g.P("    }")
 	g.P()
 
-	// Generate contains_wildcard method
-	r.generateContainsWildcardMethod(g, typeName, segments)
+	// Generate full resource name and URI methods
+	r.generateFullNameMethods(g)
+	r.generateURIMethods(g)
+
+	// Generate wildcard methods
+	generateContainsWildcardMethod(g, segments)
+	generateWildcardMethods(g, segments)
+
+	// Generate ancestry and glob tests
+	generateIsAncestorOfMethod(g)
+	generateMatchesGlobMethod(g, false)
 
 	g.P("}")
 	g.P()
-	return nil
 }
 
 func (r resourceNameCodeGenerator) generateConstructor(
@@ -135,8 +186,8 @@ func (r resourceNameCodeGenerator) generateConstructor(
 
 	var params []string
 	for _, segment := range segments {
-		if segment.IsVariable() {
-			fieldName := toRustFieldName(segment.Literal())
+		for _, variable := range segment.Variables() {
+			fieldName := toRustFieldName(variable)
 			params = append(params, fieldName+": impl Into<String>")
 		}
 	}
@@ -152,8 +203,8 @@ func (r resourceNameCodeGenerator) generateConstructor(
 	g.P("    ) -> Self {")
 	g.P("        Self {")
 	for _, segment := range segments {
-		if segment.IsVariable() {
-			fieldName := toRustFieldName(segment.Literal())
+		for _, variable := range segment.Variables() {
+			fieldName := toRustFieldName(variable)
 			g.P("            ", fieldName, ": ", fieldName, ".into(),")
 		}
 	}
@@ -162,42 +213,47 @@ func (r resourceNameCodeGenerator) generateConstructor(
 	g.P()
 }
 
-func (r resourceNameCodeGenerator) generateValidateMethod(
+func generateValidateMethod(g *protogen.GeneratedFile) {
+	g.P("    /// Validates the resource name.")
+	g.P("    pub fn validate(&self) -> Result<(), ", resourceNameErrorType, "> {")
+	g.P("        self.as_borrowed().validate()")
+	g.P("    }")
+	g.P()
+}
+
+func generateAsBorrowedMethod(
 	g *protogen.GeneratedFile,
 	typeName string,
 	segments []resourcename.Segment,
 ) {
-	g.P("    /// Validates the resource name.")
-	g.P("    pub fn validate(&self) -> Result<(), String> {")
+	borrowedName := borrowedTypeName(typeName)
+	g.P("    /// Borrows the resource name as a ", borrowedName, ".")
+	g.P("    pub fn as_borrowed(&self) -> ", borrowedName, "<'_> {")
+	g.P("        ", borrowedName, " {")
 	for _, segment := range segments {
-		if segment.IsVariable() {
-			fieldName := toRustFieldName(segment.Literal())
-			g.P("        if self.", fieldName, ".is_empty() {")
-			g.P("            return Err(\"", segment.Literal(), ": empty\".to_string());")
-			g.P("        }")
-			g.P("        if self.", fieldName, ".contains('/') {")
-			g.P("            return Err(\"", segment.Literal(), ": contains illegal character '/'\".to_string());")
-			g.P("        }")
+		for _, variable := range segment.Variables() {
+			fieldName := toRustFieldName(variable)
+			g.P("            ", fieldName, ": &self.", fieldName, ",")
 		}
 	}
-	g.P("        Ok(())")
+	g.P("        }")
 	g.P("    }")
 	g.P()
 }
 
-func (r resourceNameCodeGenerator) generateContainsWildcardMethod(
-	g *protogen.GeneratedFile,
-	typeName string,
-	segments []resourcename.Segment,
-) {
+func generateContainsWildcardMethod(g *protogen.GeneratedFile, segments []resourcename.Segment) {
 	g.P("    /// Returns true if any field contains a wildcard.")
 	g.P("    pub fn contains_wildcard(&self) -> bool {")
 
 	var conditions []string
 	for _, segment := range segments {
-		if segment.IsVariable() {
-			fieldName := toRustFieldName(segment.Literal())
-			conditions = append(conditions, "self."+fieldName+" == \"-\"")
+		for _, variable := range segment.Variables() {
+			fieldName := toRustFieldName(variable)
+			if segment.IsCapture() {
+				conditions = append(conditions, "self."+fieldName+".split('/').any(|segment| segment == \"-\")")
+			} else {
+				conditions = append(conditions, "self."+fieldName+" == \"-\"")
+			}
 		}
 	}
 
@@ -210,156 +266,162 @@ func (r resourceNameCodeGenerator) generateContainsWildcardMethod(
 	g.P()
 }
 
-func (r resourceNameCodeGenerator) generateDisplayTrait(
-	g *protogen.GeneratedFile,
-	pattern string,
-	typeName string,
-	segments []resourcename.Segment,
-) error {
+func generateDisplayTrait(g *protogen.GeneratedFile, typeName string) {
+	// The format string lives on the borrowed view, so both forms print the same name.
 	g.P("impl fmt::Display for ", typeName, " {")
 	g.P("    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {")
-
-	// Build the format string with inlined variables
-	var formatStr strings.Builder
-	first := true
-	for _, segment := range segments {
-		if !first {
-			formatStr.WriteString("/")
-		}
-		if segment.IsVariable() {
-			fieldName := toRustFieldName(segment.Literal())
-			formatStr.WriteString("{" + fieldName + "}")
-		} else {
-			formatStr.WriteString(segment.Literal())
-		}
-		first = false
-	}
-
-	// Use inlined format args for clippy compliance
-	g.P("        write!(f, \"", formatStr.String(), "\"")
-
-	// Add field references for inlined format
-	for _, segment := range segments {
-		if segment.IsVariable() {
-			fieldName := toRustFieldName(segment.Literal())
-			g.P("            , ", fieldName, " = self.", fieldName)
-		}
-	}
-
-	g.P("        )")
+	g.P("        fmt::Display::fmt(&self.as_borrowed(), f)")
 	g.P("    }")
 	g.P("}")
 	g.P()
-	return nil
 }
 
-func (r resourceNameCodeGenerator) generateFromStrTrait(
-	g *protogen.GeneratedFile,
-	pattern string,
-	typeName string,
-	segments []resourcename.Segment,
-) error {
+func generateFromStrTrait(g *protogen.GeneratedFile, typeName string) {
 	g.P("impl FromStr for ", typeName, " {")
-	g.P("    type Err = String;")
+	g.P("    type Err = ", resourceNameErrorType, ";")
 	g.P()
 	g.P("    fn from_str(s: &str) -> Result<Self, Self::Err> {")
-
-	// Generate pattern matching logic
-	g.P("        let parts: Vec<&str> = s.split('/').collect();")
-
-	// Count expected parts
-	expectedParts := len(segments)
-
-	g.P("        if parts.len() != ", expectedParts, " {")
-	g.P("            return Err(format!(\"expected {expected_parts} parts, got {}\", parts.len(), expected_parts = ", expectedParts, "));")
-	g.P("        }")
-
-	// Extract variables
-	for i, segment := range segments {
-		if segment.IsVariable() {
-			fieldName := toRustFieldName(segment.Literal())
-			g.P("        let ", fieldName, " = parts[", i, "].to_string();")
-		} else {
-			g.P("        if parts[", i, "] != ", strconv.Quote(segment.Literal()), " {")
-			g.P("            return Err(format!(\"expected '", segment.Literal(), "' at position ", i, ", got '{part}'\", part = parts[", i, "]));")
-			g.P("        }")
-		}
-	}
-
-	g.P("        let result = Self {")
-	for _, segment := range segments {
-		if segment.IsVariable() {
-			fieldName := toRustFieldName(segment.Literal())
-			g.P("            ", fieldName, ",")
-		}
-	}
-	g.P("        };")
-	g.P("        result.validate()?;")
-	g.P("        Ok(result)")
+	g.P("        ", borrowedTypeName(typeName), "::parse_borrowed(s).map(", borrowedTypeName(typeName), "::to_owned)")
 	g.P("    }")
 	g.P("}")
 	g.P()
-	return nil
 }
 
 func (r resourceNameCodeGenerator) generateMultiPatternTrait(g *protogen.GeneratedFile) error {
+	candidates, err := r.patternCandidates()
+	if err != nil {
+		return err
+	}
+
 	// Generate an enum instead of a trait for object-safety
 	enumName := r.MultiPatternEnumName()
+	unknown := r.hasUnknownVariant()
 	g.P("/// Multi-pattern resource name for ", r.resource.GetType())
 	g.P("#[derive(Debug, Clone, PartialEq, Eq, Hash)]")
+	if unknown {
+		g.P("#[non_exhaustive]")
+	}
 	g.P("pub enum ", enumName, " {")
 
-	for _, pattern := range r.resource.GetPattern() {
-		structName := r.MultiPatternStructName(pattern)
-		variantName := r.getPatternVariantName(pattern)
-		g.P("    ", variantName, "(", structName, "),")
+	for _, candidate := range candidates {
+		g.P("    ", candidate.variantName, "(", candidate.structName, "),")
+	}
+	if unknown {
+		g.P("    /// A well-formed name that matches none of the known patterns. It has no parent")
+		g.P("    /// and matches no glob.")
+		g.P("    ", unknownVariantName, "(", untypedResourceNameType, "),")
 	}
 
 	g.P("}")
 	g.P()
 
 	// Generate implementations for the enum
-	r.generateMultiPatternEnumImpls(g, enumName)
-
-	return nil
-}
-
-func (r resourceNameCodeGenerator) generateMultiPatternEnumImpls(g *protogen.GeneratedFile, enumName string) {
 	g.P("impl ", enumName, " {")
 	g.P("    /// Returns the resource type.")
 	g.P("    pub fn resource_type(&self) -> &'static str {")
 	g.P("        ", strconv.Quote(r.resource.GetType()))
 	g.P("    }")
 	g.P()
+	if unknown {
+		generateIsUnknownMethod(g, enumName)
+	}
+	generateEnumMethods(g, enumName, candidates, unknown)
+	g.P()
+	generateIsAncestorOfMethod(g)
+	generateMatchesGlobMethod(g, unknown)
+	r.generateEnumFullNameMethods(g, enumName, candidates)
+	r.generateEnumURIMethods(g, enumName, candidates)
+	g.P("}")
+	g.P()
+	generateEnumTraits(g, enumName, candidates, unknown)
+	r.generateResourceNameTraitImpl(g, enumName, r.resource.GetPattern())
+	r.generateEnumSerdeImpls(g, enumName, candidates, unknown)
+
+	generateBorrowedEnum(g, enumName, candidates, unknown)
+	return nil
+}
+
+// generateEnumMethods generates the methods shared by all generated enums, which
+// delegate to the struct of each variant, or to the UntypedResourceName of an Unknown variant.
+func generateEnumMethods(g *protogen.GeneratedFile, enumName string, candidates []patternCandidate, unknown bool) {
 	g.P("    /// Returns true if any field contains a wildcard.")
 	g.P("    pub fn contains_wildcard(&self) -> bool {")
 	g.P("        match self {")
-	for _, pattern := range r.resource.GetPattern() {
-		variantName := r.getPatternVariantName(pattern)
-		g.P("            ", enumName, "::", variantName, "(inner) => inner.contains_wildcard(),")
+	for _, candidate := range candidates {
+		g.P("            ", enumName, "::", candidate.variantName, "(inner) => inner.contains_wildcard(),")
+	}
+	if unknown {
+		g.P("            ", enumName, "::", unknownVariantName, "(name) => name.contains_wildcard(),")
 	}
 	g.P("        }")
 	g.P("    }")
 	g.P()
+	generateEnumWildcardMethods(g, enumName, candidates, unknown)
 	g.P("    /// Validates the resource name.")
-	g.P("    pub fn validate(&self) -> Result<(), String> {")
+	g.P("    pub fn validate(&self) -> Result<(), ", resourceNameErrorType, "> {")
 	g.P("        match self {")
-	for _, pattern := range r.resource.GetPattern() {
-		variantName := r.getPatternVariantName(pattern)
-		g.P("            ", enumName, "::", variantName, "(inner) => inner.validate(),")
+	for _, candidate := range candidates {
+		g.P("            ", enumName, "::", candidate.variantName, "(inner) => inner.validate(),")
+	}
+	if unknown {
+		// Unknown names are validated when parsed and cannot be modified
+		g.P("            ", enumName, "::", unknownVariantName, "(_) => Ok(()),")
 	}
 	g.P("        }")
 	g.P("    }")
-	g.P("}")
 	g.P()
+	g.P("    /// Borrows the resource name as a ", borrowedTypeName(enumName), ".")
+	g.P("    pub fn as_borrowed(&self) -> ", borrowedTypeName(enumName), "<'_> {")
+	g.P("        match self {")
+	for _, candidate := range candidates {
+		g.P("            ", enumName, "::", candidate.variantName, "(inner) => ", borrowedTypeName(enumName), "::", candidate.variantName, "(inner.as_borrowed()),")
+	}
+	if unknown {
+		g.P("            ", enumName, "::", unknownVariantName, "(name) => ", borrowedTypeName(enumName), "::", unknownVariantName, "(name.as_borrowed()),")
+	}
+	g.P("        }")
+	g.P("    }")
+}
+
+// generateIsAncestorOfMethod generates is_ancestor_of, which makes the ResourceName
+// method available without importing the trait.
+func generateIsAncestorOfMethod(g *protogen.GeneratedFile) {
+	g.P("    /// Returns true if `other` is the name of a resource nested under this one.")
+	g.P("    pub fn is_ancestor_of(&self, other: &str) -> bool {")
+	g.P("        ", resourceNameTrait, "::is_ancestor_of(self, other)")
+	g.P("    }")
+	g.P()
+}
 
+// generateMatchesGlobMethod generates matches_glob, which makes the ResourceName method
+// available without importing the trait. Globs are compiled against the known patterns, so
+// on an enum with an Unknown variant the docs say that its names match no glob.
+func generateMatchesGlobMethod(g *protogen.GeneratedFile, unknown bool) {
+	g.P("    /// Returns true if the name matches a glob such as `projects/acme/books/*`, where `*`")
+	g.P("    /// matches one segment and a final `**` any suffix. Globs that cannot match names of")
+	g.P("    /// this type match nothing.")
+	if unknown {
+		g.P("    ///")
+		g.P("    /// Globs only match names of the known patterns, so an ", unknownVariantName, " name")
+		g.P("    /// matches no glob, not even `**`.")
+	}
+	g.P("    pub fn matches_glob(&self, glob: &str) -> bool {")
+	g.P("        ", resourceNameTrait, "::matches_glob(self, glob)")
+	g.P("    }")
+	g.P()
+}
+
+// generateEnumTraits generates the Display and FromStr implementations of a generated enum.
+func generateEnumTraits(g *protogen.GeneratedFile, enumName string, candidates []patternCandidate, unknown bool) {
 	// Generate Display trait
 	g.P("impl fmt::Display for ", enumName, " {")
 	g.P("    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {")
 	g.P("        match self {")
-	for _, pattern := range r.resource.GetPattern() {
-		variantName := r.getPatternVariantName(pattern)
-		g.P("            ", enumName, "::", variantName, "(inner) => write!(f, \"{inner}\"),")
+	for _, candidate := range candidates {
+		g.P("            ", enumName, "::", candidate.variantName, "(inner) => write!(f, \"{inner}\"),")
+	}
+	if unknown {
+		g.P("            ", enumName, "::", unknownVariantName, "(name) => write!(f, \"{name}\"),")
 	}
 	g.P("        }")
 	g.P("    }")
@@ -368,30 +430,22 @@ func (r resourceNameCodeGenerator) generateMultiPatternEnumImpls(g *protogen.Gen
 
 	// Generate FromStr trait
 	g.P("impl FromStr for ", enumName, " {")
-	g.P("    type Err = String;")
+	g.P("    type Err = ", resourceNameErrorType, ";")
 	g.P()
 	g.P("    fn from_str(s: &str) -> Result<Self, Self::Err> {")
-	for _, pattern := range r.resource.GetPattern() {
-		structName := r.MultiPatternStructName(pattern)
-		variantName := r.getPatternVariantName(pattern)
-		g.P("        if let Ok(parsed) = ", structName, "::from_str(s) {")
-		g.P("            return Ok(", enumName, "::", variantName, "(parsed));")
-		g.P("        }")
-	}
-	g.P("        Err(\"no matching pattern\".to_string())")
+	g.P("        ", borrowedTypeName(enumName), "::parse_borrowed(s).map(", borrowedTypeName(enumName), "::to_owned)")
 	g.P("    }")
 	g.P("}")
 	g.P()
 }
 
-func (r resourceNameCodeGenerator) generateMultiPatternParseFunction(g *protogen.GeneratedFile) error {
+func (r resourceNameCodeGenerator) generateMultiPatternParseFunction(g *protogen.GeneratedFile) {
 	enumName := r.MultiPatternEnumName()
 	g.P("/// Parses a resource name string and returns the appropriate type.")
-	g.P("pub fn parse_", toSnakeCase(r.getResourceKind()), "_resource_name(name: &str) -> Result<", enumName, ", String> {")
+	g.P("pub fn parse_", toSnakeCase(r.getResourceKind()), "_resource_name(name: &str) -> Result<", enumName, ", ", resourceNameErrorType, "> {")
 	g.P("    ", enumName, "::from_str(name)")
 	g.P("}")
 	g.P()
-	return nil
 }
 
 func (r *resourceNameCodeGenerator) SinglePatternStructName() string {
@@ -403,8 +457,8 @@ func (r *resourceNameCodeGenerator) MultiPatternStructName(pattern string) strin
 		var result strings.Builder
 		var segments []resourcename.Segment
 		if err := resourcename.ParsePattern(pattern, &segments); err == nil {
-			for _, segment := range segments {
-				if !segment.IsVariable() && segment.Literal() != r.resource.GetPlural() {
+			for _, segment := range expandedSegments(namingSegments(segments)) {
+				if !segment.HasVariables() && !segment.IsWildcard() && segment.Literal() != r.resource.GetPlural() {
 					result.WriteString(toPascalCase(segment.Literal()))
 				}
 			}
@@ -413,7 +467,7 @@ func (r *resourceNameCodeGenerator) MultiPatternStructName(pattern string) strin
 		// If no prefix found, use the collection name
 		if result.Len() == 0 {
 			if err := resourcename.ParsePattern(pattern, &segments); err == nil && len(segments) > 0 {
-				if !segments[0].IsVariable() {
+				if !segments[0].HasVariables() {
 					result.WriteString(toPascalCase(segments[0].Literal()))
 				} else {
 					result.WriteString("Simple")
@@ -432,20 +486,30 @@ func (r *resourceNameCodeGenerator) MultiPatternStructName(pattern string) strin
 	return r.MultiPatternStructName(pattern)
 }
 
-func (r *resourceNameCodeGenerator) MultiPatternTraitName() string {
+func (r *resourceNameCodeGenerator) MultiPatternEnumName() string {
 	return toPascalCase(r.getResourceKind()) + "ResourceName"
 }
 
-func (r *resourceNameCodeGenerator) MultiPatternEnumName() string {
-	return toPascalCase(r.getResourceKind()) + "ResourceName"
+// resourceTypeName returns the name of the generated type that can hold any name of the resource.
+func (r *resourceNameCodeGenerator) resourceTypeName() string {
+	if r.isMultiPattern() {
+		return r.MultiPatternEnumName()
+	}
+	return r.SinglePatternStructName()
+}
+
+// isMultiPattern reports whether the resource is generated as an enum.
+func (r *resourceNameCodeGenerator) isMultiPattern() bool {
+	return len(r.resource.GetPattern()) > 1 ||
+		r.resource.GetHistory() == annotations.ResourceDescriptor_FUTURE_MULTI_PATTERN
 }
 
 func (r *resourceNameCodeGenerator) getPatternVariantName(pattern string) string {
 	var result strings.Builder
 	var segments []resourcename.Segment
 	if err := resourcename.ParsePattern(pattern, &segments); err == nil {
-		for _, segment := range segments {
-			if !segment.IsVariable() && segment.Literal() != r.resource.GetPlural() {
+		for _, segment := range expandedSegments(namingSegments(segments)) {
+			if !segment.HasVariables() && !segment.IsWildcard() && segment.Literal() != r.resource.GetPlural() {
 				result.WriteString(toPascalCase(segment.Literal()))
 			}
 		}
@@ -454,7 +518,7 @@ func (r *resourceNameCodeGenerator) getPatternVariantName(pattern string) string
 		// If no prefix was found, generate a name based on the pattern structure
 		// For patterns like "authors/{author}", use the collection as the variant name
 		if err := resourcename.ParsePattern(pattern, &segments); err == nil && len(segments) > 0 {
-			if !segments[0].IsVariable() {
+			if !segments[0].HasVariables() {
 				result.WriteString(toPascalCase(segments[0].Literal()))
 			} else {
 				result.WriteString("Simple")
@@ -466,6 +530,16 @@ func (r *resourceNameCodeGenerator) getPatternVariantName(pattern string) string
 	return result.String()
 }
 
+// namingSegments returns the segments whose literals tell a pattern apart from the others
+// of its resource. The trailing literal of a singleton names the resource itself, like the
+// plural of a collection, so it is left out.
+func namingSegments(segments []resourcename.Segment) []resourcename.Segment {
+	if resourcename.IsSingleton(segments) {
+		return segments[:len(segments)-1]
+	}
+	return segments
+}
+
 func (r *resourceNameCodeGenerator) getResourceKind() string {
 	parts := strings.Split(r.resource.GetType(), "/")
 	if len(parts) >= 2 {
@@ -476,7 +550,8 @@ func (r *resourceNameCodeGenerator) getResourceKind() string {
 
 // Helper functions for Rust naming conventions
 func toRustFieldName(s string) string {
-	return toSnakeCase(s)
+	// Variables can be field paths, e.g. {book.name}
+	return strings.ReplaceAll(toSnakeCase(s), ".", "_")
 }
 
 func toSnakeCase(s string) string {
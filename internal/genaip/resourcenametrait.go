@@ -0,0 +1,37 @@
+package genaip
+
+import (
+	"strconv"
+	"strings"
+
+	"google.golang.org/protobuf/compiler/protogen"
+)
+
+// generateResourceNameTraitImpl implements the ResourceName trait for a generated type
+// that can hold the given patterns. The methods delegate to the inherent ones.
+func (r resourceNameCodeGenerator) generateResourceNameTraitImpl(
+	g *protogen.GeneratedFile,
+	typeName string,
+	patterns []string,
+) {
+	quotedPatterns := make([]string, 0, len(patterns))
+	for _, pattern := range patterns {
+		quotedPatterns = append(quotedPatterns, strconv.Quote(pattern))
+	}
+
+	g.P("impl ", resourceNameTrait, " for ", typeName, " {")
+	g.P("    const TYPE: &'static str = ", strconv.Quote(r.resource.GetType()), ";")
+	g.P("    const PATTERNS: &'static [&'static str] = &[", strings.Join(quotedPatterns, ", "), "];")
+	g.P("    const SINGULAR: &'static str = ", strconv.Quote(r.resource.GetSingular()), ";")
+	g.P("    const PLURAL: &'static str = ", strconv.Quote(r.resource.GetPlural()), ";")
+	g.P()
+	g.P("    fn validate(&self) -> Result<(), ", resourceNameErrorType, "> {")
+	g.P("        self.validate()")
+	g.P("    }")
+	g.P()
+	g.P("    fn contains_wildcard(&self) -> bool {")
+	g.P("        self.contains_wildcard()")
+	g.P("    }")
+	g.P("}")
+	g.P()
+}
@@ -0,0 +1,131 @@
+package genaip
+
+import (
+	"sort"
+	"strconv"
+	"strings"
+
+	"github.com/AnteWall/protoc-gen-rust-aip/pkg/resourcename"
+	"google.golang.org/protobuf/compiler/protogen"
+)
+
+// ResourceTypes is a set of resource types. It implements flag.Value, and each value adds
+// a single resource type, e.g. library.googleapis.com/Book.
+type ResourceTypes map[string]bool
+
+// String implements flag.Value.
+func (s ResourceTypes) String() string {
+	types := make([]string, 0, len(s))
+	for resourceType := range s {
+		types = append(types, resourceType)
+	}
+	sort.Strings(types)
+	return strings.Join(types, ",")
+}
+
+// Set implements flag.Value.
+func (s ResourceTypes) Set(value string) error {
+	s[value] = true
+	return nil
+}
+
+// revisionTypeName returns the name of the type holding revision-qualified names of the resource.
+func (r *resourceNameCodeGenerator) revisionTypeName() string {
+	return toPascalCase(r.getResourceKind()) + "RevisionName"
+}
+
+// generateRevisionSeparatorCheck generates the check that the last ID of a name of a resource
+// with revisions has no @, which would display as a different revision of the resource. Names
+// with a revision parse as revisionType instead, which is empty without revisions.
+func generateRevisionSeparatorCheck(g *protogen.GeneratedFile, segments []resourcename.Segment, revisionType string) {
+	if revisionType == "" || len(segments) == 0 {
+		return
+	}
+	variables := segments[len(segments)-1].Variables()
+	if len(variables) == 0 {
+		return
+	}
+	variable := variables[len(variables)-1]
+	g.P("        ", runtimeCrate, "::reject_revision_separator(", strconv.Quote(variable), ", self.", toRustFieldName(variable),
+		", ", strconv.Quote(revisionType), ")?;")
+}
+
+// generateRevisionName generates the revision-qualified name of the resource, see AIP-162,
+// if revisions are enabled for it.
+func (r resourceNameCodeGenerator) generateRevisionName(g *protogen.GeneratedFile) {
+	if !r.config.Revisions[r.resource.GetType()] {
+		return
+	}
+	typeName := r.revisionTypeName()
+	nameType := r.resourceTypeName()
+
+	g.P("/// Revision-qualified name of a ", nameType, ", e.g. `name@revision`.")
+	g.P("#[derive(Debug, Clone, PartialEq, Eq, Hash)]")
+	g.P("pub struct ", typeName, " {")
+	g.P("    pub name: ", nameType, ",")
+	g.P("    pub revision: Option<String>,")
+	g.P("}")
+	g.P()
+
+	g.P("impl ", typeName, " {")
+	g.P("    /// Creates a new ", typeName, " for a revision of the resource.")
+	g.P("    pub fn new(name: ", nameType, ", revision: impl Into<String>) -> Self {")
+	g.P("        Self {")
+	g.P("            name,")
+	g.P("            revision: Some(revision.into()),")
+	g.P("        }")
+	g.P("    }")
+	g.P()
+	g.P("    /// Validates the resource name and the revision ID.")
+	g.P("    pub fn validate(&self) -> Result<(), ", resourceNameErrorType, "> {")
+	g.P("        self.name.validate()?;")
+	g.P("        match &self.revision {")
+	g.P("            Some(revision) => ", runtimeCrate, "::validate_revision_id(revision),")
+	g.P("            None => Ok(()),")
+	g.P("        }")
+	g.P("    }")
+	g.P()
+	g.P("    /// Returns the name of the resource, without the revision.")
+	g.P("    pub fn without_revision(&self) -> ", nameType, " {")
+	g.P("        self.name.clone()")
+	g.P("    }")
+	g.P("}")
+	g.P()
+
+	g.P("impl From<", nameType, "> for ", typeName, " {")
+	g.P("    fn from(name: ", nameType, ") -> Self {")
+	g.P("        Self {")
+	g.P("            name,")
+	g.P("            revision: None,")
+	g.P("        }")
+	g.P("    }")
+	g.P("}")
+	g.P()
+
+	g.P("impl fmt::Display for ", typeName, " {")
+	g.P("    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {")
+	g.P("        match &self.revision {")
+	g.P("            Some(revision) => write!(f, \"{}@{}\", self.name, revision),")
+	g.P("            None => write!(f, \"{}\", self.name),")
+	g.P("        }")
+	g.P("    }")
+	g.P("}")
+	g.P()
+
+	g.P("impl FromStr for ", typeName, " {")
+	g.P("    type Err = ", resourceNameErrorType, ";")
+	g.P()
+	g.P("    fn from_str(s: &str) -> Result<Self, Self::Err> {")
+	// Not imported in include_aip.rs, which would warn when no resource has revisions
+	g.P("        let (name, revision) = ", runtimeCrate, "::split_revision(s);")
+	g.P("        if let Some(revision) = revision {")
+	g.P("            ", runtimeCrate, "::validate_revision_id(revision)?;")
+	g.P("        }")
+	g.P("        Ok(Self {")
+	g.P("            name: name.parse()?,")
+	g.P("            revision: revision.map(str::to_string),")
+	g.P("        })")
+	g.P("    }")
+	g.P("}")
+	g.P()
+}
@@ -0,0 +1,205 @@
+package genaip
+
+import (
+	"fmt"
+	"regexp"
+	"strconv"
+	"strings"
+
+	"github.com/AnteWall/protoc-gen-rust-aip/pkg/resourcename"
+	"google.golang.org/protobuf/compiler/protogen"
+	"google.golang.org/protobuf/proto"
+	"google.golang.org/protobuf/reflect/protoreflect"
+	"google.golang.org/protobuf/reflect/protoregistry"
+	"google.golang.org/protobuf/types/dynamicpb"
+)
+
+// variableRulesExtension is the message option declaring the variable rules of the resource
+// of the message, see proto/rust_aip/annotations.proto.
+const variableRulesExtension protoreflect.FullName = "rust_aip.variable_rules"
+
+// fileVariableRulesExtension is the file option declaring the variable rules of all the
+// resources of the file.
+const fileVariableRulesExtension protoreflect.FullName = "rust_aip.file_variable_rules"
+
+// variableRule holds the checks of a rust_aip.VariableRule.
+type variableRule struct {
+	regex   string
+	uuid    bool
+	rfc1034 bool
+	maxLen  uint64
+	charset string
+}
+
+// variableRules returns the rules of the resource by variable: the ones of its file,
+// overridden by the ones of its message.
+func (r *resourceNameCodeGenerator) variableRules() (map[string]variableRule, error) {
+	rules := map[string]variableRule{}
+	if err := readVariableRules(r.files, r.file.Desc.Options(), fileVariableRulesExtension, rules); err != nil {
+		return nil, err
+	}
+	if r.message != nil {
+		if err := readVariableRules(r.files, r.message.Options(), variableRulesExtension, rules); err != nil {
+			return nil, err
+		}
+	}
+	if len(rules) == 0 {
+		return nil, nil
+	}
+
+	variables := map[string]bool{}
+	for _, pattern := range r.resource.GetPattern() {
+		var segments []resourcename.Segment
+		if err := resourcename.ParsePattern(pattern, &segments); err != nil {
+			return nil, err
+		}
+		for _, segment := range segments {
+			for _, variable := range segment.Variables() {
+				variables[variable] = true
+			}
+		}
+	}
+	for variable, rule := range rules {
+		if !variables[variable] && r.message != nil {
+			return nil, fmt.Errorf("%s: variable rule for unknown variable %q", r.resource.GetType(), variable)
+		}
+		if !variables[variable] {
+			// File rules apply to the resources that have the variable
+			delete(rules, variable)
+			continue
+		}
+		if rule.regex != "" {
+			if _, err := regexp.Compile(rule.regex); err != nil {
+				return nil, fmt.Errorf("%s: invalid regex for variable %q: %w", r.resource.GetType(), variable, err)
+			}
+			if err := checkPortableRegex(rule.regex); err != nil {
+				return nil, fmt.Errorf("%s: regex for variable %q: %w", r.resource.GetType(), variable, err)
+			}
+		}
+	}
+	return rules, nil
+}
+
+// checkPortableRegex rejects the syntax of regexes that Go's regexp, which checks them here,
+// and Rust's regex crate, which runs them in the generated code, read differently: \Q...\E
+// quoting, nested classes and class set operations such as [a-z&&[^x]], and {,n} repetitions.
+// Otherwise both follow RE2 and agree on ASCII values, the only ones the validation profiles
+// let through before the rules run.
+func checkPortableRegex(regex string) error {
+	inClass := false
+	for i := 0; i < len(regex); i++ {
+		rest := regex[i:]
+		switch {
+		case strings.HasPrefix(rest, `\Q`) || strings.HasPrefix(rest, `\E`):
+			return fmt.Errorf("%s quoting is not supported by Rust, escape the characters instead", rest[:2])
+		case rest[0] == '\\':
+			i++
+		case inClass && strings.HasPrefix(rest, "[:"):
+			end := strings.Index(rest, ":]")
+			if end < 0 {
+				return fmt.Errorf("nested class at offset %d is read differently by Rust, escape the [", i)
+			}
+			i += end + 1
+		case inClass && rest[0] == '[':
+			return fmt.Errorf("nested class at offset %d is read differently by Rust, escape the [", i)
+		case inClass && (strings.HasPrefix(rest, "&&") || strings.HasPrefix(rest, "--") || strings.HasPrefix(rest, "~~")):
+			return fmt.Errorf("%s at offset %d is a class operation in Rust, escape it", rest[:2], i)
+		case inClass && rest[0] == ']':
+			inClass = false
+		case rest[0] == '[':
+			inClass = true
+			// A ] right after the opening [ or [^ is a literal in both syntaxes
+			if strings.HasPrefix(rest, "[^") {
+				i++
+			}
+			if strings.HasPrefix(regex[i+1:], "]") {
+				i++
+			}
+		case strings.HasPrefix(rest, "{,"):
+			return fmt.Errorf("{,n} at offset %d is a literal in Go and invalid in Rust, use {0,n}", i)
+		}
+	}
+	return nil
+}
+
+// readVariableRules reads the rules of an extension of options into rules. The extension
+// is looked up in the files of the request, and there are none if it was not imported.
+func readVariableRules(
+	files *protoregistry.Files,
+	options proto.Message,
+	name protoreflect.FullName,
+	rules map[string]variableRule,
+) error {
+	descriptor, err := files.FindDescriptorByName(name)
+	if err != nil {
+		return nil
+	}
+	extension, ok := descriptor.(protoreflect.ExtensionDescriptor)
+	if !ok {
+		return fmt.Errorf("%s is not an extension", name)
+	}
+	// The options were parsed without the extension, which is left in their unknown fields
+	extensionType := dynamicpb.NewExtensionType(extension)
+	var types protoregistry.Types
+	if err := types.RegisterExtension(extensionType); err != nil {
+		return err
+	}
+	data, err := proto.Marshal(options)
+	if err != nil {
+		return err
+	}
+	resolved := options.ProtoReflect().Type().New().Interface()
+	if err := (proto.UnmarshalOptions{Resolver: &types}).Unmarshal(data, resolved); err != nil {
+		return err
+	}
+
+	list := resolved.ProtoReflect().Get(extensionType.TypeDescriptor()).List()
+	for i := 0; i < list.Len(); i++ {
+		message := list.Get(i).Message()
+		fields := message.Descriptor().Fields()
+		get := func(field protoreflect.Name) protoreflect.Value {
+			return message.Get(fields.ByName(field))
+		}
+		variable := get("variable").String()
+		if variable == "" {
+			return fmt.Errorf("%s: rule without a variable", name)
+		}
+		rules[variable] = variableRule{
+			regex:   get("regex").String(),
+			uuid:    get("uuid").Bool(),
+			rfc1034: get("rfc1034").Bool(),
+			maxLen:  get("max_len").Uint(),
+			charset: get("charset").String(),
+		}
+	}
+	return nil
+}
+
+// generateVariableRuleChecks generates the checks of the rule of a variable, if any, in a
+// validate method of a borrowed view.
+func (r resourceNameCodeGenerator) generateVariableRuleChecks(g *protogen.GeneratedFile, variable string) {
+	rule, ok := r.rules[variable]
+	if !ok {
+		return
+	}
+	field := strconv.Quote(variable)
+	value := "self." + toRustFieldName(variable)
+	if rule.rfc1034 {
+		g.P("        ", runtimeCrate, "::validate_rfc1034_segment(", field, ", ", value, ")?;")
+	}
+	if rule.uuid {
+		g.P("        ", runtimeCrate, "::rules::validate_uuid(", field, ", ", value, ")?;")
+	}
+	if rule.maxLen > 0 {
+		g.P("        ", runtimeCrate, "::rules::validate_max_len(", field, ", ", value, ", ", rule.maxLen, ")?;")
+	}
+	if rule.charset != "" {
+		g.P("        ", runtimeCrate, "::rules::validate_charset(", field, ", ", value, ", ", strconv.Quote(rule.charset), ")?;")
+	}
+	if rule.regex != "" {
+		// The macro fails to compile with a message naming the regex feature when it is not enabled
+		static := strings.ToUpper(toRustFieldName(variable)) + "_REGEX"
+		g.P("        ", runtimeCrate, "::regex_rule!(", static, ", ", strconv.Quote(rule.regex), ");")
+		g.P("        ", static, ".validate(", field, ", ", value, ")?;")
+	}
+}
@@ -0,0 +1,51 @@
+package genaip
+
+import (
+	"strings"
+
+	"google.golang.org/protobuf/compiler/protogen"
+)
+
+// runtimeCrate is the Rust crate the generated code depends on, see crates/aip-resourcename.
+const runtimeCrate = "aip_resourcename"
+
+// resourceNameErrorType is the name of the error type shared by all generated resource names.
+const resourceNameErrorType = "ResourceNameError"
+
+// resourceNameTrait is the name of the trait implemented by all generated resource names.
+const resourceNameTrait = "ResourceName"
+
+// dynResourceNameTrait is the name of the object-safe companion of resourceNameTrait.
+const dynResourceNameTrait = "DynResourceName"
+
+// untypedResourceNameType is the name of the runtime type holding names of any resource type.
+const untypedResourceNameType = "UntypedResourceName"
+
+// scannerType is the name of the runtime type that drives the generated parsers.
+const scannerType = "Scanner"
+
+// generateRuntimeImports imports the runtime crate items used by the generated code.
+// The public ones are re-exported so that users of the generated code do not need to
+// depend on the runtime crate themselves.
+func generateRuntimeImports(g *protogen.GeneratedFile, config Config) {
+	exported := []string{
+		dynResourceNameTrait,
+		resourceNameTrait,
+		resourceNameErrorType,
+		resourceNameErrorType + "Kind",
+		untypedResourceNameType,
+		borrowedTypeName(untypedResourceNameType),
+	}
+	internal := []string{
+		scannerType,
+		"reject_wildcard",
+		"strip_service",
+		config.Validation.segmentValidator(),
+	}
+	g.P("pub use ", runtimeCrate, "::{", strings.Join(exported, ", "), "};")
+	g.P("use ", runtimeCrate, "::{", strings.Join(internal, ", "), "};")
+	if imports := config.Serde.serdeImports(); len(imports) > 0 {
+		g.P("use ", runtimeCrate, "::serde::{", strings.Join(imports, ", "), "};")
+	}
+	g.P()
+}
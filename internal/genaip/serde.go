@@ -0,0 +1,211 @@
+package genaip
+
+import (
+	"fmt"
+	"slices"
+	"strconv"
+	"strings"
+
+	"github.com/AnteWall/protoc-gen-rust-aip/pkg/resourcename"
+	"google.golang.org/protobuf/compiler/protogen"
+)
+
+// SerdeMode selects the serde implementations generated for resource names.
+type SerdeMode string
+
+const (
+	// SerdeNone generates no serde implementations.
+	SerdeNone SerdeMode = ""
+
+	// SerdeString serializes resource names as their canonical string.
+	SerdeString SerdeMode = "string"
+
+	// SerdeStructured serializes resource names as a map from their variables to their values.
+	SerdeStructured SerdeMode = "structured"
+)
+
+// validate returns an error for unknown modes.
+func (m SerdeMode) validate() error {
+	switch m {
+	case SerdeNone, SerdeString, SerdeStructured:
+		return nil
+	default:
+		return fmt.Errorf("invalid serde option %q, expected %q or %q", m, SerdeString, SerdeStructured)
+	}
+}
+
+// serdeImports returns the items of the runtime serde module used by the generated code.
+func (m SerdeMode) serdeImports() []string {
+	switch m {
+	case SerdeString:
+		return []string{"Deserialize", "Deserializer", "Serialize", "Serializer", "deserialize_str", "serialize_str"}
+	case SerdeStructured:
+		return []string{"Deserialize", "Deserializer", "Serialize", "Serializer", "deserialize_variables", "serialize_variables", "validated"}
+	default:
+		return nil
+	}
+}
+
+// generateStructSerdeImpls implements Serialize and Deserialize for the struct of a pattern,
+// delegating to the runtime serde module. In the structured mode, each variable is
+// deserialized into its field and the name is validated, without formatting it.
+func (r resourceNameCodeGenerator) generateStructSerdeImpls(g *protogen.GeneratedFile, typeName string, segments []resourcename.Segment) {
+	switch r.config.Serde {
+	case SerdeNone:
+		return
+	case SerdeString:
+		generateStringSerdeImpls(g, typeName, "")
+		return
+	}
+	variables := segmentVariables(segments)
+	entries := make([]string, 0, len(variables))
+	fields := make([]string, 0, len(variables))
+	for _, variable := range variables {
+		field := toRustFieldName(variable)
+		entries = append(entries, "("+strconv.Quote(variable)+", &self."+field+")")
+		fields = append(fields, field)
+	}
+
+	g.P("impl Serialize for ", typeName, " {")
+	g.P("    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {")
+	g.P("        serialize_variables(serializer, [", strings.Join(entries, ", "), "])")
+	g.P("    }")
+	g.P("}")
+	g.P()
+	g.P("impl<'de> Deserialize<'de> for ", typeName, " {")
+	g.P("    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {")
+	g.P("        let [", strings.Join(fields, ", "), "] = deserialize_variables(deserializer, ", quotedArray(variables), ")?;")
+	g.P("        validated(Self { ", strings.Join(fields, ", "), " })")
+	g.P("    }")
+	g.P("}")
+	g.P()
+}
+
+// generateEnumSerdeImpls implements Serialize and Deserialize for a multi-pattern enum. In the
+// structured mode, a variant serializes as the struct it holds, and a map deserializes to the
+// variant with exactly its variables. Variants that share their variables with another
+// variant cannot be told apart by them, so they serialize as their string, like the Unknown
+// variant, and the enum then deserializes from either shape, or only from its string when no
+// variant can be told apart.
+func (r resourceNameCodeGenerator) generateEnumSerdeImpls(
+	g *protogen.GeneratedFile,
+	enumName string,
+	candidates []patternCandidate,
+	unknown bool,
+) {
+	switch r.config.Serde {
+	case SerdeNone:
+		return
+	case SerdeString:
+		generateStringSerdeImpls(g, enumName, "")
+		return
+	}
+	// The items only enums use are not imported in include_aip.rs, which would warn without them
+	ambiguous := ambiguousCandidates(candidates)
+	if !slices.Contains(ambiguous, false) {
+		generateStringSerdeImpls(g, enumName, runtimeCrate+"::serde::")
+		return
+	}
+	hasNames := unknown || slices.Contains(ambiguous, true)
+
+	g.P("impl Serialize for ", enumName, " {")
+	g.P("    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {")
+	g.P("        match self {")
+	for i, candidate := range candidates {
+		if ambiguous[i] {
+			g.P("            ", enumName, "::", candidate.variantName, "(name) => ", runtimeCrate, "::serde::serialize_str(name, serializer),")
+		} else {
+			g.P("            ", enumName, "::", candidate.variantName, "(name) => name.serialize(serializer),")
+		}
+	}
+	if unknown {
+		g.P("            ", enumName, "::", unknownVariantName, "(name) => ", runtimeCrate, "::serde::serialize_str(name, serializer),")
+	}
+	g.P("        }")
+	g.P("    }")
+	g.P("}")
+	g.P()
+
+	g.P("impl<'de> Deserialize<'de> for ", enumName, " {")
+	g.P("    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {")
+	if hasNames {
+		g.P("        let mut variables = match ", runtimeCrate, "::serde::VariablesOrName::deserialize(deserializer)? {")
+		g.P("            ", runtimeCrate, "::serde::VariablesOrName::Variables(variables) => variables,")
+		g.P("            ", runtimeCrate, "::serde::VariablesOrName::Name(name) => return Ok(name),")
+		g.P("        };")
+	} else {
+		g.P("        let mut variables = ", runtimeCrate, "::serde::Variables::deserialize(deserializer)?;")
+	}
+	var expected []string
+	for i, candidate := range candidates {
+		if ambiguous[i] {
+			continue
+		}
+		variables := segmentVariables(candidate.segments)
+		fields := make([]string, 0, len(variables))
+		for _, variable := range variables {
+			fields = append(fields, toRustFieldName(variable))
+		}
+		g.P("        if let Some([", strings.Join(fields, ", "), "]) = variables.take(", quotedArray(variables), ") {")
+		g.P("            return validated(", enumName, "::", candidate.variantName, "(", candidate.structName, " { ", strings.Join(fields, ", "), " }));")
+		g.P("        }")
+		expected = append(expected, "&"+quotedArray(variables))
+	}
+	g.P("        Err(variables.no_match(&[", strings.Join(expected, ", "), "]))")
+	g.P("    }")
+	g.P("}")
+	g.P()
+}
+
+// generateStringSerdeImpls implements Serialize and Deserialize with the canonical string,
+// calling the runtime functions through path, unless they are imported.
+func generateStringSerdeImpls(g *protogen.GeneratedFile, typeName string, path string) {
+	g.P("impl Serialize for ", typeName, " {")
+	g.P("    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {")
+	g.P("        ", path, "serialize_str(self, serializer)")
+	g.P("    }")
+	g.P("}")
+	g.P()
+	g.P("impl<'de> Deserialize<'de> for ", typeName, " {")
+	g.P("    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {")
+	g.P("        ", path, "deserialize_str(deserializer)")
+	g.P("    }")
+	g.P("}")
+	g.P()
+}
+
+// ambiguousCandidates reports, for each candidate, whether another candidate has the same
+// set of variables.
+func ambiguousCandidates(candidates []patternCandidate) []bool {
+	keys := make([]string, len(candidates))
+	count := make(map[string]int, len(candidates))
+	for i, candidate := range candidates {
+		variables := segmentVariables(candidate.segments)
+		slices.Sort(variables)
+		keys[i] = strings.Join(variables, "/")
+		count[keys[i]]++
+	}
+	ambiguous := make([]bool, len(candidates))
+	for i, key := range keys {
+		ambiguous[i] = count[key] > 1
+	}
+	return ambiguous
+}
+
+// segmentVariables returns the variables of a pattern in order.
+func segmentVariables(segments []resourcename.Segment) []string {
+	var variables []string
+	for _, segment := range segments {
+		variables = append(variables, segment.Variables()...)
+	}
+	return variables
+}
+
+// quotedArray formats strings as a Rust array of string literals.
+func quotedArray(values []string) string {
+	quoted := make([]string, 0, len(values))
+	for _, value := range values {
+		quoted = append(quoted, strconv.Quote(value))
+	}
+	return "[" + strings.Join(quoted, ", ") + "]"
+}
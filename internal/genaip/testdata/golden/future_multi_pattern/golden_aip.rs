@@ -0,0 +1,469 @@
+// Code generated by protoc-gen-rust-aip. DO NOT EDIT.
+//
+// versions:
+// 	protoc-gen-rust-aip development
+// 	protoc (unknown)
+// source: golden.proto
+
+/// Multi-pattern resource name for golden.example.com/Author
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AuthorResourceName {
+    Authors(AuthorsAuthorResourceName),
+    /// A well-formed name that matches none of the known patterns. It has no parent
+    /// and matches no glob.
+    Unknown(UntypedResourceName),
+}
+
+impl AuthorResourceName {
+    /// Returns the resource type.
+    pub fn resource_type(&self) -> &'static str {
+        "golden.example.com/Author"
+    }
+
+    /// Returns true if the name matches none of the known patterns.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, AuthorResourceName::Unknown(_))
+    }
+
+    /// Returns true if any field contains a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        match self {
+            AuthorResourceName::Authors(inner) => inner.contains_wildcard(),
+            AuthorResourceName::Unknown(name) => name.contains_wildcard(),
+        }
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    /// Names of different patterns are matched segment by segment.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        match (self, concrete) {
+            (AuthorResourceName::Authors(inner), AuthorResourceName::Authors(concrete)) => inner.matches(concrete),
+            (AuthorResourceName::Unknown(name), AuthorResourceName::Unknown(concrete)) => name.matches(concrete),
+            _ => aip_resourcename::wildcard_matches(&self.to_string(), &concrete.to_string()),
+        }
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(self, variables: &[&str]) -> Self {
+        match self {
+            AuthorResourceName::Authors(inner) => AuthorResourceName::Authors(inner.with_wildcards_in(variables)),
+            AuthorResourceName::Unknown(name) => AuthorResourceName::Unknown(name),
+        }
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        match self {
+            AuthorResourceName::Authors(inner) => inner.validate_concrete(),
+            AuthorResourceName::Unknown(name) => name.validate_concrete(),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        match self {
+            AuthorResourceName::Authors(inner) => inner.validate(),
+            AuthorResourceName::Unknown(_) => Ok(()),
+        }
+    }
+
+    /// Borrows the resource name as a AuthorResourceNameRef.
+    pub fn as_borrowed(&self) -> AuthorResourceNameRef<'_> {
+        match self {
+            AuthorResourceName::Authors(inner) => AuthorResourceNameRef::Authors(inner.as_borrowed()),
+            AuthorResourceName::Unknown(name) => AuthorResourceNameRef::Unknown(name.as_borrowed()),
+        }
+    }
+
+    /// Returns true if `other` is the name of a resource nested under this one.
+    pub fn is_ancestor_of(&self, other: &str) -> bool {
+        ResourceName::is_ancestor_of(self, other)
+    }
+
+    /// Returns true if the name matches a glob such as `projects/acme/books/*`, where `*`
+    /// matches one segment and a final `**` any suffix. Globs that cannot match names of
+    /// this type match nothing.
+    ///
+    /// Globs only match names of the known patterns, so an Unknown name
+    /// matches no glob, not even `**`.
+    pub fn matches_glob(&self, glob: &str) -> bool {
+        ResourceName::matches_glob(self, glob)
+    }
+
+    /// Returns the name of the service the resource belongs to.
+    pub fn service_name(&self) -> &'static str {
+        match self {
+            AuthorResourceName::Authors(inner) => inner.service_name(),
+            AuthorResourceName::Unknown(_) => "golden.example.com",
+        }
+    }
+
+    /// Returns the full resource name, prefixed with the service name.
+    pub fn to_full_name(&self) -> String {
+        match self {
+            AuthorResourceName::Authors(inner) => inner.to_full_name(),
+            AuthorResourceName::Unknown(name) => format!("//{}/{}", self.service_name(), name),
+        }
+    }
+
+    /// Parses a full resource name, which must belong to the service of the resource.
+    pub fn from_full_name(full_name: &str) -> Result<Self, ResourceNameError> {
+        strip_service(full_name, "golden.example.com")?.parse()
+    }
+
+    /// Returns the API version of the resource, from its proto package.
+    pub fn api_version(&self) -> &'static str {
+        match self {
+            AuthorResourceName::Authors(inner) => inner.api_version(),
+            AuthorResourceName::Unknown(_) => "v1",
+        }
+    }
+
+    /// Returns the resource URI in the given API version, or else in the one of the resource.
+    pub fn to_uri(&self, version: Option<&str>) -> String {
+        match self {
+            AuthorResourceName::Authors(inner) => inner.to_uri(version),
+            AuthorResourceName::Unknown(name) => {
+                let version = version.unwrap_or(self.api_version());
+                format!("https://{}/{}/{}", self.service_name(), version, name)
+            }
+        }
+    }
+
+    /// Parses a resource URI of the service of the resource, and returns the name with the
+    /// API version of the URI.
+    pub fn from_uri(uri: &str) -> Result<(Self, &str), ResourceNameError> {
+        let (version, name) = aip_resourcename::split_uri(uri, "golden.example.com")?;
+        Ok((name.parse()?, version))
+    }
+
+}
+
+impl fmt::Display for AuthorResourceName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AuthorResourceName::Authors(inner) => write!(f, "{inner}"),
+            AuthorResourceName::Unknown(name) => write!(f, "{name}"),
+        }
+    }
+}
+
+impl FromStr for AuthorResourceName {
+    type Err = ResourceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        AuthorResourceNameRef::parse_borrowed(s).map(AuthorResourceNameRef::to_owned)
+    }
+}
+
+impl ResourceName for AuthorResourceName {
+    const TYPE: &'static str = "golden.example.com/Author";
+    const PATTERNS: &'static [&'static str] = &["authors/{author}"];
+    const SINGULAR: &'static str = "author";
+    const PLURAL: &'static str = "authors";
+
+    fn validate(&self) -> Result<(), ResourceNameError> {
+        self.validate()
+    }
+
+    fn contains_wildcard(&self) -> bool {
+        self.contains_wildcard()
+    }
+}
+
+impl Serialize for AuthorResourceName {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            AuthorResourceName::Authors(name) => name.serialize(serializer),
+            AuthorResourceName::Unknown(name) => aip_resourcename::serde::serialize_str(name, serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for AuthorResourceName {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut variables = match aip_resourcename::serde::VariablesOrName::deserialize(deserializer)? {
+            aip_resourcename::serde::VariablesOrName::Variables(variables) => variables,
+            aip_resourcename::serde::VariablesOrName::Name(name) => return Ok(name),
+        };
+        if let Some([author]) = variables.take(["author"]) {
+            return validated(AuthorResourceName::Authors(AuthorsAuthorResourceName { author }));
+        }
+        Err(variables.no_match(&[&["author"]]))
+    }
+}
+
+/// Borrowed view of a AuthorResourceName that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AuthorResourceNameRef<'a> {
+    Authors(AuthorsAuthorResourceNameRef<'a>),
+    /// A well-formed name that matches none of the known patterns.
+    Unknown(UntypedResourceNameRef<'a>),
+}
+
+impl<'a> AuthorResourceNameRef<'a> {
+    /// Parses a resource name without allocating. Well-formed names that match none of
+    /// the known patterns are parsed as Unknown.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        Self::parse_known(s).or_else(|err| {
+            if err.kind != ResourceNameErrorKind::NoMatchingPattern {
+                return Err(err);
+            }
+            UntypedResourceNameRef::parse_borrowed(s)
+                .map(Self::Unknown)
+                .map_err(|_| err)
+        })
+    }
+
+    /// Parses a resource name of one of the known patterns.
+    fn parse_known(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut parts = s.split('/');
+        match parts.next() {
+            Some("authors") => {
+                match parts.next() {
+                    Some(part1) => {
+                        match parts.next() {
+                            Some(part) => Err(ResourceNameError::no_matching_pattern("authors/{author}", 2, Some(part))),
+                            None => AuthorsAuthorResourceNameRef::from_segments(part1).map(Self::Authors),
+                        }
+                    }
+                    None => Err(ResourceNameError::no_matching_pattern("authors/{author}", 1, None)),
+                }
+            }
+            Some(part) => Err(ResourceNameError::no_matching_pattern("authors/{author}", 0, Some(part))),
+            None => Err(ResourceNameError::no_matching_pattern("authors/{author}", 0, None)),
+        }
+    }
+
+    /// Returns true if the name matches none of the known patterns.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, AuthorResourceNameRef::Unknown(_))
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        match self {
+            AuthorResourceNameRef::Authors(inner) => inner.validate(),
+            AuthorResourceNameRef::Unknown(_) => Ok(()),
+        }
+    }
+
+    /// Converts the borrowed view into an owned AuthorResourceName.
+    pub fn to_owned(self) -> AuthorResourceName {
+        match self {
+            AuthorResourceNameRef::Authors(inner) => AuthorResourceName::Authors(inner.to_owned()),
+            AuthorResourceNameRef::Unknown(name) => AuthorResourceName::Unknown(name.to_owned()),
+        }
+    }
+}
+
+impl fmt::Display for AuthorResourceNameRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AuthorResourceNameRef::Authors(inner) => write!(f, "{inner}"),
+            AuthorResourceNameRef::Unknown(name) => write!(f, "{name}"),
+        }
+    }
+}
+
+/// Parses a resource name string and returns the appropriate type.
+pub fn parse_author_resource_name(name: &str) -> Result<AuthorResourceName, ResourceNameError> {
+    AuthorResourceName::from_str(name)
+}
+
+/// Resource name for golden.example.com/Author
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AuthorsAuthorResourceName {
+    pub author: String,
+}
+
+impl AuthorsAuthorResourceName {
+    /// Creates a new AuthorsAuthorResourceName.
+    pub fn new(
+        author: impl Into<String>
+    ) -> Self {
+        Self {
+            author: author.into(),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        self.as_borrowed().validate()
+    }
+
+    /// Borrows the resource name as a AuthorsAuthorResourceNameRef.
+    pub fn as_borrowed(&self) -> AuthorsAuthorResourceNameRef<'_> {
+        AuthorsAuthorResourceNameRef {
+            author: &self.author,
+        }
+    }
+
+    /// Returns the resource type.
+    pub fn resource_type(&self) -> &'static str {
+        "golden.example.com/Author"
+    }
+
+    /// Returns the name of the service the resource belongs to.
+    pub fn service_name(&self) -> &'static str {
+        "golden.example.com"
+    }
+
+    /// Returns the full resource name, prefixed with the service name.
+    pub fn to_full_name(&self) -> String {
+        format!("//{}/{}", self.service_name(), self)
+    }
+
+    /// Parses a full resource name, which must belong to the service of the resource.
+    pub fn from_full_name(full_name: &str) -> Result<Self, ResourceNameError> {
+        strip_service(full_name, "golden.example.com")?.parse()
+    }
+
+    /// Returns the API version of the resource, from its proto package.
+    pub fn api_version(&self) -> &'static str {
+        "v1"
+    }
+
+    /// Returns the resource URI in the given API version, or else in the one of the resource.
+    pub fn to_uri(&self, version: Option<&str>) -> String {
+        let version = version.unwrap_or(self.api_version());
+        format!("https://{}/{}/{}", self.service_name(), version, self)
+    }
+
+    /// Parses a resource URI of the service of the resource, and returns the name with the
+    /// API version of the URI.
+    pub fn from_uri(uri: &str) -> Result<(Self, &str), ResourceNameError> {
+        let (version, name) = aip_resourcename::split_uri(uri, "golden.example.com")?;
+        Ok((name.parse()?, version))
+    }
+
+    /// Returns true if any field contains a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        self.author == "-"
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        self.author == "-" || self.author == concrete.author
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(mut self, variables: &[&str]) -> Self {
+        if variables.contains(&"author") {
+            self.author = "-".to_string();
+        }
+        self
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        self.validate()?;
+        reject_wildcard("author", &self.author)?;
+        Ok(())
+    }
+
+    /// Returns true if `other` is the name of a resource nested under this one.
+    pub fn is_ancestor_of(&self, other: &str) -> bool {
+        ResourceName::is_ancestor_of(self, other)
+    }
+
+    /// Returns true if the name matches a glob such as `projects/acme/books/*`, where `*`
+    /// matches one segment and a final `**` any suffix. Globs that cannot match names of
+    /// this type match nothing.
+    pub fn matches_glob(&self, glob: &str) -> bool {
+        ResourceName::matches_glob(self, glob)
+    }
+
+}
+
+impl fmt::Display for AuthorsAuthorResourceName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_borrowed(), f)
+    }
+}
+
+impl FromStr for AuthorsAuthorResourceName {
+    type Err = ResourceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        AuthorsAuthorResourceNameRef::parse_borrowed(s).map(AuthorsAuthorResourceNameRef::to_owned)
+    }
+}
+
+impl ResourceName for AuthorsAuthorResourceName {
+    const TYPE: &'static str = "golden.example.com/Author";
+    const PATTERNS: &'static [&'static str] = &["authors/{author}"];
+    const SINGULAR: &'static str = "author";
+    const PLURAL: &'static str = "authors";
+
+    fn validate(&self) -> Result<(), ResourceNameError> {
+        self.validate()
+    }
+
+    fn contains_wildcard(&self) -> bool {
+        self.contains_wildcard()
+    }
+}
+
+impl Serialize for AuthorsAuthorResourceName {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_variables(serializer, [("author", &self.author)])
+    }
+}
+
+impl<'de> Deserialize<'de> for AuthorsAuthorResourceName {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let [author] = deserialize_variables(deserializer, ["author"])?;
+        validated(Self { author })
+    }
+}
+
+/// Borrowed view of a AuthorsAuthorResourceName that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AuthorsAuthorResourceNameRef<'a> {
+    pub author: &'a str,
+}
+
+impl<'a> AuthorsAuthorResourceNameRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut scanner = Scanner::new(s, 2);
+        scanner.literal("authors")?;
+        let author = scanner.variable()?;
+        scanner.finish()?;
+        Self::from_segments(author)
+    }
+
+    /// Creates a AuthorsAuthorResourceNameRef from parsed segments and validates it.
+    fn from_segments(author: &'a str) -> Result<Self, ResourceNameError> {
+        let name = Self { author };
+        name.validate()?;
+        Ok(name)
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        validate_segment("author", self.author)?;
+        Ok(())
+    }
+
+    /// Converts the borrowed view into an owned AuthorsAuthorResourceName.
+    pub fn to_owned(self) -> AuthorsAuthorResourceName {
+        AuthorsAuthorResourceName {
+            author: self.author.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for AuthorsAuthorResourceNameRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "authors/{author}"
+            , author = self.author
+        )
+    }
+}
+
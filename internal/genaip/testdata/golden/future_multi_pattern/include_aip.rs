@@ -0,0 +1,67 @@
+// Code generated by protoc-gen-rust-aip. DO NOT EDIT.
+//
+// This file includes all generated AIP resource name files.
+// Include this file in your lib.rs to import all AIP resource names:
+// include!("gen/include_aip.rs");
+//
+// versions:
+// 	protoc-gen-rust-aip development
+// 	protoc (unknown)
+
+use std::fmt;
+use std::str::FromStr;
+
+pub use aip_resourcename::{DynResourceName, ResourceName, ResourceNameError, ResourceNameErrorKind, UntypedResourceName, UntypedResourceNameRef};
+use aip_resourcename::{Scanner, reject_wildcard, strip_service, validate_segment};
+use aip_resourcename::serde::{Deserialize, Deserializer, Serialize, Serializer, deserialize_variables, serialize_variables, validated};
+
+include!("golden_aip.rs");
+
+/// Name of any resource generated in this compilation.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AnyResourceName {
+    Author(AuthorResourceName),
+}
+
+impl AnyResourceName {
+    /// Returns the name as a DynResourceName.
+    pub fn as_dyn(&self) -> &dyn DynResourceName {
+        match self {
+            AnyResourceName::Author(inner) => inner,
+        }
+    }
+
+    /// Returns the resource type.
+    pub fn resource_type(&self) -> &'static str {
+        self.as_dyn().resource_type()
+    }
+}
+
+impl fmt::Display for AnyResourceName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_dyn(), f)
+    }
+}
+
+impl From<AuthorResourceName> for AnyResourceName {
+    fn from(name: AuthorResourceName) -> Self {
+        AnyResourceName::Author(name)
+    }
+}
+
+/// Parses the name of a resource of the given type, e.g. `library.googleapis.com/Book`.
+pub fn parse_any(resource_type: &str, name: &str) -> Result<AnyResourceName, ResourceNameError> {
+    match resource_type {
+        "golden.example.com/Author" => name.parse().map(AnyResourceName::Author),
+        _ => Err(ResourceNameError::unknown_type(resource_type)),
+    }
+}
+
+/// Returns the type of every resource with a pattern that matches the name.
+pub fn detect(name: &str) -> Vec<&'static str> {
+    let mut types = Vec::new();
+    if AuthorResourceNameRef::parse_borrowed(name).is_ok_and(|name| !name.is_unknown()) {
+        types.push("golden.example.com/Author");
+    }
+    types
+}
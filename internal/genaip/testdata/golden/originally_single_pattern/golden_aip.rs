@@ -0,0 +1,791 @@
+// Code generated by protoc-gen-rust-aip. DO NOT EDIT.
+//
+// versions:
+// 	protoc-gen-rust-aip development
+// 	protoc (unknown)
+// source: golden.proto
+
+/// Multi-pattern resource name for golden.example.com/Publisher
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PublisherResourceName {
+    Publishers(PublishersPublisherResourceName),
+    Projects(ProjectsPublisherResourceName),
+}
+
+impl PublisherResourceName {
+    /// Returns the resource type.
+    pub fn resource_type(&self) -> &'static str {
+        "golden.example.com/Publisher"
+    }
+
+    /// Returns true if any field contains a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        match self {
+            PublisherResourceName::Publishers(inner) => inner.contains_wildcard(),
+            PublisherResourceName::Projects(inner) => inner.contains_wildcard(),
+        }
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    /// Names of different patterns are matched segment by segment.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        match (self, concrete) {
+            (PublisherResourceName::Publishers(inner), PublisherResourceName::Publishers(concrete)) => inner.matches(concrete),
+            (PublisherResourceName::Projects(inner), PublisherResourceName::Projects(concrete)) => inner.matches(concrete),
+            _ => aip_resourcename::wildcard_matches(&self.to_string(), &concrete.to_string()),
+        }
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(self, variables: &[&str]) -> Self {
+        match self {
+            PublisherResourceName::Publishers(inner) => PublisherResourceName::Publishers(inner.with_wildcards_in(variables)),
+            PublisherResourceName::Projects(inner) => PublisherResourceName::Projects(inner.with_wildcards_in(variables)),
+        }
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        match self {
+            PublisherResourceName::Publishers(inner) => inner.validate_concrete(),
+            PublisherResourceName::Projects(inner) => inner.validate_concrete(),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        match self {
+            PublisherResourceName::Publishers(inner) => inner.validate(),
+            PublisherResourceName::Projects(inner) => inner.validate(),
+        }
+    }
+
+    /// Borrows the resource name as a PublisherResourceNameRef.
+    pub fn as_borrowed(&self) -> PublisherResourceNameRef<'_> {
+        match self {
+            PublisherResourceName::Publishers(inner) => PublisherResourceNameRef::Publishers(inner.as_borrowed()),
+            PublisherResourceName::Projects(inner) => PublisherResourceNameRef::Projects(inner.as_borrowed()),
+        }
+    }
+
+    /// Returns true if `other` is the name of a resource nested under this one.
+    pub fn is_ancestor_of(&self, other: &str) -> bool {
+        ResourceName::is_ancestor_of(self, other)
+    }
+
+    /// Returns true if the name matches a glob such as `projects/acme/books/*`, where `*`
+    /// matches one segment and a final `**` any suffix. Globs that cannot match names of
+    /// this type match nothing.
+    pub fn matches_glob(&self, glob: &str) -> bool {
+        ResourceName::matches_glob(self, glob)
+    }
+
+    /// Returns the name of the service the resource belongs to.
+    pub fn service_name(&self) -> &'static str {
+        match self {
+            PublisherResourceName::Publishers(inner) => inner.service_name(),
+            PublisherResourceName::Projects(inner) => inner.service_name(),
+        }
+    }
+
+    /// Returns the full resource name, prefixed with the service name.
+    pub fn to_full_name(&self) -> String {
+        match self {
+            PublisherResourceName::Publishers(inner) => inner.to_full_name(),
+            PublisherResourceName::Projects(inner) => inner.to_full_name(),
+        }
+    }
+
+    /// Parses a full resource name, which must belong to the service of the resource.
+    pub fn from_full_name(full_name: &str) -> Result<Self, ResourceNameError> {
+        strip_service(full_name, "golden.example.com")?.parse()
+    }
+
+    /// Returns the API version of the resource, from its proto package.
+    pub fn api_version(&self) -> &'static str {
+        match self {
+            PublisherResourceName::Publishers(inner) => inner.api_version(),
+            PublisherResourceName::Projects(inner) => inner.api_version(),
+        }
+    }
+
+    /// Returns the resource URI in the given API version, or else in the one of the resource.
+    pub fn to_uri(&self, version: Option<&str>) -> String {
+        match self {
+            PublisherResourceName::Publishers(inner) => inner.to_uri(version),
+            PublisherResourceName::Projects(inner) => inner.to_uri(version),
+        }
+    }
+
+    /// Parses a resource URI of the service of the resource, and returns the name with the
+    /// API version of the URI.
+    pub fn from_uri(uri: &str) -> Result<(Self, &str), ResourceNameError> {
+        let (version, name) = aip_resourcename::split_uri(uri, "golden.example.com")?;
+        Ok((name.parse()?, version))
+    }
+
+}
+
+impl fmt::Display for PublisherResourceName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PublisherResourceName::Publishers(inner) => write!(f, "{inner}"),
+            PublisherResourceName::Projects(inner) => write!(f, "{inner}"),
+        }
+    }
+}
+
+impl FromStr for PublisherResourceName {
+    type Err = ResourceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        PublisherResourceNameRef::parse_borrowed(s).map(PublisherResourceNameRef::to_owned)
+    }
+}
+
+impl ResourceName for PublisherResourceName {
+    const TYPE: &'static str = "golden.example.com/Publisher";
+    const PATTERNS: &'static [&'static str] = &["publishers/{publisher}", "projects/{project}/publishers/{publisher}"];
+    const SINGULAR: &'static str = "publisher";
+    const PLURAL: &'static str = "publishers";
+
+    fn validate(&self) -> Result<(), ResourceNameError> {
+        self.validate()
+    }
+
+    fn contains_wildcard(&self) -> bool {
+        self.contains_wildcard()
+    }
+}
+
+/// Borrowed view of a PublisherResourceName that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PublisherResourceNameRef<'a> {
+    Publishers(PublishersPublisherResourceNameRef<'a>),
+    Projects(ProjectsPublisherResourceNameRef<'a>),
+}
+
+impl<'a> PublisherResourceNameRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut parts = s.split('/');
+        match parts.next() {
+            Some("publishers") => {
+                match parts.next() {
+                    Some(part1) => {
+                        match parts.next() {
+                            Some(part) => Err(ResourceNameError::no_matching_pattern("publishers/{publisher}", 2, Some(part))),
+                            None => PublishersPublisherResourceNameRef::from_segments(part1).map(Self::Publishers),
+                        }
+                    }
+                    None => Err(ResourceNameError::no_matching_pattern("publishers/{publisher}", 1, None)),
+                }
+            }
+            Some("projects") => {
+                match parts.next() {
+                    Some(part1) => {
+                        match parts.next() {
+                            Some("publishers") => {
+                                match parts.next() {
+                                    Some(part3) => {
+                                        match parts.next() {
+                                            Some(part) => Err(ResourceNameError::no_matching_pattern("projects/{project}/publishers/{publisher}", 4, Some(part))),
+                                            None => ProjectsPublisherResourceNameRef::from_segments(part1, part3).map(Self::Projects),
+                                        }
+                                    }
+                                    None => Err(ResourceNameError::no_matching_pattern("projects/{project}/publishers/{publisher}", 3, None)),
+                                }
+                            }
+                            Some(part) => Err(ResourceNameError::no_matching_pattern("projects/{project}/publishers/{publisher}", 2, Some(part))),
+                            None => Err(ResourceNameError::no_matching_pattern("projects/{project}/publishers/{publisher}", 2, None)),
+                        }
+                    }
+                    None => Err(ResourceNameError::no_matching_pattern("projects/{project}/publishers/{publisher}", 1, None)),
+                }
+            }
+            Some(part) => Err(ResourceNameError::no_matching_pattern("publishers/{publisher}", 0, Some(part))),
+            None => Err(ResourceNameError::no_matching_pattern("publishers/{publisher}", 0, None)),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        match self {
+            PublisherResourceNameRef::Publishers(inner) => inner.validate(),
+            PublisherResourceNameRef::Projects(inner) => inner.validate(),
+        }
+    }
+
+    /// Converts the borrowed view into an owned PublisherResourceName.
+    pub fn to_owned(self) -> PublisherResourceName {
+        match self {
+            PublisherResourceNameRef::Publishers(inner) => PublisherResourceName::Publishers(inner.to_owned()),
+            PublisherResourceNameRef::Projects(inner) => PublisherResourceName::Projects(inner.to_owned()),
+        }
+    }
+}
+
+impl fmt::Display for PublisherResourceNameRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PublisherResourceNameRef::Publishers(inner) => write!(f, "{inner}"),
+            PublisherResourceNameRef::Projects(inner) => write!(f, "{inner}"),
+        }
+    }
+}
+
+/// Parses a resource name string and returns the appropriate type.
+pub fn parse_publisher_resource_name(name: &str) -> Result<PublisherResourceName, ResourceNameError> {
+    PublisherResourceName::from_str(name)
+}
+
+/// Resource name for golden.example.com/Publisher
+///
+/// The original pattern of the resource, from before it had several patterns.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PublishersPublisherResourceName {
+    pub publisher: String,
+}
+
+impl PublishersPublisherResourceName {
+    /// Creates a new PublishersPublisherResourceName.
+    pub fn new(
+        publisher: impl Into<String>
+    ) -> Self {
+        Self {
+            publisher: publisher.into(),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        self.as_borrowed().validate()
+    }
+
+    /// Borrows the resource name as a PublishersPublisherResourceNameRef.
+    pub fn as_borrowed(&self) -> PublishersPublisherResourceNameRef<'_> {
+        PublishersPublisherResourceNameRef {
+            publisher: &self.publisher,
+        }
+    }
+
+    /// Returns the resource type.
+    pub fn resource_type(&self) -> &'static str {
+        "golden.example.com/Publisher"
+    }
+
+    /// Returns the name of the service the resource belongs to.
+    pub fn service_name(&self) -> &'static str {
+        "golden.example.com"
+    }
+
+    /// Returns the full resource name, prefixed with the service name.
+    pub fn to_full_name(&self) -> String {
+        format!("//{}/{}", self.service_name(), self)
+    }
+
+    /// Parses a full resource name, which must belong to the service of the resource.
+    pub fn from_full_name(full_name: &str) -> Result<Self, ResourceNameError> {
+        strip_service(full_name, "golden.example.com")?.parse()
+    }
+
+    /// Returns the API version of the resource, from its proto package.
+    pub fn api_version(&self) -> &'static str {
+        "v1"
+    }
+
+    /// Returns the resource URI in the given API version, or else in the one of the resource.
+    pub fn to_uri(&self, version: Option<&str>) -> String {
+        let version = version.unwrap_or(self.api_version());
+        format!("https://{}/{}/{}", self.service_name(), version, self)
+    }
+
+    /// Parses a resource URI of the service of the resource, and returns the name with the
+    /// API version of the URI.
+    pub fn from_uri(uri: &str) -> Result<(Self, &str), ResourceNameError> {
+        let (version, name) = aip_resourcename::split_uri(uri, "golden.example.com")?;
+        Ok((name.parse()?, version))
+    }
+
+    /// Returns true if any field contains a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        self.publisher == "-"
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        self.publisher == "-" || self.publisher == concrete.publisher
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(mut self, variables: &[&str]) -> Self {
+        if variables.contains(&"publisher") {
+            self.publisher = "-".to_string();
+        }
+        self
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        self.validate()?;
+        reject_wildcard("publisher", &self.publisher)?;
+        Ok(())
+    }
+
+    /// Returns true if `other` is the name of a resource nested under this one.
+    pub fn is_ancestor_of(&self, other: &str) -> bool {
+        ResourceName::is_ancestor_of(self, other)
+    }
+
+    /// Returns true if the name matches a glob such as `projects/acme/books/*`, where `*`
+    /// matches one segment and a final `**` any suffix. Globs that cannot match names of
+    /// this type match nothing.
+    pub fn matches_glob(&self, glob: &str) -> bool {
+        ResourceName::matches_glob(self, glob)
+    }
+
+}
+
+impl fmt::Display for PublishersPublisherResourceName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_borrowed(), f)
+    }
+}
+
+impl FromStr for PublishersPublisherResourceName {
+    type Err = ResourceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        PublishersPublisherResourceNameRef::parse_borrowed(s).map(PublishersPublisherResourceNameRef::to_owned)
+    }
+}
+
+impl ResourceName for PublishersPublisherResourceName {
+    const TYPE: &'static str = "golden.example.com/Publisher";
+    const PATTERNS: &'static [&'static str] = &["publishers/{publisher}"];
+    const SINGULAR: &'static str = "publisher";
+    const PLURAL: &'static str = "publishers";
+
+    fn validate(&self) -> Result<(), ResourceNameError> {
+        self.validate()
+    }
+
+    fn contains_wildcard(&self) -> bool {
+        self.contains_wildcard()
+    }
+}
+
+/// Borrowed view of a PublishersPublisherResourceName that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PublishersPublisherResourceNameRef<'a> {
+    pub publisher: &'a str,
+}
+
+impl<'a> PublishersPublisherResourceNameRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut scanner = Scanner::new(s, 2);
+        scanner.literal("publishers")?;
+        let publisher = scanner.variable()?;
+        scanner.finish()?;
+        Self::from_segments(publisher)
+    }
+
+    /// Creates a PublishersPublisherResourceNameRef from parsed segments and validates it.
+    fn from_segments(publisher: &'a str) -> Result<Self, ResourceNameError> {
+        let name = Self { publisher };
+        name.validate()?;
+        Ok(name)
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        validate_segment("publisher", self.publisher)?;
+        Ok(())
+    }
+
+    /// Converts the borrowed view into an owned PublishersPublisherResourceName.
+    pub fn to_owned(self) -> PublishersPublisherResourceName {
+        PublishersPublisherResourceName {
+            publisher: self.publisher.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for PublishersPublisherResourceNameRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "publishers/{publisher}"
+            , publisher = self.publisher
+        )
+    }
+}
+
+/// Resource name for golden.example.com/Publisher
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ProjectsPublisherResourceName {
+    pub project: String,
+    pub publisher: String,
+}
+
+impl ProjectsPublisherResourceName {
+    /// Creates a new ProjectsPublisherResourceName.
+    pub fn new(
+        project: impl Into<String>,
+        publisher: impl Into<String>
+    ) -> Self {
+        Self {
+            project: project.into(),
+            publisher: publisher.into(),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        self.as_borrowed().validate()
+    }
+
+    /// Borrows the resource name as a ProjectsPublisherResourceNameRef.
+    pub fn as_borrowed(&self) -> ProjectsPublisherResourceNameRef<'_> {
+        ProjectsPublisherResourceNameRef {
+            project: &self.project,
+            publisher: &self.publisher,
+        }
+    }
+
+    /// Returns the resource type.
+    pub fn resource_type(&self) -> &'static str {
+        "golden.example.com/Publisher"
+    }
+
+    /// Returns the name of the service the resource belongs to.
+    pub fn service_name(&self) -> &'static str {
+        "golden.example.com"
+    }
+
+    /// Returns the full resource name, prefixed with the service name.
+    pub fn to_full_name(&self) -> String {
+        format!("//{}/{}", self.service_name(), self)
+    }
+
+    /// Parses a full resource name, which must belong to the service of the resource.
+    pub fn from_full_name(full_name: &str) -> Result<Self, ResourceNameError> {
+        strip_service(full_name, "golden.example.com")?.parse()
+    }
+
+    /// Returns the API version of the resource, from its proto package.
+    pub fn api_version(&self) -> &'static str {
+        "v1"
+    }
+
+    /// Returns the resource URI in the given API version, or else in the one of the resource.
+    pub fn to_uri(&self, version: Option<&str>) -> String {
+        let version = version.unwrap_or(self.api_version());
+        format!("https://{}/{}/{}", self.service_name(), version, self)
+    }
+
+    /// Parses a resource URI of the service of the resource, and returns the name with the
+    /// API version of the URI.
+    pub fn from_uri(uri: &str) -> Result<(Self, &str), ResourceNameError> {
+        let (version, name) = aip_resourcename::split_uri(uri, "golden.example.com")?;
+        Ok((name.parse()?, version))
+    }
+
+    /// Returns true if any field contains a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        self.project == "-" || self.publisher == "-"
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        (self.project == "-" || self.project == concrete.project)
+            && (self.publisher == "-" || self.publisher == concrete.publisher)
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(mut self, variables: &[&str]) -> Self {
+        if variables.contains(&"project") {
+            self.project = "-".to_string();
+        }
+        if variables.contains(&"publisher") {
+            self.publisher = "-".to_string();
+        }
+        self
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        self.validate()?;
+        reject_wildcard("project", &self.project)?;
+        reject_wildcard("publisher", &self.publisher)?;
+        Ok(())
+    }
+
+    /// Returns true if `other` is the name of a resource nested under this one.
+    pub fn is_ancestor_of(&self, other: &str) -> bool {
+        ResourceName::is_ancestor_of(self, other)
+    }
+
+    /// Returns true if the name matches a glob such as `projects/acme/books/*`, where `*`
+    /// matches one segment and a final `**` any suffix. Globs that cannot match names of
+    /// this type match nothing.
+    pub fn matches_glob(&self, glob: &str) -> bool {
+        ResourceName::matches_glob(self, glob)
+    }
+
+}
+
+impl fmt::Display for ProjectsPublisherResourceName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_borrowed(), f)
+    }
+}
+
+impl FromStr for ProjectsPublisherResourceName {
+    type Err = ResourceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ProjectsPublisherResourceNameRef::parse_borrowed(s).map(ProjectsPublisherResourceNameRef::to_owned)
+    }
+}
+
+impl ResourceName for ProjectsPublisherResourceName {
+    const TYPE: &'static str = "golden.example.com/Publisher";
+    const PATTERNS: &'static [&'static str] = &["projects/{project}/publishers/{publisher}"];
+    const SINGULAR: &'static str = "publisher";
+    const PLURAL: &'static str = "publishers";
+
+    fn validate(&self) -> Result<(), ResourceNameError> {
+        self.validate()
+    }
+
+    fn contains_wildcard(&self) -> bool {
+        self.contains_wildcard()
+    }
+}
+
+/// Borrowed view of a ProjectsPublisherResourceName that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ProjectsPublisherResourceNameRef<'a> {
+    pub project: &'a str,
+    pub publisher: &'a str,
+}
+
+impl<'a> ProjectsPublisherResourceNameRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut scanner = Scanner::new(s, 4);
+        scanner.literal("projects")?;
+        let project = scanner.variable()?;
+        scanner.literal("publishers")?;
+        let publisher = scanner.variable()?;
+        scanner.finish()?;
+        Self::from_segments(project, publisher)
+    }
+
+    /// Creates a ProjectsPublisherResourceNameRef from parsed segments and validates it.
+    fn from_segments(project: &'a str, publisher: &'a str) -> Result<Self, ResourceNameError> {
+        let name = Self { project, publisher };
+        name.validate()?;
+        Ok(name)
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        validate_segment("project", self.project)?;
+        validate_segment("publisher", self.publisher)?;
+        Ok(())
+    }
+
+    /// Converts the borrowed view into an owned ProjectsPublisherResourceName.
+    pub fn to_owned(self) -> ProjectsPublisherResourceName {
+        ProjectsPublisherResourceName {
+            project: self.project.to_string(),
+            publisher: self.publisher.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ProjectsPublisherResourceNameRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "projects/{project}/publishers/{publisher}"
+            , project = self.project
+            , publisher = self.publisher
+        )
+    }
+}
+
+/// Resource name of the original pattern `publishers/{publisher}` of golden.example.com/Publisher,
+/// from before the resource had several patterns.
+#[deprecated(note = "golden.example.com/Publisher has several patterns, use PublisherResourceName")]
+pub type SinglePatternPublisherResourceName = PublishersPublisherResourceName;
+
+impl From<PublishersPublisherResourceName> for PublisherResourceName {
+    fn from(name: PublishersPublisherResourceName) -> Self {
+        PublisherResourceName::Publishers(name)
+    }
+}
+
+impl TryFrom<PublisherResourceName> for PublishersPublisherResourceName {
+    type Error = ResourceNameError;
+
+    /// Converts names of the original pattern `publishers/{publisher}`, and fails for the others.
+    fn try_from(name: PublisherResourceName) -> Result<Self, Self::Error> {
+        match name {
+            PublisherResourceName::Publishers(name) => Ok(name),
+            name => name.to_string().parse(),
+        }
+    }
+}
+
+impl PublisherResourceName {
+    /// Creates a new PublisherResourceName with the original pattern `publishers/{publisher}`.
+    #[deprecated(note = "golden.example.com/Publisher has several patterns, use PublisherResourceName::Publishers")]
+    pub fn new(publisher: impl Into<String>) -> Self {
+        PublisherResourceName::Publishers(PublishersPublisherResourceName::new(publisher))
+    }
+}
+
+/// Parent of a PublisherResourceName: `projects/{project}`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PublisherParent {
+    pub project: String,
+}
+
+impl PublisherParent {
+    /// Creates a new PublisherParent.
+    pub fn new(
+        project: impl Into<String>
+    ) -> Self {
+        Self {
+            project: project.into(),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        self.as_borrowed().validate()
+    }
+
+    /// Borrows the resource name as a PublisherParentRef.
+    pub fn as_borrowed(&self) -> PublisherParentRef<'_> {
+        PublisherParentRef {
+            project: &self.project,
+        }
+    }
+
+    /// Returns true if any field contains a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        self.project == "-"
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        self.project == "-" || self.project == concrete.project
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(mut self, variables: &[&str]) -> Self {
+        if variables.contains(&"project") {
+            self.project = "-".to_string();
+        }
+        self
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        self.validate()?;
+        reject_wildcard("project", &self.project)?;
+        Ok(())
+    }
+
+}
+
+impl fmt::Display for PublisherParent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_borrowed(), f)
+    }
+}
+
+impl FromStr for PublisherParent {
+    type Err = ResourceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        PublisherParentRef::parse_borrowed(s).map(PublisherParentRef::to_owned)
+    }
+}
+
+/// Borrowed view of a PublisherParent that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PublisherParentRef<'a> {
+    pub project: &'a str,
+}
+
+impl<'a> PublisherParentRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut scanner = Scanner::new(s, 2);
+        scanner.literal("projects")?;
+        let project = scanner.variable()?;
+        scanner.finish()?;
+        Self::from_segments(project)
+    }
+
+    /// Creates a PublisherParentRef from parsed segments and validates it.
+    fn from_segments(project: &'a str) -> Result<Self, ResourceNameError> {
+        let name = Self { project };
+        name.validate()?;
+        Ok(name)
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        validate_segment("project", self.project)?;
+        Ok(())
+    }
+
+    /// Converts the borrowed view into an owned PublisherParent.
+    pub fn to_owned(self) -> PublisherParent {
+        PublisherParent {
+            project: self.project.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for PublisherParentRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "projects/{project}"
+            , project = self.project
+        )
+    }
+}
+
+impl ProjectsPublisherResourceName {
+    /// Returns the name of the parent of this resource.
+    pub fn parent(&self) -> PublisherParent {
+        PublisherParent {
+            project: self.project.clone(),
+        }
+    }
+}
+
+impl PublisherResourceName {
+    /// Returns the name of the parent of this resource, or None for top-level patterns.
+    pub fn parent(&self) -> Option<PublisherParent> {
+        match self {
+            PublisherResourceName::Publishers(_) => None,
+            PublisherResourceName::Projects(inner) => Some(inner.parent()),
+        }
+    }
+}
+
+impl PublisherParent {
+    /// Creates the name of a PublisherResourceName nested under this one.
+    pub fn publisher(&self, publisher: impl Into<String>) -> PublisherResourceName {
+        PublisherResourceName::Projects(ProjectsPublisherResourceName { project: self.project.clone(), publisher: publisher.into() })
+    }
+}
+
@@ -0,0 +1,398 @@
+// Code generated by protoc-gen-rust-aip. DO NOT EDIT.
+//
+// versions:
+// 	protoc-gen-rust-aip development
+// 	protoc (unknown)
+// source: golden.proto
+
+/// Resource name for golden.example.com/Book
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BookResourceName {
+    pub project: String,
+    pub book: String,
+}
+
+impl BookResourceName {
+    /// Creates a new BookResourceName.
+    pub fn new(
+        project: impl Into<String>,
+        book: impl Into<String>
+    ) -> Self {
+        Self {
+            project: project.into(),
+            book: book.into(),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        self.as_borrowed().validate()
+    }
+
+    /// Borrows the resource name as a BookResourceNameRef.
+    pub fn as_borrowed(&self) -> BookResourceNameRef<'_> {
+        BookResourceNameRef {
+            project: &self.project,
+            book: &self.book,
+        }
+    }
+
+    /// Returns the resource type.
+    pub fn resource_type(&self) -> &'static str {
+        "golden.example.com/Book"
+    }
+
+    /// Returns the name of the service the resource belongs to.
+    pub fn service_name(&self) -> &'static str {
+        "golden.example.com"
+    }
+
+    /// Returns the full resource name, prefixed with the service name.
+    pub fn to_full_name(&self) -> String {
+        format!("//{}/{}", self.service_name(), self)
+    }
+
+    /// Parses a full resource name, which must belong to the service of the resource.
+    pub fn from_full_name(full_name: &str) -> Result<Self, ResourceNameError> {
+        strip_service(full_name, "golden.example.com")?.parse()
+    }
+
+    /// Returns the API version of the resource, from its proto package.
+    pub fn api_version(&self) -> &'static str {
+        "v1"
+    }
+
+    /// Returns the resource URI in the given API version, or else in the one of the resource.
+    pub fn to_uri(&self, version: Option<&str>) -> String {
+        let version = version.unwrap_or(self.api_version());
+        format!("https://{}/{}/{}", self.service_name(), version, self)
+    }
+
+    /// Parses a resource URI of the service of the resource, and returns the name with the
+    /// API version of the URI.
+    pub fn from_uri(uri: &str) -> Result<(Self, &str), ResourceNameError> {
+        let (version, name) = aip_resourcename::split_uri(uri, "golden.example.com")?;
+        Ok((name.parse()?, version))
+    }
+
+    /// Returns true if any field contains a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        self.project == "-" || self.book == "-"
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        (self.project == "-" || self.project == concrete.project)
+            && (self.book == "-" || self.book == concrete.book)
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(mut self, variables: &[&str]) -> Self {
+        if variables.contains(&"project") {
+            self.project = "-".to_string();
+        }
+        if variables.contains(&"book") {
+            self.book = "-".to_string();
+        }
+        self
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        self.validate()?;
+        reject_wildcard("project", &self.project)?;
+        reject_wildcard("book", &self.book)?;
+        Ok(())
+    }
+
+    /// Returns true if `other` is the name of a resource nested under this one.
+    pub fn is_ancestor_of(&self, other: &str) -> bool {
+        ResourceName::is_ancestor_of(self, other)
+    }
+
+    /// Returns true if the name matches a glob such as `projects/acme/books/*`, where `*`
+    /// matches one segment and a final `**` any suffix. Globs that cannot match names of
+    /// this type match nothing.
+    pub fn matches_glob(&self, glob: &str) -> bool {
+        ResourceName::matches_glob(self, glob)
+    }
+
+}
+
+impl fmt::Display for BookResourceName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_borrowed(), f)
+    }
+}
+
+impl FromStr for BookResourceName {
+    type Err = ResourceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        BookResourceNameRef::parse_borrowed(s).map(BookResourceNameRef::to_owned)
+    }
+}
+
+impl ResourceName for BookResourceName {
+    const TYPE: &'static str = "golden.example.com/Book";
+    const PATTERNS: &'static [&'static str] = &["projects/{project}/books/{book}"];
+    const SINGULAR: &'static str = "book";
+    const PLURAL: &'static str = "books";
+
+    fn validate(&self) -> Result<(), ResourceNameError> {
+        self.validate()
+    }
+
+    fn contains_wildcard(&self) -> bool {
+        self.contains_wildcard()
+    }
+}
+
+/// Borrowed view of a BookResourceName that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BookResourceNameRef<'a> {
+    pub project: &'a str,
+    pub book: &'a str,
+}
+
+impl<'a> BookResourceNameRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut scanner = Scanner::new(s, 4);
+        scanner.literal("projects")?;
+        let project = scanner.variable()?;
+        scanner.literal("books")?;
+        let book = scanner.variable()?;
+        scanner.finish()?;
+        Self::from_segments(project, book)
+    }
+
+    /// Creates a BookResourceNameRef from parsed segments and validates it.
+    fn from_segments(project: &'a str, book: &'a str) -> Result<Self, ResourceNameError> {
+        let name = Self { project, book };
+        name.validate()?;
+        Ok(name)
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        validate_segment("project", self.project)?;
+        validate_segment("book", self.book)?;
+        aip_resourcename::reject_revision_separator("book", self.book, "BookRevisionName")?;
+        Ok(())
+    }
+
+    /// Converts the borrowed view into an owned BookResourceName.
+    pub fn to_owned(self) -> BookResourceName {
+        BookResourceName {
+            project: self.project.to_string(),
+            book: self.book.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for BookResourceNameRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "projects/{project}/books/{book}"
+            , project = self.project
+            , book = self.book
+        )
+    }
+}
+
+/// Parent of a BookResourceName: `projects/{project}`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BookParent {
+    pub project: String,
+}
+
+impl BookParent {
+    /// Creates a new BookParent.
+    pub fn new(
+        project: impl Into<String>
+    ) -> Self {
+        Self {
+            project: project.into(),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        self.as_borrowed().validate()
+    }
+
+    /// Borrows the resource name as a BookParentRef.
+    pub fn as_borrowed(&self) -> BookParentRef<'_> {
+        BookParentRef {
+            project: &self.project,
+        }
+    }
+
+    /// Returns true if any field contains a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        self.project == "-"
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        self.project == "-" || self.project == concrete.project
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(mut self, variables: &[&str]) -> Self {
+        if variables.contains(&"project") {
+            self.project = "-".to_string();
+        }
+        self
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        self.validate()?;
+        reject_wildcard("project", &self.project)?;
+        Ok(())
+    }
+
+}
+
+impl fmt::Display for BookParent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_borrowed(), f)
+    }
+}
+
+impl FromStr for BookParent {
+    type Err = ResourceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        BookParentRef::parse_borrowed(s).map(BookParentRef::to_owned)
+    }
+}
+
+/// Borrowed view of a BookParent that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BookParentRef<'a> {
+    pub project: &'a str,
+}
+
+impl<'a> BookParentRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut scanner = Scanner::new(s, 2);
+        scanner.literal("projects")?;
+        let project = scanner.variable()?;
+        scanner.finish()?;
+        Self::from_segments(project)
+    }
+
+    /// Creates a BookParentRef from parsed segments and validates it.
+    fn from_segments(project: &'a str) -> Result<Self, ResourceNameError> {
+        let name = Self { project };
+        name.validate()?;
+        Ok(name)
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        validate_segment("project", self.project)?;
+        Ok(())
+    }
+
+    /// Converts the borrowed view into an owned BookParent.
+    pub fn to_owned(self) -> BookParent {
+        BookParent {
+            project: self.project.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for BookParentRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "projects/{project}"
+            , project = self.project
+        )
+    }
+}
+
+impl BookResourceName {
+    /// Returns the name of the parent of this resource.
+    pub fn parent(&self) -> BookParent {
+        BookParent {
+            project: self.project.clone(),
+        }
+    }
+}
+
+impl BookParent {
+    /// Creates the name of a BookResourceName nested under this one.
+    pub fn book(&self, book: impl Into<String>) -> BookResourceName {
+        BookResourceName { project: self.project.clone(), book: book.into() }
+    }
+}
+
+/// Revision-qualified name of a BookResourceName, e.g. `name@revision`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BookRevisionName {
+    pub name: BookResourceName,
+    pub revision: Option<String>,
+}
+
+impl BookRevisionName {
+    /// Creates a new BookRevisionName for a revision of the resource.
+    pub fn new(name: BookResourceName, revision: impl Into<String>) -> Self {
+        Self {
+            name,
+            revision: Some(revision.into()),
+        }
+    }
+
+    /// Validates the resource name and the revision ID.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        self.name.validate()?;
+        match &self.revision {
+            Some(revision) => aip_resourcename::validate_revision_id(revision),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns the name of the resource, without the revision.
+    pub fn without_revision(&self) -> BookResourceName {
+        self.name.clone()
+    }
+}
+
+impl From<BookResourceName> for BookRevisionName {
+    fn from(name: BookResourceName) -> Self {
+        Self {
+            name,
+            revision: None,
+        }
+    }
+}
+
+impl fmt::Display for BookRevisionName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.revision {
+            Some(revision) => write!(f, "{}@{}", self.name, revision),
+            None => write!(f, "{}", self.name),
+        }
+    }
+}
+
+impl FromStr for BookRevisionName {
+    type Err = ResourceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, revision) = aip_resourcename::split_revision(s);
+        if let Some(revision) = revision {
+            aip_resourcename::validate_revision_id(revision)?;
+        }
+        Ok(Self {
+            name: name.parse()?,
+            revision: revision.map(str::to_string),
+        })
+    }
+}
+
@@ -0,0 +1,1650 @@
+// Code generated by protoc-gen-rust-aip. DO NOT EDIT.
+//
+// versions:
+// 	protoc-gen-rust-aip development
+// 	protoc (unknown)
+// source: golden.proto
+
+/// Resource name for golden.example.com/Project
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ProjectResourceName {
+    pub project: String,
+}
+
+impl ProjectResourceName {
+    /// Creates a new ProjectResourceName.
+    pub fn new(
+        project: impl Into<String>
+    ) -> Self {
+        Self {
+            project: project.into(),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        self.as_borrowed().validate()
+    }
+
+    /// Borrows the resource name as a ProjectResourceNameRef.
+    pub fn as_borrowed(&self) -> ProjectResourceNameRef<'_> {
+        ProjectResourceNameRef {
+            project: &self.project,
+        }
+    }
+
+    /// Returns the resource type.
+    pub fn resource_type(&self) -> &'static str {
+        "golden.example.com/Project"
+    }
+
+    /// Returns the name of the service the resource belongs to.
+    pub fn service_name(&self) -> &'static str {
+        "golden.example.com"
+    }
+
+    /// Returns the full resource name, prefixed with the service name.
+    pub fn to_full_name(&self) -> String {
+        format!("//{}/{}", self.service_name(), self)
+    }
+
+    /// Parses a full resource name, which must belong to the service of the resource.
+    pub fn from_full_name(full_name: &str) -> Result<Self, ResourceNameError> {
+        strip_service(full_name, "golden.example.com")?.parse()
+    }
+
+    /// Returns the API version of the resource, from its proto package.
+    pub fn api_version(&self) -> &'static str {
+        "v1"
+    }
+
+    /// Returns the resource URI in the given API version, or else in the one of the resource.
+    pub fn to_uri(&self, version: Option<&str>) -> String {
+        let version = version.unwrap_or(self.api_version());
+        format!("https://{}/{}/{}", self.service_name(), version, self)
+    }
+
+    /// Parses a resource URI of the service of the resource, and returns the name with the
+    /// API version of the URI.
+    pub fn from_uri(uri: &str) -> Result<(Self, &str), ResourceNameError> {
+        let (version, name) = aip_resourcename::split_uri(uri, "golden.example.com")?;
+        Ok((name.parse()?, version))
+    }
+
+    /// Returns true if any field contains a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        self.project == "-"
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        self.project == "-" || self.project == concrete.project
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(mut self, variables: &[&str]) -> Self {
+        if variables.contains(&"project") {
+            self.project = "-".to_string();
+        }
+        self
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        self.validate()?;
+        reject_wildcard("project", &self.project)?;
+        Ok(())
+    }
+
+    /// Returns true if `other` is the name of a resource nested under this one.
+    pub fn is_ancestor_of(&self, other: &str) -> bool {
+        ResourceName::is_ancestor_of(self, other)
+    }
+
+    /// Returns true if the name matches a glob such as `projects/acme/books/*`, where `*`
+    /// matches one segment and a final `**` any suffix. Globs that cannot match names of
+    /// this type match nothing.
+    pub fn matches_glob(&self, glob: &str) -> bool {
+        ResourceName::matches_glob(self, glob)
+    }
+
+}
+
+impl fmt::Display for ProjectResourceName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_borrowed(), f)
+    }
+}
+
+impl FromStr for ProjectResourceName {
+    type Err = ResourceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ProjectResourceNameRef::parse_borrowed(s).map(ProjectResourceNameRef::to_owned)
+    }
+}
+
+impl ResourceName for ProjectResourceName {
+    const TYPE: &'static str = "golden.example.com/Project";
+    const PATTERNS: &'static [&'static str] = &["projects/{project}"];
+    const SINGULAR: &'static str = "project";
+    const PLURAL: &'static str = "projects";
+
+    fn validate(&self) -> Result<(), ResourceNameError> {
+        self.validate()
+    }
+
+    fn contains_wildcard(&self) -> bool {
+        self.contains_wildcard()
+    }
+}
+
+/// Borrowed view of a ProjectResourceName that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ProjectResourceNameRef<'a> {
+    pub project: &'a str,
+}
+
+impl<'a> ProjectResourceNameRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut scanner = Scanner::new(s, 2);
+        scanner.literal("projects")?;
+        let project = scanner.variable()?;
+        scanner.finish()?;
+        Self::from_segments(project)
+    }
+
+    /// Creates a ProjectResourceNameRef from parsed segments and validates it.
+    fn from_segments(project: &'a str) -> Result<Self, ResourceNameError> {
+        let name = Self { project };
+        name.validate()?;
+        Ok(name)
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        validate_segment("project", self.project)?;
+        Ok(())
+    }
+
+    /// Converts the borrowed view into an owned ProjectResourceName.
+    pub fn to_owned(self) -> ProjectResourceName {
+        ProjectResourceName {
+            project: self.project.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ProjectResourceNameRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "projects/{project}"
+            , project = self.project
+        )
+    }
+}
+
+/// Resource name for golden.example.com/User
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UserResourceName {
+    pub user: String,
+}
+
+impl UserResourceName {
+    /// Creates a new UserResourceName.
+    pub fn new(
+        user: impl Into<String>
+    ) -> Self {
+        Self {
+            user: user.into(),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        self.as_borrowed().validate()
+    }
+
+    /// Borrows the resource name as a UserResourceNameRef.
+    pub fn as_borrowed(&self) -> UserResourceNameRef<'_> {
+        UserResourceNameRef {
+            user: &self.user,
+        }
+    }
+
+    /// Returns the resource type.
+    pub fn resource_type(&self) -> &'static str {
+        "golden.example.com/User"
+    }
+
+    /// Returns the name of the service the resource belongs to.
+    pub fn service_name(&self) -> &'static str {
+        "golden.example.com"
+    }
+
+    /// Returns the full resource name, prefixed with the service name.
+    pub fn to_full_name(&self) -> String {
+        format!("//{}/{}", self.service_name(), self)
+    }
+
+    /// Parses a full resource name, which must belong to the service of the resource.
+    pub fn from_full_name(full_name: &str) -> Result<Self, ResourceNameError> {
+        strip_service(full_name, "golden.example.com")?.parse()
+    }
+
+    /// Returns the API version of the resource, from its proto package.
+    pub fn api_version(&self) -> &'static str {
+        "v1"
+    }
+
+    /// Returns the resource URI in the given API version, or else in the one of the resource.
+    pub fn to_uri(&self, version: Option<&str>) -> String {
+        let version = version.unwrap_or(self.api_version());
+        format!("https://{}/{}/{}", self.service_name(), version, self)
+    }
+
+    /// Parses a resource URI of the service of the resource, and returns the name with the
+    /// API version of the URI.
+    pub fn from_uri(uri: &str) -> Result<(Self, &str), ResourceNameError> {
+        let (version, name) = aip_resourcename::split_uri(uri, "golden.example.com")?;
+        Ok((name.parse()?, version))
+    }
+
+    /// Returns true if any field contains a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        self.user == "-"
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        self.user == "-" || self.user == concrete.user
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(mut self, variables: &[&str]) -> Self {
+        if variables.contains(&"user") {
+            self.user = "-".to_string();
+        }
+        self
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        self.validate()?;
+        reject_wildcard("user", &self.user)?;
+        Ok(())
+    }
+
+    /// Returns true if `other` is the name of a resource nested under this one.
+    pub fn is_ancestor_of(&self, other: &str) -> bool {
+        ResourceName::is_ancestor_of(self, other)
+    }
+
+    /// Returns true if the name matches a glob such as `projects/acme/books/*`, where `*`
+    /// matches one segment and a final `**` any suffix. Globs that cannot match names of
+    /// this type match nothing.
+    pub fn matches_glob(&self, glob: &str) -> bool {
+        ResourceName::matches_glob(self, glob)
+    }
+
+}
+
+impl fmt::Display for UserResourceName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_borrowed(), f)
+    }
+}
+
+impl FromStr for UserResourceName {
+    type Err = ResourceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        UserResourceNameRef::parse_borrowed(s).map(UserResourceNameRef::to_owned)
+    }
+}
+
+impl ResourceName for UserResourceName {
+    const TYPE: &'static str = "golden.example.com/User";
+    const PATTERNS: &'static [&'static str] = &["users/{user}"];
+    const SINGULAR: &'static str = "user";
+    const PLURAL: &'static str = "users";
+
+    fn validate(&self) -> Result<(), ResourceNameError> {
+        self.validate()
+    }
+
+    fn contains_wildcard(&self) -> bool {
+        self.contains_wildcard()
+    }
+}
+
+/// Borrowed view of a UserResourceName that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UserResourceNameRef<'a> {
+    pub user: &'a str,
+}
+
+impl<'a> UserResourceNameRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut scanner = Scanner::new(s, 2);
+        scanner.literal("users")?;
+        let user = scanner.variable()?;
+        scanner.finish()?;
+        Self::from_segments(user)
+    }
+
+    /// Creates a UserResourceNameRef from parsed segments and validates it.
+    fn from_segments(user: &'a str) -> Result<Self, ResourceNameError> {
+        let name = Self { user };
+        name.validate()?;
+        Ok(name)
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        validate_segment("user", self.user)?;
+        Ok(())
+    }
+
+    /// Converts the borrowed view into an owned UserResourceName.
+    pub fn to_owned(self) -> UserResourceName {
+        UserResourceName {
+            user: self.user.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for UserResourceNameRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "users/{user}"
+            , user = self.user
+        )
+    }
+}
+
+/// Resource name for golden.example.com/Settings, a singleton of its parent (AIP-156).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SettingsResourceName {
+    pub project: String,
+}
+
+impl SettingsResourceName {
+    /// Creates a new SettingsResourceName.
+    pub fn new(
+        project: impl Into<String>
+    ) -> Self {
+        Self {
+            project: project.into(),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        self.as_borrowed().validate()
+    }
+
+    /// Borrows the resource name as a SettingsResourceNameRef.
+    pub fn as_borrowed(&self) -> SettingsResourceNameRef<'_> {
+        SettingsResourceNameRef {
+            project: &self.project,
+        }
+    }
+
+    /// Returns the resource type.
+    pub fn resource_type(&self) -> &'static str {
+        "golden.example.com/Settings"
+    }
+
+    /// Returns the name of the service the resource belongs to.
+    pub fn service_name(&self) -> &'static str {
+        "golden.example.com"
+    }
+
+    /// Returns the full resource name, prefixed with the service name.
+    pub fn to_full_name(&self) -> String {
+        format!("//{}/{}", self.service_name(), self)
+    }
+
+    /// Parses a full resource name, which must belong to the service of the resource.
+    pub fn from_full_name(full_name: &str) -> Result<Self, ResourceNameError> {
+        strip_service(full_name, "golden.example.com")?.parse()
+    }
+
+    /// Returns the API version of the resource, from its proto package.
+    pub fn api_version(&self) -> &'static str {
+        "v1"
+    }
+
+    /// Returns the resource URI in the given API version, or else in the one of the resource.
+    pub fn to_uri(&self, version: Option<&str>) -> String {
+        let version = version.unwrap_or(self.api_version());
+        format!("https://{}/{}/{}", self.service_name(), version, self)
+    }
+
+    /// Parses a resource URI of the service of the resource, and returns the name with the
+    /// API version of the URI.
+    pub fn from_uri(uri: &str) -> Result<(Self, &str), ResourceNameError> {
+        let (version, name) = aip_resourcename::split_uri(uri, "golden.example.com")?;
+        Ok((name.parse()?, version))
+    }
+
+    /// Returns true if any field contains a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        self.project == "-"
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        self.project == "-" || self.project == concrete.project
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(mut self, variables: &[&str]) -> Self {
+        if variables.contains(&"project") {
+            self.project = "-".to_string();
+        }
+        self
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        self.validate()?;
+        reject_wildcard("project", &self.project)?;
+        Ok(())
+    }
+
+    /// Returns true if `other` is the name of a resource nested under this one.
+    pub fn is_ancestor_of(&self, other: &str) -> bool {
+        ResourceName::is_ancestor_of(self, other)
+    }
+
+    /// Returns true if the name matches a glob such as `projects/acme/books/*`, where `*`
+    /// matches one segment and a final `**` any suffix. Globs that cannot match names of
+    /// this type match nothing.
+    pub fn matches_glob(&self, glob: &str) -> bool {
+        ResourceName::matches_glob(self, glob)
+    }
+
+}
+
+impl fmt::Display for SettingsResourceName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_borrowed(), f)
+    }
+}
+
+impl FromStr for SettingsResourceName {
+    type Err = ResourceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        SettingsResourceNameRef::parse_borrowed(s).map(SettingsResourceNameRef::to_owned)
+    }
+}
+
+impl ResourceName for SettingsResourceName {
+    const TYPE: &'static str = "golden.example.com/Settings";
+    const PATTERNS: &'static [&'static str] = &["projects/{project}/settings"];
+    const SINGULAR: &'static str = "settings";
+    const PLURAL: &'static str = "settings";
+
+    fn validate(&self) -> Result<(), ResourceNameError> {
+        self.validate()
+    }
+
+    fn contains_wildcard(&self) -> bool {
+        self.contains_wildcard()
+    }
+}
+
+/// Borrowed view of a SettingsResourceName that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SettingsResourceNameRef<'a> {
+    pub project: &'a str,
+}
+
+impl<'a> SettingsResourceNameRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut scanner = Scanner::new(s, 3);
+        scanner.literal("projects")?;
+        let project = scanner.variable()?;
+        scanner.literal("settings")?;
+        scanner.finish()?;
+        Self::from_segments(project)
+    }
+
+    /// Creates a SettingsResourceNameRef from parsed segments and validates it.
+    fn from_segments(project: &'a str) -> Result<Self, ResourceNameError> {
+        let name = Self { project };
+        name.validate()?;
+        Ok(name)
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        validate_segment("project", self.project)?;
+        Ok(())
+    }
+
+    /// Converts the borrowed view into an owned SettingsResourceName.
+    pub fn to_owned(self) -> SettingsResourceName {
+        SettingsResourceName {
+            project: self.project.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for SettingsResourceNameRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "projects/{project}/settings"
+            , project = self.project
+        )
+    }
+}
+
+/// Parent of a SettingsResourceName.
+pub type SettingsParent = ProjectResourceName;
+
+impl SettingsResourceName {
+    /// Returns the name of the parent of this resource.
+    pub fn parent(&self) -> ProjectResourceName {
+        ProjectResourceName {
+            project: self.project.clone(),
+        }
+    }
+}
+
+impl SettingsResourceName {
+    /// Returns the name of the ProjectResourceName this resource is nested under.
+    pub fn project(&self) -> ProjectResourceName {
+        ProjectResourceName {
+            project: self.project.clone(),
+        }
+    }
+}
+
+impl ProjectResourceName {
+    /// Creates the name of a SettingsResourceName nested under this one.
+    pub fn settings(&self) -> SettingsResourceName {
+        SettingsResourceName { project: self.project.clone() }
+    }
+}
+
+/// Multi-pattern resource name for golden.example.com/Config
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ConfigResourceName {
+    Projects(ProjectsConfigResourceName),
+    Users(UsersConfigResourceName),
+}
+
+impl ConfigResourceName {
+    /// Returns the resource type.
+    pub fn resource_type(&self) -> &'static str {
+        "golden.example.com/Config"
+    }
+
+    /// Returns true if any field contains a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        match self {
+            ConfigResourceName::Projects(inner) => inner.contains_wildcard(),
+            ConfigResourceName::Users(inner) => inner.contains_wildcard(),
+        }
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    /// Names of different patterns are matched segment by segment.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        match (self, concrete) {
+            (ConfigResourceName::Projects(inner), ConfigResourceName::Projects(concrete)) => inner.matches(concrete),
+            (ConfigResourceName::Users(inner), ConfigResourceName::Users(concrete)) => inner.matches(concrete),
+            _ => aip_resourcename::wildcard_matches(&self.to_string(), &concrete.to_string()),
+        }
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(self, variables: &[&str]) -> Self {
+        match self {
+            ConfigResourceName::Projects(inner) => ConfigResourceName::Projects(inner.with_wildcards_in(variables)),
+            ConfigResourceName::Users(inner) => ConfigResourceName::Users(inner.with_wildcards_in(variables)),
+        }
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        match self {
+            ConfigResourceName::Projects(inner) => inner.validate_concrete(),
+            ConfigResourceName::Users(inner) => inner.validate_concrete(),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        match self {
+            ConfigResourceName::Projects(inner) => inner.validate(),
+            ConfigResourceName::Users(inner) => inner.validate(),
+        }
+    }
+
+    /// Borrows the resource name as a ConfigResourceNameRef.
+    pub fn as_borrowed(&self) -> ConfigResourceNameRef<'_> {
+        match self {
+            ConfigResourceName::Projects(inner) => ConfigResourceNameRef::Projects(inner.as_borrowed()),
+            ConfigResourceName::Users(inner) => ConfigResourceNameRef::Users(inner.as_borrowed()),
+        }
+    }
+
+    /// Returns true if `other` is the name of a resource nested under this one.
+    pub fn is_ancestor_of(&self, other: &str) -> bool {
+        ResourceName::is_ancestor_of(self, other)
+    }
+
+    /// Returns true if the name matches a glob such as `projects/acme/books/*`, where `*`
+    /// matches one segment and a final `**` any suffix. Globs that cannot match names of
+    /// this type match nothing.
+    pub fn matches_glob(&self, glob: &str) -> bool {
+        ResourceName::matches_glob(self, glob)
+    }
+
+    /// Returns the name of the service the resource belongs to.
+    pub fn service_name(&self) -> &'static str {
+        match self {
+            ConfigResourceName::Projects(inner) => inner.service_name(),
+            ConfigResourceName::Users(inner) => inner.service_name(),
+        }
+    }
+
+    /// Returns the full resource name, prefixed with the service name.
+    pub fn to_full_name(&self) -> String {
+        match self {
+            ConfigResourceName::Projects(inner) => inner.to_full_name(),
+            ConfigResourceName::Users(inner) => inner.to_full_name(),
+        }
+    }
+
+    /// Parses a full resource name, which must belong to the service of the resource.
+    pub fn from_full_name(full_name: &str) -> Result<Self, ResourceNameError> {
+        strip_service(full_name, "golden.example.com")?.parse()
+    }
+
+    /// Returns the API version of the resource, from its proto package.
+    pub fn api_version(&self) -> &'static str {
+        match self {
+            ConfigResourceName::Projects(inner) => inner.api_version(),
+            ConfigResourceName::Users(inner) => inner.api_version(),
+        }
+    }
+
+    /// Returns the resource URI in the given API version, or else in the one of the resource.
+    pub fn to_uri(&self, version: Option<&str>) -> String {
+        match self {
+            ConfigResourceName::Projects(inner) => inner.to_uri(version),
+            ConfigResourceName::Users(inner) => inner.to_uri(version),
+        }
+    }
+
+    /// Parses a resource URI of the service of the resource, and returns the name with the
+    /// API version of the URI.
+    pub fn from_uri(uri: &str) -> Result<(Self, &str), ResourceNameError> {
+        let (version, name) = aip_resourcename::split_uri(uri, "golden.example.com")?;
+        Ok((name.parse()?, version))
+    }
+
+}
+
+impl fmt::Display for ConfigResourceName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigResourceName::Projects(inner) => write!(f, "{inner}"),
+            ConfigResourceName::Users(inner) => write!(f, "{inner}"),
+        }
+    }
+}
+
+impl FromStr for ConfigResourceName {
+    type Err = ResourceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ConfigResourceNameRef::parse_borrowed(s).map(ConfigResourceNameRef::to_owned)
+    }
+}
+
+impl ResourceName for ConfigResourceName {
+    const TYPE: &'static str = "golden.example.com/Config";
+    const PATTERNS: &'static [&'static str] = &["projects/{project}/config", "users/{user}/config"];
+    const SINGULAR: &'static str = "config";
+    const PLURAL: &'static str = "configs";
+
+    fn validate(&self) -> Result<(), ResourceNameError> {
+        self.validate()
+    }
+
+    fn contains_wildcard(&self) -> bool {
+        self.contains_wildcard()
+    }
+}
+
+/// Borrowed view of a ConfigResourceName that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConfigResourceNameRef<'a> {
+    Projects(ProjectsConfigResourceNameRef<'a>),
+    Users(UsersConfigResourceNameRef<'a>),
+}
+
+impl<'a> ConfigResourceNameRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut parts = s.split('/');
+        match parts.next() {
+            Some("projects") => {
+                match parts.next() {
+                    Some(part1) => {
+                        match parts.next() {
+                            Some("config") => {
+                                match parts.next() {
+                                    Some(part) => Err(ResourceNameError::no_matching_pattern("projects/{project}/config", 3, Some(part))),
+                                    None => ProjectsConfigResourceNameRef::from_segments(part1).map(Self::Projects),
+                                }
+                            }
+                            Some(part) => Err(ResourceNameError::no_matching_pattern("projects/{project}/config", 2, Some(part))),
+                            None => Err(ResourceNameError::no_matching_pattern("projects/{project}/config", 2, None)),
+                        }
+                    }
+                    None => Err(ResourceNameError::no_matching_pattern("projects/{project}/config", 1, None)),
+                }
+            }
+            Some("users") => {
+                match parts.next() {
+                    Some(part1) => {
+                        match parts.next() {
+                            Some("config") => {
+                                match parts.next() {
+                                    Some(part) => Err(ResourceNameError::no_matching_pattern("users/{user}/config", 3, Some(part))),
+                                    None => UsersConfigResourceNameRef::from_segments(part1).map(Self::Users),
+                                }
+                            }
+                            Some(part) => Err(ResourceNameError::no_matching_pattern("users/{user}/config", 2, Some(part))),
+                            None => Err(ResourceNameError::no_matching_pattern("users/{user}/config", 2, None)),
+                        }
+                    }
+                    None => Err(ResourceNameError::no_matching_pattern("users/{user}/config", 1, None)),
+                }
+            }
+            Some(part) => Err(ResourceNameError::no_matching_pattern("projects/{project}/config", 0, Some(part))),
+            None => Err(ResourceNameError::no_matching_pattern("projects/{project}/config", 0, None)),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        match self {
+            ConfigResourceNameRef::Projects(inner) => inner.validate(),
+            ConfigResourceNameRef::Users(inner) => inner.validate(),
+        }
+    }
+
+    /// Converts the borrowed view into an owned ConfigResourceName.
+    pub fn to_owned(self) -> ConfigResourceName {
+        match self {
+            ConfigResourceNameRef::Projects(inner) => ConfigResourceName::Projects(inner.to_owned()),
+            ConfigResourceNameRef::Users(inner) => ConfigResourceName::Users(inner.to_owned()),
+        }
+    }
+}
+
+impl fmt::Display for ConfigResourceNameRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigResourceNameRef::Projects(inner) => write!(f, "{inner}"),
+            ConfigResourceNameRef::Users(inner) => write!(f, "{inner}"),
+        }
+    }
+}
+
+/// Parses a resource name string and returns the appropriate type.
+pub fn parse_config_resource_name(name: &str) -> Result<ConfigResourceName, ResourceNameError> {
+    ConfigResourceName::from_str(name)
+}
+
+/// Resource name for golden.example.com/Config, a singleton of its parent (AIP-156).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ProjectsConfigResourceName {
+    pub project: String,
+}
+
+impl ProjectsConfigResourceName {
+    /// Creates a new ProjectsConfigResourceName.
+    pub fn new(
+        project: impl Into<String>
+    ) -> Self {
+        Self {
+            project: project.into(),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        self.as_borrowed().validate()
+    }
+
+    /// Borrows the resource name as a ProjectsConfigResourceNameRef.
+    pub fn as_borrowed(&self) -> ProjectsConfigResourceNameRef<'_> {
+        ProjectsConfigResourceNameRef {
+            project: &self.project,
+        }
+    }
+
+    /// Returns the resource type.
+    pub fn resource_type(&self) -> &'static str {
+        "golden.example.com/Config"
+    }
+
+    /// Returns the name of the service the resource belongs to.
+    pub fn service_name(&self) -> &'static str {
+        "golden.example.com"
+    }
+
+    /// Returns the full resource name, prefixed with the service name.
+    pub fn to_full_name(&self) -> String {
+        format!("//{}/{}", self.service_name(), self)
+    }
+
+    /// Parses a full resource name, which must belong to the service of the resource.
+    pub fn from_full_name(full_name: &str) -> Result<Self, ResourceNameError> {
+        strip_service(full_name, "golden.example.com")?.parse()
+    }
+
+    /// Returns the API version of the resource, from its proto package.
+    pub fn api_version(&self) -> &'static str {
+        "v1"
+    }
+
+    /// Returns the resource URI in the given API version, or else in the one of the resource.
+    pub fn to_uri(&self, version: Option<&str>) -> String {
+        let version = version.unwrap_or(self.api_version());
+        format!("https://{}/{}/{}", self.service_name(), version, self)
+    }
+
+    /// Parses a resource URI of the service of the resource, and returns the name with the
+    /// API version of the URI.
+    pub fn from_uri(uri: &str) -> Result<(Self, &str), ResourceNameError> {
+        let (version, name) = aip_resourcename::split_uri(uri, "golden.example.com")?;
+        Ok((name.parse()?, version))
+    }
+
+    /// Returns true if any field contains a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        self.project == "-"
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        self.project == "-" || self.project == concrete.project
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(mut self, variables: &[&str]) -> Self {
+        if variables.contains(&"project") {
+            self.project = "-".to_string();
+        }
+        self
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        self.validate()?;
+        reject_wildcard("project", &self.project)?;
+        Ok(())
+    }
+
+    /// Returns true if `other` is the name of a resource nested under this one.
+    pub fn is_ancestor_of(&self, other: &str) -> bool {
+        ResourceName::is_ancestor_of(self, other)
+    }
+
+    /// Returns true if the name matches a glob such as `projects/acme/books/*`, where `*`
+    /// matches one segment and a final `**` any suffix. Globs that cannot match names of
+    /// this type match nothing.
+    pub fn matches_glob(&self, glob: &str) -> bool {
+        ResourceName::matches_glob(self, glob)
+    }
+
+}
+
+impl fmt::Display for ProjectsConfigResourceName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_borrowed(), f)
+    }
+}
+
+impl FromStr for ProjectsConfigResourceName {
+    type Err = ResourceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ProjectsConfigResourceNameRef::parse_borrowed(s).map(ProjectsConfigResourceNameRef::to_owned)
+    }
+}
+
+impl ResourceName for ProjectsConfigResourceName {
+    const TYPE: &'static str = "golden.example.com/Config";
+    const PATTERNS: &'static [&'static str] = &["projects/{project}/config"];
+    const SINGULAR: &'static str = "config";
+    const PLURAL: &'static str = "configs";
+
+    fn validate(&self) -> Result<(), ResourceNameError> {
+        self.validate()
+    }
+
+    fn contains_wildcard(&self) -> bool {
+        self.contains_wildcard()
+    }
+}
+
+/// Borrowed view of a ProjectsConfigResourceName that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ProjectsConfigResourceNameRef<'a> {
+    pub project: &'a str,
+}
+
+impl<'a> ProjectsConfigResourceNameRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut scanner = Scanner::new(s, 3);
+        scanner.literal("projects")?;
+        let project = scanner.variable()?;
+        scanner.literal("config")?;
+        scanner.finish()?;
+        Self::from_segments(project)
+    }
+
+    /// Creates a ProjectsConfigResourceNameRef from parsed segments and validates it.
+    fn from_segments(project: &'a str) -> Result<Self, ResourceNameError> {
+        let name = Self { project };
+        name.validate()?;
+        Ok(name)
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        validate_segment("project", self.project)?;
+        Ok(())
+    }
+
+    /// Converts the borrowed view into an owned ProjectsConfigResourceName.
+    pub fn to_owned(self) -> ProjectsConfigResourceName {
+        ProjectsConfigResourceName {
+            project: self.project.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ProjectsConfigResourceNameRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "projects/{project}/config"
+            , project = self.project
+        )
+    }
+}
+
+/// Resource name for golden.example.com/Config, a singleton of its parent (AIP-156).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UsersConfigResourceName {
+    pub user: String,
+}
+
+impl UsersConfigResourceName {
+    /// Creates a new UsersConfigResourceName.
+    pub fn new(
+        user: impl Into<String>
+    ) -> Self {
+        Self {
+            user: user.into(),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        self.as_borrowed().validate()
+    }
+
+    /// Borrows the resource name as a UsersConfigResourceNameRef.
+    pub fn as_borrowed(&self) -> UsersConfigResourceNameRef<'_> {
+        UsersConfigResourceNameRef {
+            user: &self.user,
+        }
+    }
+
+    /// Returns the resource type.
+    pub fn resource_type(&self) -> &'static str {
+        "golden.example.com/Config"
+    }
+
+    /// Returns the name of the service the resource belongs to.
+    pub fn service_name(&self) -> &'static str {
+        "golden.example.com"
+    }
+
+    /// Returns the full resource name, prefixed with the service name.
+    pub fn to_full_name(&self) -> String {
+        format!("//{}/{}", self.service_name(), self)
+    }
+
+    /// Parses a full resource name, which must belong to the service of the resource.
+    pub fn from_full_name(full_name: &str) -> Result<Self, ResourceNameError> {
+        strip_service(full_name, "golden.example.com")?.parse()
+    }
+
+    /// Returns the API version of the resource, from its proto package.
+    pub fn api_version(&self) -> &'static str {
+        "v1"
+    }
+
+    /// Returns the resource URI in the given API version, or else in the one of the resource.
+    pub fn to_uri(&self, version: Option<&str>) -> String {
+        let version = version.unwrap_or(self.api_version());
+        format!("https://{}/{}/{}", self.service_name(), version, self)
+    }
+
+    /// Parses a resource URI of the service of the resource, and returns the name with the
+    /// API version of the URI.
+    pub fn from_uri(uri: &str) -> Result<(Self, &str), ResourceNameError> {
+        let (version, name) = aip_resourcename::split_uri(uri, "golden.example.com")?;
+        Ok((name.parse()?, version))
+    }
+
+    /// Returns true if any field contains a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        self.user == "-"
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        self.user == "-" || self.user == concrete.user
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(mut self, variables: &[&str]) -> Self {
+        if variables.contains(&"user") {
+            self.user = "-".to_string();
+        }
+        self
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        self.validate()?;
+        reject_wildcard("user", &self.user)?;
+        Ok(())
+    }
+
+    /// Returns true if `other` is the name of a resource nested under this one.
+    pub fn is_ancestor_of(&self, other: &str) -> bool {
+        ResourceName::is_ancestor_of(self, other)
+    }
+
+    /// Returns true if the name matches a glob such as `projects/acme/books/*`, where `*`
+    /// matches one segment and a final `**` any suffix. Globs that cannot match names of
+    /// this type match nothing.
+    pub fn matches_glob(&self, glob: &str) -> bool {
+        ResourceName::matches_glob(self, glob)
+    }
+
+}
+
+impl fmt::Display for UsersConfigResourceName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_borrowed(), f)
+    }
+}
+
+impl FromStr for UsersConfigResourceName {
+    type Err = ResourceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        UsersConfigResourceNameRef::parse_borrowed(s).map(UsersConfigResourceNameRef::to_owned)
+    }
+}
+
+impl ResourceName for UsersConfigResourceName {
+    const TYPE: &'static str = "golden.example.com/Config";
+    const PATTERNS: &'static [&'static str] = &["users/{user}/config"];
+    const SINGULAR: &'static str = "config";
+    const PLURAL: &'static str = "configs";
+
+    fn validate(&self) -> Result<(), ResourceNameError> {
+        self.validate()
+    }
+
+    fn contains_wildcard(&self) -> bool {
+        self.contains_wildcard()
+    }
+}
+
+/// Borrowed view of a UsersConfigResourceName that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UsersConfigResourceNameRef<'a> {
+    pub user: &'a str,
+}
+
+impl<'a> UsersConfigResourceNameRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut scanner = Scanner::new(s, 3);
+        scanner.literal("users")?;
+        let user = scanner.variable()?;
+        scanner.literal("config")?;
+        scanner.finish()?;
+        Self::from_segments(user)
+    }
+
+    /// Creates a UsersConfigResourceNameRef from parsed segments and validates it.
+    fn from_segments(user: &'a str) -> Result<Self, ResourceNameError> {
+        let name = Self { user };
+        name.validate()?;
+        Ok(name)
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        validate_segment("user", self.user)?;
+        Ok(())
+    }
+
+    /// Converts the borrowed view into an owned UsersConfigResourceName.
+    pub fn to_owned(self) -> UsersConfigResourceName {
+        UsersConfigResourceName {
+            user: self.user.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for UsersConfigResourceNameRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "users/{user}/config"
+            , user = self.user
+        )
+    }
+}
+
+/// Parent of a ConfigResourceName.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ConfigParent {
+    Projects(ProjectsConfigParent),
+    Users(UsersConfigParent),
+}
+
+impl ConfigParent {
+    /// Returns true if any field contains a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        match self {
+            ConfigParent::Projects(inner) => inner.contains_wildcard(),
+            ConfigParent::Users(inner) => inner.contains_wildcard(),
+        }
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    /// Names of different patterns are matched segment by segment.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        match (self, concrete) {
+            (ConfigParent::Projects(inner), ConfigParent::Projects(concrete)) => inner.matches(concrete),
+            (ConfigParent::Users(inner), ConfigParent::Users(concrete)) => inner.matches(concrete),
+            _ => aip_resourcename::wildcard_matches(&self.to_string(), &concrete.to_string()),
+        }
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(self, variables: &[&str]) -> Self {
+        match self {
+            ConfigParent::Projects(inner) => ConfigParent::Projects(inner.with_wildcards_in(variables)),
+            ConfigParent::Users(inner) => ConfigParent::Users(inner.with_wildcards_in(variables)),
+        }
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        match self {
+            ConfigParent::Projects(inner) => inner.validate_concrete(),
+            ConfigParent::Users(inner) => inner.validate_concrete(),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        match self {
+            ConfigParent::Projects(inner) => inner.validate(),
+            ConfigParent::Users(inner) => inner.validate(),
+        }
+    }
+
+    /// Borrows the resource name as a ConfigParentRef.
+    pub fn as_borrowed(&self) -> ConfigParentRef<'_> {
+        match self {
+            ConfigParent::Projects(inner) => ConfigParentRef::Projects(inner.as_borrowed()),
+            ConfigParent::Users(inner) => ConfigParentRef::Users(inner.as_borrowed()),
+        }
+    }
+}
+
+impl fmt::Display for ConfigParent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigParent::Projects(inner) => write!(f, "{inner}"),
+            ConfigParent::Users(inner) => write!(f, "{inner}"),
+        }
+    }
+}
+
+impl FromStr for ConfigParent {
+    type Err = ResourceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ConfigParentRef::parse_borrowed(s).map(ConfigParentRef::to_owned)
+    }
+}
+
+/// Borrowed view of a ConfigParent that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConfigParentRef<'a> {
+    Projects(ProjectsConfigParentRef<'a>),
+    Users(UsersConfigParentRef<'a>),
+}
+
+impl<'a> ConfigParentRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut parts = s.split('/');
+        match parts.next() {
+            Some("projects") => {
+                match parts.next() {
+                    Some(part1) => {
+                        match parts.next() {
+                            Some(part) => Err(ResourceNameError::no_matching_pattern("projects/{project}", 2, Some(part))),
+                            None => ProjectsConfigParentRef::from_segments(part1).map(Self::Projects),
+                        }
+                    }
+                    None => Err(ResourceNameError::no_matching_pattern("projects/{project}", 1, None)),
+                }
+            }
+            Some("users") => {
+                match parts.next() {
+                    Some(part1) => {
+                        match parts.next() {
+                            Some(part) => Err(ResourceNameError::no_matching_pattern("users/{user}", 2, Some(part))),
+                            None => UsersConfigParentRef::from_segments(part1).map(Self::Users),
+                        }
+                    }
+                    None => Err(ResourceNameError::no_matching_pattern("users/{user}", 1, None)),
+                }
+            }
+            Some(part) => Err(ResourceNameError::no_matching_pattern("projects/{project}", 0, Some(part))),
+            None => Err(ResourceNameError::no_matching_pattern("projects/{project}", 0, None)),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        match self {
+            ConfigParentRef::Projects(inner) => inner.validate(),
+            ConfigParentRef::Users(inner) => inner.validate(),
+        }
+    }
+
+    /// Converts the borrowed view into an owned ConfigParent.
+    pub fn to_owned(self) -> ConfigParent {
+        match self {
+            ConfigParentRef::Projects(inner) => ConfigParent::Projects(inner.to_owned()),
+            ConfigParentRef::Users(inner) => ConfigParent::Users(inner.to_owned()),
+        }
+    }
+}
+
+impl fmt::Display for ConfigParentRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigParentRef::Projects(inner) => write!(f, "{inner}"),
+            ConfigParentRef::Users(inner) => write!(f, "{inner}"),
+        }
+    }
+}
+
+/// Parent of a ConfigResourceName: `projects/{project}`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ProjectsConfigParent {
+    pub project: String,
+}
+
+impl ProjectsConfigParent {
+    /// Creates a new ProjectsConfigParent.
+    pub fn new(
+        project: impl Into<String>
+    ) -> Self {
+        Self {
+            project: project.into(),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        self.as_borrowed().validate()
+    }
+
+    /// Borrows the resource name as a ProjectsConfigParentRef.
+    pub fn as_borrowed(&self) -> ProjectsConfigParentRef<'_> {
+        ProjectsConfigParentRef {
+            project: &self.project,
+        }
+    }
+
+    /// Returns true if any field contains a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        self.project == "-"
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        self.project == "-" || self.project == concrete.project
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(mut self, variables: &[&str]) -> Self {
+        if variables.contains(&"project") {
+            self.project = "-".to_string();
+        }
+        self
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        self.validate()?;
+        reject_wildcard("project", &self.project)?;
+        Ok(())
+    }
+
+}
+
+impl fmt::Display for ProjectsConfigParent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_borrowed(), f)
+    }
+}
+
+impl FromStr for ProjectsConfigParent {
+    type Err = ResourceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ProjectsConfigParentRef::parse_borrowed(s).map(ProjectsConfigParentRef::to_owned)
+    }
+}
+
+/// Borrowed view of a ProjectsConfigParent that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ProjectsConfigParentRef<'a> {
+    pub project: &'a str,
+}
+
+impl<'a> ProjectsConfigParentRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut scanner = Scanner::new(s, 2);
+        scanner.literal("projects")?;
+        let project = scanner.variable()?;
+        scanner.finish()?;
+        Self::from_segments(project)
+    }
+
+    /// Creates a ProjectsConfigParentRef from parsed segments and validates it.
+    fn from_segments(project: &'a str) -> Result<Self, ResourceNameError> {
+        let name = Self { project };
+        name.validate()?;
+        Ok(name)
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        validate_segment("project", self.project)?;
+        Ok(())
+    }
+
+    /// Converts the borrowed view into an owned ProjectsConfigParent.
+    pub fn to_owned(self) -> ProjectsConfigParent {
+        ProjectsConfigParent {
+            project: self.project.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ProjectsConfigParentRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "projects/{project}"
+            , project = self.project
+        )
+    }
+}
+
+/// Parent of a ConfigResourceName: `users/{user}`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UsersConfigParent {
+    pub user: String,
+}
+
+impl UsersConfigParent {
+    /// Creates a new UsersConfigParent.
+    pub fn new(
+        user: impl Into<String>
+    ) -> Self {
+        Self {
+            user: user.into(),
+        }
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        self.as_borrowed().validate()
+    }
+
+    /// Borrows the resource name as a UsersConfigParentRef.
+    pub fn as_borrowed(&self) -> UsersConfigParentRef<'_> {
+        UsersConfigParentRef {
+            user: &self.user,
+        }
+    }
+
+    /// Returns true if any field contains a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        self.user == "-"
+    }
+
+    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.
+    pub fn matches(&self, concrete: &Self) -> bool {
+        self.user == "-" || self.user == concrete.user
+    }
+
+    /// Returns the name with a wildcard in the given variables, e.g. `["project"]`.
+    /// Unknown variables are ignored.
+    pub fn with_wildcards_in(mut self, variables: &[&str]) -> Self {
+        if variables.contains(&"user") {
+            self.user = "-".to_string();
+        }
+        self
+    }
+
+    /// Validates the resource name and rejects wildcards, e.g. for Create requests.
+    pub fn validate_concrete(&self) -> Result<(), ResourceNameError> {
+        self.validate()?;
+        reject_wildcard("user", &self.user)?;
+        Ok(())
+    }
+
+}
+
+impl fmt::Display for UsersConfigParent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_borrowed(), f)
+    }
+}
+
+impl FromStr for UsersConfigParent {
+    type Err = ResourceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        UsersConfigParentRef::parse_borrowed(s).map(UsersConfigParentRef::to_owned)
+    }
+}
+
+/// Borrowed view of a UsersConfigParent that parses without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UsersConfigParentRef<'a> {
+    pub user: &'a str,
+}
+
+impl<'a> UsersConfigParentRef<'a> {
+    /// Parses a resource name without allocating.
+    pub fn parse_borrowed(s: &'a str) -> Result<Self, ResourceNameError> {
+        let mut scanner = Scanner::new(s, 2);
+        scanner.literal("users")?;
+        let user = scanner.variable()?;
+        scanner.finish()?;
+        Self::from_segments(user)
+    }
+
+    /// Creates a UsersConfigParentRef from parsed segments and validates it.
+    fn from_segments(user: &'a str) -> Result<Self, ResourceNameError> {
+        let name = Self { user };
+        name.validate()?;
+        Ok(name)
+    }
+
+    /// Validates the resource name.
+    pub fn validate(&self) -> Result<(), ResourceNameError> {
+        validate_segment("user", self.user)?;
+        Ok(())
+    }
+
+    /// Converts the borrowed view into an owned UsersConfigParent.
+    pub fn to_owned(self) -> UsersConfigParent {
+        UsersConfigParent {
+            user: self.user.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for UsersConfigParentRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "users/{user}"
+            , user = self.user
+        )
+    }
+}
+
+impl ProjectsConfigResourceName {
+    /// Returns the name of the parent of this resource.
+    pub fn parent(&self) -> ProjectsConfigParent {
+        ProjectsConfigParent {
+            project: self.project.clone(),
+        }
+    }
+}
+
+impl UsersConfigResourceName {
+    /// Returns the name of the parent of this resource.
+    pub fn parent(&self) -> UsersConfigParent {
+        UsersConfigParent {
+            user: self.user.clone(),
+        }
+    }
+}
+
+impl ConfigResourceName {
+    /// Returns the name of the parent of this resource.
+    pub fn parent(&self) -> ConfigParent {
+        match self {
+            ConfigResourceName::Projects(inner) => ConfigParent::Projects(inner.parent()),
+            ConfigResourceName::Users(inner) => ConfigParent::Users(inner.parent()),
+        }
+    }
+}
+
+impl ProjectsConfigParent {
+    /// Creates the name of a ConfigResourceName nested under this one.
+    pub fn config(&self) -> ConfigResourceName {
+        ConfigResourceName::Projects(ProjectsConfigResourceName { project: self.project.clone() })
+    }
+}
+
+impl UsersConfigParent {
+    /// Creates the name of a ConfigResourceName nested under this one.
+    pub fn config(&self) -> ConfigResourceName {
+        ConfigResourceName::Users(UsersConfigResourceName { user: self.user.clone() })
+    }
+}
+
+impl ConfigParent {
+    /// Creates the name of a ConfigResourceName nested under this one.
+    pub fn config(&self) -> ConfigResourceName {
+        match self {
+            ConfigParent::Projects(inner) => inner.config(),
+            ConfigParent::Users(inner) => inner.config(),
+        }
+    }
+}
+
+impl ProjectsConfigResourceName {
+    /// Returns the name of the ProjectResourceName this resource is nested under.
+    pub fn project(&self) -> ProjectResourceName {
+        ProjectResourceName {
+            project: self.project.clone(),
+        }
+    }
+}
+
+impl ConfigResourceName {
+    /// Returns the name of the ProjectResourceName this resource is nested under, if any.
+    pub fn project(&self) -> Option<ProjectResourceName> {
+        match self {
+            ConfigResourceName::Projects(inner) => Some(inner.project()),
+            ConfigResourceName::Users(_) => None,
+        }
+    }
+}
+
+impl ProjectResourceName {
+    /// Creates the name of a ConfigResourceName nested under this one.
+    pub fn config(&self) -> ConfigResourceName {
+        ConfigResourceName::Projects(ProjectsConfigResourceName { project: self.project.clone() })
+    }
+}
+
+impl UsersConfigResourceName {
+    /// Returns the name of the UserResourceName this resource is nested under.
+    pub fn user(&self) -> UserResourceName {
+        UserResourceName {
+            user: self.user.clone(),
+        }
+    }
+}
+
+impl ConfigResourceName {
+    /// Returns the name of the UserResourceName this resource is nested under, if any.
+    pub fn user(&self) -> Option<UserResourceName> {
+        match self {
+            ConfigResourceName::Projects(_) => None,
+            ConfigResourceName::Users(inner) => Some(inner.user()),
+        }
+    }
+}
+
+impl UserResourceName {
+    /// Creates the name of a ConfigResourceName nested under this one.
+    pub fn config(&self) -> ConfigResourceName {
+        ConfigResourceName::Users(UsersConfigResourceName { user: self.user.clone() })
+    }
+}
+
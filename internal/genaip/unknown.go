@@ -0,0 +1,27 @@
+package genaip
+
+import (
+	"google.golang.org/genproto/googleapis/api/annotations"
+	"google.golang.org/protobuf/compiler/protogen"
+)
+
+// unknownVariantName is the variant of an enum holding the names that match none of its
+// patterns, as an UntypedResourceName.
+const unknownVariantName = "Unknown"
+
+// hasUnknownVariant reports whether the enum of the resource has an Unknown variant. Resources
+// marked FUTURE_MULTI_PATTERN get one, so that clients keep parsing the names of patterns
+// added after they were generated.
+func (r *resourceNameCodeGenerator) hasUnknownVariant() bool {
+	return r.resource.GetHistory() == annotations.ResourceDescriptor_FUTURE_MULTI_PATTERN
+}
+
+// generateIsUnknownMethod generates is_unknown on an enum with an Unknown variant, or on
+// its borrowed view.
+func generateIsUnknownMethod(g *protogen.GeneratedFile, enumName string) {
+	g.P("    /// Returns true if the name matches none of the known patterns.")
+	g.P("    pub fn is_unknown(&self) -> bool {")
+	g.P("        matches!(self, ", enumName, "::", unknownVariantName, "(_))")
+	g.P("    }")
+	g.P()
+}
@@ -0,0 +1,97 @@
+package genaip
+
+import (
+	"regexp"
+	"strconv"
+	"strings"
+
+	"google.golang.org/protobuf/compiler/protogen"
+)
+
+// apiVersionPattern matches the version segment of a proto package, e.g. v1 or v1beta2.
+var apiVersionPattern = regexp.MustCompile(`^v\d+(p\d+)?((alpha|beta)\d*)?$`)
+
+// apiVersion returns the API version of the resource, which is the last segment of the
+// package of its file that looks like a version, or false if there is none.
+func (r *resourceNameCodeGenerator) apiVersion() (string, bool) {
+	parts := strings.Split(string(r.file.Desc.Package()), ".")
+	for i := len(parts) - 1; i >= 0; i-- {
+		if apiVersionPattern.MatchString(parts[i]) {
+			return parts[i], true
+		}
+	}
+	return "", false
+}
+
+// generateURIMethods generates the methods converting a struct from and to a resource URI.
+// Resources whose package has no version get none.
+func (r resourceNameCodeGenerator) generateURIMethods(g *protogen.GeneratedFile) {
+	version, ok := r.apiVersion()
+	if !ok {
+		return
+	}
+	g.P("    /// Returns the API version of the resource, from its proto package.")
+	g.P("    pub fn api_version(&self) -> &'static str {")
+	g.P("        ", strconv.Quote(version))
+	g.P("    }")
+	g.P()
+	g.P("    /// Returns the resource URI in the given API version, or else in the one of the resource.")
+	g.P("    pub fn to_uri(&self, version: Option<&str>) -> String {")
+	g.P("        let version = version.unwrap_or(self.api_version());")
+	g.P("        format!(\"https://{}/{}/{}\", self.service_name(), version, self)")
+	g.P("    }")
+	g.P()
+	r.generateFromURIMethod(g)
+}
+
+// generateEnumURIMethods generates the methods converting an enum from and to a resource
+// URI, which delegate to the struct of each variant.
+func (r resourceNameCodeGenerator) generateEnumURIMethods(
+	g *protogen.GeneratedFile,
+	enumName string,
+	candidates []patternCandidate,
+) {
+	version, ok := r.apiVersion()
+	if !ok {
+		return
+	}
+	g.P("    /// Returns the API version of the resource, from its proto package.")
+	g.P("    pub fn api_version(&self) -> &'static str {")
+	g.P("        match self {")
+	for _, candidate := range candidates {
+		g.P("            ", enumName, "::", candidate.variantName, "(inner) => inner.api_version(),")
+	}
+	if r.hasUnknownVariant() {
+		g.P("            ", enumName, "::", unknownVariantName, "(_) => ", strconv.Quote(version), ",")
+	}
+	g.P("        }")
+	g.P("    }")
+	g.P()
+	g.P("    /// Returns the resource URI in the given API version, or else in the one of the resource.")
+	g.P("    pub fn to_uri(&self, version: Option<&str>) -> String {")
+	g.P("        match self {")
+	for _, candidate := range candidates {
+		g.P("            ", enumName, "::", candidate.variantName, "(inner) => inner.to_uri(version),")
+	}
+	if r.hasUnknownVariant() {
+		g.P("            ", enumName, "::", unknownVariantName, "(name) => {")
+		g.P("                let version = version.unwrap_or(self.api_version());")
+		g.P("                format!(\"https://{}/{}/{}\", self.service_name(), version, name)")
+		g.P("            }")
+	}
+	g.P("        }")
+	g.P("    }")
+	g.P()
+	r.generateFromURIMethod(g)
+}
+
+func (r resourceNameCodeGenerator) generateFromURIMethod(g *protogen.GeneratedFile) {
+	g.P("    /// Parses a resource URI of the service of the resource, and returns the name with the")
+	g.P("    /// API version of the URI.")
+	g.P("    pub fn from_uri(uri: &str) -> Result<(Self, &str), ", resourceNameErrorType, "> {")
+	// Not imported in include_aip.rs, which would warn when no package has a version
+	g.P("        let (version, name) = ", runtimeCrate, "::split_uri(uri, ", strconv.Quote(r.serviceName()), ")?;")
+	g.P("        Ok((name.parse()?, version))")
+	g.P("    }")
+	g.P()
+}
@@ -0,0 +1,34 @@
+package genaip
+
+import "fmt"
+
+// ValidationProfile selects the rules the generated code validates variable segments with.
+type ValidationProfile string
+
+const (
+	// ValidationAIP122 accepts printable ASCII without whitespace, up to 1024 characters.
+	// It is the default.
+	ValidationAIP122 ValidationProfile = "aip122"
+
+	// ValidationRFC1034 accepts lowercase letters, digits and hyphens, starting with a
+	// letter, up to 63 characters.
+	ValidationRFC1034 ValidationProfile = "rfc1034"
+)
+
+// validate returns an error for unknown profiles.
+func (p ValidationProfile) validate() error {
+	switch p {
+	case "", ValidationAIP122, ValidationRFC1034:
+		return nil
+	default:
+		return fmt.Errorf("invalid validation option %q, expected %q or %q", p, ValidationAIP122, ValidationRFC1034)
+	}
+}
+
+// segmentValidator returns the runtime function validating a variable segment.
+func (p ValidationProfile) segmentValidator() string {
+	if p == ValidationRFC1034 {
+		return "validate_rfc1034_segment"
+	}
+	return "validate_segment"
+}
@@ -0,0 +1,129 @@
+package genaip
+
+import (
+	"strconv"
+	"strings"
+
+	"github.com/AnteWall/protoc-gen-rust-aip/pkg/resourcename"
+	"google.golang.org/protobuf/compiler/protogen"
+)
+
+// generateWildcardMethods generates the methods handling wildcards in a struct, see AIP-159.
+func generateWildcardMethods(g *protogen.GeneratedFile, segments []resourcename.Segment) {
+	var variables []string
+	for _, segment := range segments {
+		variables = append(variables, segment.Variables()...)
+	}
+	// The values of captures span several segments, each of which can be a wildcard.
+	templates := captureTemplates(segments)
+
+	g.P("    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.")
+	g.P("    pub fn matches(&self, concrete: &Self) -> bool {")
+	if len(variables) == 0 {
+		g.P("        self == concrete")
+	} else {
+		var conditions []string
+		for _, variable := range variables {
+			fieldName := toRustFieldName(variable)
+			if template, ok := templates[variable]; ok {
+				conditions = append(conditions, "aip_resourcename::capture_matches("+strconv.Quote(template)+", &self."+fieldName+", &concrete."+fieldName+")")
+			} else {
+				conditions = append(conditions, "self."+fieldName+" == \"-\" || self."+fieldName+" == concrete."+fieldName)
+			}
+		}
+		if len(conditions) > 1 {
+			for i, condition := range conditions {
+				conditions[i] = "(" + condition + ")"
+			}
+		}
+		g.P("        ", strings.Join(conditions, "\n            && "))
+	}
+	g.P("    }")
+	g.P()
+
+	g.P("    /// Returns the name with a wildcard in the given variables, e.g. `[\"project\"]`.")
+	g.P("    /// Unknown variables are ignored.")
+	g.P("    pub fn with_wildcards_in(mut self, variables: &[&str]) -> Self {")
+	if len(variables) == 0 {
+		g.P("        let _ = variables;")
+	}
+	for _, variable := range variables {
+		g.P("        if variables.contains(&", strconv.Quote(variable), ") {")
+		if template, ok := templates[variable]; ok {
+			g.P("            self.", toRustFieldName(variable), " = aip_resourcename::capture_wildcard(", strconv.Quote(template), ");")
+		} else {
+			g.P("            self.", toRustFieldName(variable), " = \"-\".to_string();")
+		}
+		g.P("        }")
+	}
+	g.P("        self")
+	g.P("    }")
+	g.P()
+
+	g.P("    /// Validates the resource name and rejects wildcards, e.g. for Create requests.")
+	g.P("    pub fn validate_concrete(&self) -> Result<(), ", resourceNameErrorType, "> {")
+	g.P("        self.validate()?;")
+	for _, variable := range variables {
+		if _, ok := templates[variable]; ok {
+			g.P("        for segment in self.", toRustFieldName(variable), ".split('/') {")
+			g.P("            reject_wildcard(", strconv.Quote(variable), ", segment)?;")
+			g.P("        }")
+		} else {
+			g.P("        reject_wildcard(", strconv.Quote(variable), ", &self.", toRustFieldName(variable), ")?;")
+		}
+	}
+	g.P("        Ok(())")
+	g.P("    }")
+	g.P()
+}
+
+// generateEnumWildcardMethods generates the methods handling wildcards in an enum. Names of
+// the same variant are matched by their fields, and names of different variants, e.g. of an
+// unknown pattern, by their segments.
+func generateEnumWildcardMethods(g *protogen.GeneratedFile, enumName string, candidates []patternCandidate, unknown bool) {
+	g.P("    /// Returns true if `concrete` is matched by this name, where a wildcard matches any value.")
+	g.P("    /// Names of different patterns are matched segment by segment.")
+	g.P("    pub fn matches(&self, concrete: &Self) -> bool {")
+	g.P("        match (self, concrete) {")
+	for _, candidate := range candidates {
+		variant := enumName + "::" + candidate.variantName
+		g.P("            (", variant, "(inner), ", variant, "(concrete)) => inner.matches(concrete),")
+	}
+	if unknown {
+		variant := enumName + "::" + unknownVariantName
+		g.P("            (", variant, "(name), ", variant, "(concrete)) => name.matches(concrete),")
+	}
+	if len(candidates) > 1 || unknown {
+		g.P("            _ => ", runtimeCrate, "::wildcard_matches(&self.to_string(), &concrete.to_string()),")
+	}
+	g.P("        }")
+	g.P("    }")
+	g.P()
+	g.P("    /// Returns the name with a wildcard in the given variables, e.g. `[\"project\"]`.")
+	g.P("    /// Unknown variables are ignored.")
+	g.P("    pub fn with_wildcards_in(self, variables: &[&str]) -> Self {")
+	g.P("        match self {")
+	for _, candidate := range candidates {
+		variant := enumName + "::" + candidate.variantName
+		g.P("            ", variant, "(inner) => ", variant, "(inner.with_wildcards_in(variables)),")
+	}
+	if unknown {
+		// The variables of unknown names are not known
+		g.P("            ", enumName, "::", unknownVariantName, "(name) => ", enumName, "::", unknownVariantName, "(name),")
+	}
+	g.P("        }")
+	g.P("    }")
+	g.P()
+	g.P("    /// Validates the resource name and rejects wildcards, e.g. for Create requests.")
+	g.P("    pub fn validate_concrete(&self) -> Result<(), ", resourceNameErrorType, "> {")
+	g.P("        match self {")
+	for _, candidate := range candidates {
+		g.P("            ", enumName, "::", candidate.variantName, "(inner) => inner.validate_concrete(),")
+	}
+	if unknown {
+		g.P("            ", enumName, "::", unknownVariantName, "(name) => name.validate_concrete(),")
+	}
+	g.P("        }")
+	g.P("    }")
+	g.P()
+}
@@ -6,10 +6,16 @@ import (
 	"strings"
 )
 
+// Separators are the literals that can separate the variables of a complex segment.
+const Separators = "~.-_"
+
 // Segment represents a segment of a resource name pattern.
 type Segment struct {
 	literal    string
 	isVariable bool
+	isComplex  bool
+	isWildcard bool
+	template   string
 }
 
 // NewSegment creates a new segment.
@@ -20,43 +26,259 @@ func NewSegment(literal string, isVariable bool) Segment {
 	}
 }
 
-// IsVariable returns true if this segment is a variable (e.g., {project}).
+// IsVariable returns true if this segment is a variable (e.g., {project}), including one
+// spanning several segments (e.g., {name=projects/*/books/*}).
 func (s Segment) IsVariable() bool {
 	return s.isVariable
 }
 
-// Literal returns the literal value of the segment.
+// IsCapture returns true if this segment is a variable whose value spans the segments
+// matched by a template of literals and wildcards (e.g., {name=projects/*/books/*}).
+func (s Segment) IsCapture() bool {
+	return s.template != ""
+}
+
+// Template returns the template of a capture (e.g., projects/*/books/*).
+func (s Segment) Template() string {
+	return s.template
+}
+
+// IsWildcard returns true if this segment is an anonymous wildcard, * or **.
+func (s Segment) IsWildcard() bool {
+	return s.isWildcard
+}
+
+// IsMultiWildcard returns true if this segment is **, which matches the rest of a name.
+func (s Segment) IsMultiWildcard() bool {
+	return s.isWildcard && s.literal == "**"
+}
+
+// IsComplex returns true if this segment holds several variables separated by single
+// characters (e.g., {key_a}~{key_b}).
+func (s Segment) IsComplex() bool {
+	return s.isComplex
+}
+
+// HasVariables returns true if this segment is a variable or a complex segment.
+func (s Segment) HasVariables() bool {
+	return s.isVariable || s.isComplex
+}
+
+// Literal returns the literal value of the segment, the name of the variable, or the raw
+// complex segment.
 func (s Segment) Literal() string {
 	return s.literal
 }
 
-// ParsePattern parses a resource name pattern into segments.
+// TemplateSegments returns the segments of the template of a capture, or the segment itself.
+func (s Segment) TemplateSegments() []Segment {
+	if !s.IsCapture() {
+		return []Segment{s}
+	}
+	var segments []Segment
+	for _, part := range strings.Split(s.template, "/") {
+		segments = append(segments, Segment{literal: part, isWildcard: part == "*" || part == "**"})
+	}
+	return segments
+}
+
+// endsWithMultiWildcard reports whether the segment matches the rest of a name.
+func (s Segment) endsWithMultiWildcard() bool {
+	segments := s.TemplateSegments()
+	return segments[len(segments)-1].IsMultiWildcard()
+}
+
+// Parts returns the variables and separators of a complex segment, or the segment itself.
+func (s Segment) Parts() []Segment {
+	if !s.isComplex {
+		return []Segment{s}
+	}
+	parts, _ := parseComplex(s.literal)
+	return parts
+}
+
+// Variables returns the names of the variables of the segment, in order.
+func (s Segment) Variables() []string {
+	var variables []string
+	for _, part := range s.Parts() {
+		if part.IsVariable() {
+			variables = append(variables, part.Literal())
+		}
+	}
+	return variables
+}
+
+// ParsePattern parses a resource name pattern into segments. Patterns follow the path
+// template syntax of google.api.http: besides literals and variables, a segment can be an
+// anonymous wildcard, * or **, or a variable spanning several segments, such as
+// {name=projects/*/books/*}. A ** must come last.
 func ParsePattern(pattern string, segments *[]Segment) error {
 	if segments == nil {
 		return fmt.Errorf("segments slice cannot be nil")
 	}
 
 	*segments = nil
-	parts := strings.Split(pattern, "/")
+	parts, err := SplitPattern(pattern)
+	if err != nil {
+		return err
+	}
 
-	for _, part := range parts {
-		if strings.HasPrefix(part, "{") && strings.HasSuffix(part, "}") {
-			// Variable segment
-			literal := part[1 : len(part)-1]
-			*segments = append(*segments, NewSegment(literal, true))
-		} else {
-			// Literal segment
-			*segments = append(*segments, NewSegment(part, false))
+	for i, part := range parts {
+		segment, err := parseSegment(part)
+		if err != nil {
+			return fmt.Errorf("invalid segment %q in pattern %q: %w", part, pattern, err)
 		}
+		if segment.endsWithMultiWildcard() && i < len(parts)-1 {
+			return fmt.Errorf("invalid segment %q in pattern %q: ** must come last", part, pattern)
+		}
+		*segments = append(*segments, segment)
 	}
 
 	return nil
 }
 
+// SplitPattern splits a pattern at the slashes outside of variables, which may hold
+// templates such as {name=projects/*}.
+func SplitPattern(pattern string) ([]string, error) {
+	var parts []string
+	start, depth := 0, 0
+	for i, c := range pattern {
+		switch c {
+		case '{':
+			depth++
+			if depth > 1 {
+				return nil, fmt.Errorf("nested variable at %d in pattern %q", i, pattern)
+			}
+		case '}':
+			depth--
+			if depth < 0 {
+				return nil, fmt.Errorf("unbalanced '}' at %d in pattern %q", i, pattern)
+			}
+		case '/':
+			if depth == 0 {
+				parts = append(parts, pattern[start:i])
+				start = i + 1
+			}
+		}
+	}
+	if depth != 0 {
+		return nil, fmt.Errorf("unterminated variable in pattern %q", pattern)
+	}
+	return append(parts, pattern[start:]), nil
+}
+
+// parseSegment parses a single segment of a pattern.
+func parseSegment(part string) (Segment, error) {
+	switch {
+	case part == "*" || part == "**":
+		// Anonymous wildcard
+		return Segment{literal: part, isWildcard: true}, nil
+	case strings.HasPrefix(part, "{") && strings.HasSuffix(part, "}") && !strings.ContainsAny(part[1:len(part)-1], "{}"):
+		// Variable segment, whose template defaults to a single segment
+		name, template, found := strings.Cut(part[1:len(part)-1], "=")
+		if !isFieldPath(name) {
+			return Segment{}, fmt.Errorf("invalid variable name %q", name)
+		}
+		if !found || template == "*" {
+			return NewSegment(name, true), nil
+		}
+		templateParts := strings.Split(template, "/")
+		for i, templatePart := range templateParts {
+			if templatePart == "" || strings.ContainsAny(templatePart, "{}=") ||
+				(templatePart == "**" && i < len(templateParts)-1) {
+				return Segment{}, fmt.Errorf("invalid template %q", template)
+			}
+		}
+		return Segment{literal: name, isVariable: true, template: template}, nil
+	case strings.ContainsAny(part, "{}"):
+		// Complex segment
+		if _, err := parseComplex(part); err != nil {
+			return Segment{}, err
+		}
+		return Segment{literal: part, isComplex: true}, nil
+	default:
+		// Literal segment
+		return NewSegment(part, false), nil
+	}
+}
+
+// isFieldPath reports whether a variable name is a field path, such as book or book.name.
+func isFieldPath(name string) bool {
+	for _, ident := range strings.Split(name, ".") {
+		if ident == "" {
+			return false
+		}
+		for _, c := range ident {
+			if !('a' <= c && c <= 'z' || 'A' <= c && c <= 'Z' || '0' <= c && c <= '9' || c == '_') {
+				return false
+			}
+		}
+	}
+	return true
+}
+
+// parseComplex parses the parts of a complex segment: variables separated by single
+// characters from Separators, e.g. {key_a}~{key_b}.
+func parseComplex(segment string) ([]Segment, error) {
+	var parts []Segment
+	rest := segment
+	for {
+		end := strings.IndexByte(rest, '}')
+		if !strings.HasPrefix(rest, "{") || end < 0 {
+			return nil, fmt.Errorf("expected a variable at %q", rest)
+		}
+		name := rest[1:end]
+		if !isFieldPath(name) {
+			return nil, fmt.Errorf("invalid variable %q", rest[:end+1])
+		}
+		parts = append(parts, NewSegment(name, true))
+		rest = rest[end+1:]
+		if rest == "" {
+			break
+		}
+		if !strings.ContainsRune(Separators, rune(rest[0])) {
+			return nil, fmt.Errorf("expected one of %q between variables, got %q", Separators, rest)
+		}
+		parts = append(parts, NewSegment(rest[:1], false))
+		rest = rest[1:]
+	}
+	if len(parts) == 1 {
+		return nil, fmt.Errorf("expected several variables")
+	}
+	return parts, nil
+}
+
+// splitComplex splits the value of a complex segment into the values of its variables, each
+// ending at the first following separator. It reports false if a separator is missing.
+func splitComplex(segment Segment, value string) ([]string, bool) {
+	var values []string
+	rest := value
+	for _, part := range segment.Parts() {
+		if part.IsVariable() {
+			continue
+		}
+		head, tail, found := strings.Cut(rest, part.Literal())
+		if !found {
+			return nil, false
+		}
+		values = append(values, head)
+		rest = tail
+	}
+	return append(values, rest), true
+}
+
+// IsSingleton reports whether the segments of a pattern are those of a singleton resource,
+// see AIP-156: the pattern ends with a literal after a variable, e.g. projects/{project}/settings.
+func IsSingleton(segments []Segment) bool {
+	n := len(segments)
+	return n >= 2 && !segments[n-1].HasVariables() && !segments[n-1].IsWildcard() && segments[n-2].HasVariables()
+}
+
 // Wildcard is the resource name wildcard character "-".
 const Wildcard = "-"
 
 // Sprint formats resource name variables according to a pattern and returns the resulting string.
+// Anonymous wildcards are formatted as the Wildcard.
 func Sprint(pattern string, variables ...string) string {
 	var segments []Segment
 	if err := ParsePattern(pattern, &segments); err != nil {
@@ -71,13 +293,17 @@ func Sprint(pattern string, variables ...string) string {
 			result.WriteByte('/')
 		}
 
-		if segment.IsVariable() {
-			if varIndex < len(variables) {
-				result.WriteString(variables[varIndex])
-				varIndex++
+		for _, part := range segment.Parts() {
+			if part.IsVariable() {
+				if varIndex < len(variables) {
+					result.WriteString(variables[varIndex])
+					varIndex++
+				}
+			} else if part.IsWildcard() {
+				result.WriteString(Wildcard)
+			} else {
+				result.WriteString(part.Literal())
 			}
-		} else {
-			result.WriteString(segment.Literal())
 		}
 	}
 
@@ -85,35 +311,65 @@ func Sprint(pattern string, variables ...string) string {
 }
 
 // Sscan scans a resource name, storing successive segments into successive variables
-// as determined by the provided pattern.
+// as determined by the provided pattern. Anonymous wildcards are matched but not stored.
 func Sscan(name, pattern string, variables ...*string) error {
 	var segments []Segment
 	if err := ParsePattern(pattern, &segments); err != nil {
 		return err
 	}
 
-	parts := strings.Split(name, "/")
-	if len(parts) != len(segments) {
-		return fmt.Errorf("expected %d parts, got %d", len(segments), len(parts))
+	values, err := scan(segments, strings.Split(name, "/"))
+	if err != nil {
+		return err
 	}
 
 	varIndex := 0
-	for i, segment := range segments {
-		if segment.IsVariable() {
-			if varIndex < len(variables) && variables[varIndex] != nil {
-				*variables[varIndex] = parts[i]
-				varIndex++
-			}
-		} else {
-			if parts[i] != segment.Literal() {
-				return fmt.Errorf("expected %q at position %d, got %q", segment.Literal(), i, parts[i])
-			}
+	for _, value := range values {
+		if varIndex < len(variables) && variables[varIndex] != nil {
+			*variables[varIndex] = value
+			varIndex++
 		}
 	}
 
 	return nil
 }
 
+// scan matches the parts of a resource name against the segments of a pattern and returns
+// the values of the variables, in order.
+func scan(segments []Segment, parts []string) ([]string, error) {
+	var values []string
+	position := 0
+	for _, segment := range segments {
+		templateSegments := segment.TemplateSegments()
+		n := len(templateSegments)
+		if segment.endsWithMultiWildcard() && len(parts)-position > n {
+			n = len(parts) - position
+		}
+		if position+n > len(parts) {
+			return nil, fmt.Errorf("expected %d parts, got %d", position+n, len(parts))
+		}
+		for i, templateSegment := range templateSegments {
+			part := parts[position+i]
+			if !templateSegment.HasVariables() && !templateSegment.IsWildcard() && part != templateSegment.Literal() {
+				return nil, fmt.Errorf("expected %q at position %d, got %q", templateSegment.Literal(), position+i, part)
+			}
+		}
+		value := strings.Join(parts[position:position+n], "/")
+		if segment.HasVariables() {
+			split, ok := splitComplex(segment, value)
+			if !ok {
+				return nil, fmt.Errorf("expected %q at position %d, got %q", segment.Literal(), position, value)
+			}
+			values = append(values, split...)
+		}
+		position += n
+	}
+	if position != len(parts) {
+		return nil, fmt.Errorf("expected %d parts, got %d", position, len(parts))
+	}
+	return values, nil
+}
+
 // Validate validates a resource name format.
 func Validate(name string) error {
 	if name == "" {
@@ -138,16 +394,6 @@ func Match(pattern, name string) bool {
 		return false
 	}
 
-	parts := strings.Split(name, "/")
-	if len(parts) != len(segments) {
-		return false
-	}
-
-	for i, segment := range segments {
-		if !segment.IsVariable() && segment.Literal() != parts[i] {
-			return false
-		}
-	}
-
-	return true
+	_, err := scan(segments, strings.Split(name, "/"))
+	return err == nil
 }
@@ -0,0 +1,250 @@
+package resourcename
+
+import "testing"
+
+// parse parses a pattern that is expected to be valid.
+func parse(t *testing.T, pattern string) []Segment {
+	t.Helper()
+	var segments []Segment
+	if err := ParsePattern(pattern, &segments); err != nil {
+		t.Fatalf("ParsePattern(%q): %v", pattern, err)
+	}
+	return segments
+}
+
+func TestIsSingleton(t *testing.T) {
+	for _, tt := range []struct {
+		pattern string
+		want    bool
+	}{
+		{"projects/{project}/settings", true},
+		{"projects/{project}/locations/{location}/settings", true},
+		{"{parent=users/*}/settings", true},
+		{"shelves/{shelf_a}~{shelf_b}/settings", true},
+		{"settings", false},
+		{"projects/{project}", false},
+		{"projects/{project}/books/{book}", false},
+		{"projects/{project}/config/settings", false},
+		{"projects/{project}/*", false},
+		{"projects/{project}/**", false},
+	} {
+		t.Run(tt.pattern, func(t *testing.T) {
+			if got := IsSingleton(parse(t, tt.pattern)); got != tt.want {
+				t.Errorf("IsSingleton(%q) = %v, want %v", tt.pattern, got, tt.want)
+			}
+		})
+	}
+}
+
+func TestSingletonNames(t *testing.T) {
+	const pattern = "projects/{project}/settings"
+	if got := Sprint(pattern, "p"); got != "projects/p/settings" {
+		t.Errorf("Sprint(%q, %q) = %q, want %q", pattern, "p", got, "projects/p/settings")
+	}
+	for _, tt := range []struct {
+		name    string
+		project string
+		wantErr bool
+	}{
+		{"projects/p/settings", "p", false},
+		{"projects/-/settings", "-", false},
+		{"projects/p", "", true},
+		{"projects/p/settings/s", "", true},
+		{"projects/p/config", "", true},
+		{"settings", "", true},
+	} {
+		t.Run(tt.name, func(t *testing.T) {
+			var project string
+			err := Sscan(tt.name, pattern, &project)
+			if (err != nil) != tt.wantErr {
+				t.Fatalf("Sscan(%q) error = %v, want error %v", tt.name, err, tt.wantErr)
+			}
+			if project != tt.project {
+				t.Errorf("Sscan(%q) project = %q, want %q", tt.name, project, tt.project)
+			}
+			if Match(pattern, tt.name) == tt.wantErr {
+				t.Errorf("Match(%q, %q) = %v, want %v", pattern, tt.name, tt.wantErr, !tt.wantErr)
+			}
+		})
+	}
+}
+
+func TestParsePatternComplex(t *testing.T) {
+	for _, tt := range []struct {
+		pattern   string
+		variables []string
+	}{
+		{"keys/{key_a}~{key_b}", []string{"key_a", "key_b"}},
+		{"keys/{a}.{b}.{c}", []string{"a", "b", "c"}},
+		{"keys/{a}-{b}", []string{"a", "b"}},
+		{"keys/{a}_{b.c}", []string{"a", "b.c"}},
+	} {
+		t.Run(tt.pattern, func(t *testing.T) {
+			segment := parse(t, tt.pattern)[1]
+			if !segment.IsComplex() || !segment.HasVariables() || segment.IsVariable() {
+				t.Fatalf("segment %q is not complex", segment.Literal())
+			}
+			variables := segment.Variables()
+			if len(variables) != len(tt.variables) {
+				t.Fatalf("Variables() = %q, want %q", variables, tt.variables)
+			}
+			for i := range variables {
+				if variables[i] != tt.variables[i] {
+					t.Errorf("Variables() = %q, want %q", variables, tt.variables)
+				}
+			}
+		})
+	}
+}
+
+func TestParsePatternComplexErrors(t *testing.T) {
+	for _, pattern := range []string{
+		"keys/{a}+{b}",
+		"keys/{a}/{b}~",
+		"keys/{a}~~{b}",
+		"keys/{a}{b}",
+		"keys/{a}~b",
+		"keys/a~{b}",
+		"keys/{a}~{}",
+		"keys/{a}~{b-c}",
+	} {
+		t.Run(pattern, func(t *testing.T) {
+			var segments []Segment
+			if err := ParsePattern(pattern, &segments); err == nil {
+				t.Errorf("ParsePattern(%q) = %v, want error", pattern, segments)
+			}
+		})
+	}
+}
+
+func TestComplexNames(t *testing.T) {
+	const pattern = "keys/{key_a}~{key_b}"
+	if got := Sprint(pattern, "a", "b"); got != "keys/a~b" {
+		t.Errorf("Sprint(%q, %q, %q) = %q, want %q", pattern, "a", "b", got, "keys/a~b")
+	}
+	for _, tt := range []struct {
+		name    string
+		keyA    string
+		keyB    string
+		wantErr bool
+	}{
+		{"keys/a~b", "a", "b", false},
+		{"keys/a~b~c", "a", "b~c", false},
+		{"keys/~b", "", "b", false},
+		{"keys/a", "", "", true},
+		{"keys/a.b", "", "", true},
+		{"keys/a/b", "", "", true},
+	} {
+		t.Run(tt.name, func(t *testing.T) {
+			var keyA, keyB string
+			err := Sscan(tt.name, pattern, &keyA, &keyB)
+			if (err != nil) != tt.wantErr {
+				t.Fatalf("Sscan(%q) error = %v, want error %v", tt.name, err, tt.wantErr)
+			}
+			if keyA != tt.keyA || keyB != tt.keyB {
+				t.Errorf("Sscan(%q) = %q, %q, want %q, %q", tt.name, keyA, keyB, tt.keyA, tt.keyB)
+			}
+		})
+	}
+}
+
+func TestParsePatternCaptures(t *testing.T) {
+	segments := parse(t, "{parent=projects/*}/v1/*/files/{path=**}")
+	for _, tt := range []struct {
+		index          int
+		literal        string
+		template       string
+		capture        bool
+		wildcard       bool
+		multiWildcard  bool
+		templateLength int
+	}{
+		{0, "parent", "projects/*", true, false, false, 2},
+		{1, "v1", "", false, false, false, 1},
+		{2, "*", "", false, true, false, 1},
+		{3, "files", "", false, false, false, 1},
+		{4, "path", "**", true, false, false, 1},
+	} {
+		segment := segments[tt.index]
+		if segment.Literal() != tt.literal || segment.Template() != tt.template ||
+			segment.IsCapture() != tt.capture || segment.IsVariable() != tt.capture ||
+			segment.IsWildcard() != tt.wildcard || segment.IsMultiWildcard() != tt.multiWildcard ||
+			len(segment.TemplateSegments()) != tt.templateLength {
+			t.Errorf("segment %d = %+v, want %+v", tt.index, segment, tt)
+		}
+	}
+	if !parse(t, "files/**")[1].IsMultiWildcard() {
+		t.Errorf("** is not a multi-segment wildcard")
+	}
+}
+
+func TestParsePatternCaptureErrors(t *testing.T) {
+	for _, pattern := range []string{
+		"files/**/x",
+		"{path=**}/x",
+		"{name=a/**/b}",
+		"{name=a//b}",
+		"{name=a/{b}}",
+		"{name=a}}",
+		"{name=projects/*",
+		"{na-me=projects/*}",
+	} {
+		t.Run(pattern, func(t *testing.T) {
+			var segments []Segment
+			if err := ParsePattern(pattern, &segments); err == nil {
+				t.Errorf("ParsePattern(%q) = %v, want error", pattern, segments)
+			}
+		})
+	}
+}
+
+func TestCaptureNames(t *testing.T) {
+	const pattern = "{parent=projects/*}/files/{path=**}"
+	if got := Sprint(pattern, "projects/p", "a/b"); got != "projects/p/files/a/b" {
+		t.Errorf("Sprint(%q) = %q, want %q", pattern, got, "projects/p/files/a/b")
+	}
+	for _, tt := range []struct {
+		name    string
+		parent  string
+		path    string
+		wantErr bool
+	}{
+		{"projects/p/files/a", "projects/p", "a", false},
+		{"projects/p/files/a/b/c", "projects/p", "a/b/c", false},
+		{"projects/p/files", "", "", true},
+		{"projects/p/x/files/a", "", "", true},
+		{"shelves/s/files/a", "", "", true},
+	} {
+		t.Run(tt.name, func(t *testing.T) {
+			var parent, path string
+			err := Sscan(tt.name, pattern, &parent, &path)
+			if (err != nil) != tt.wantErr {
+				t.Fatalf("Sscan(%q) error = %v, want error %v", tt.name, err, tt.wantErr)
+			}
+			if parent != tt.parent || path != tt.path {
+				t.Errorf("Sscan(%q) = %q, %q, want %q, %q", tt.name, parent, path, tt.parent, tt.path)
+			}
+		})
+	}
+}
+
+func TestAnonymousWildcards(t *testing.T) {
+	const pattern = "v1/*/files/**"
+	if got := Sprint(pattern); got != "v1/-/files/-" {
+		t.Errorf("Sprint(%q) = %q, want %q", pattern, got, "v1/-/files/-")
+	}
+	for _, tt := range []struct {
+		name string
+		want bool
+	}{
+		{"v1/x/files/a", true},
+		{"v1/x/files/a/b/c", true},
+		{"v1/x/files", false},
+		{"v1/x/y/files/a", false},
+		{"v1/files/a", false},
+	} {
+		if got := Match(pattern, tt.name); got != tt.want {
+			t.Errorf("Match(%q, %q) = %v, want %v", pattern, tt.name, got, tt.want)
+		}
+	}
+}